@@ -0,0 +1,48 @@
+use super::helpers;
+use cognitive_services_speech_sdk_rs::common::EventBuffering;
+use cognitive_services_speech_sdk_rs::speech::SpeechRecognizerEvent;
+use log::*;
+use tokio_stream::StreamExt;
+
+#[allow(dead_code)]
+pub async fn run_example() {
+    info!("-----------------------------------------------------");
+    info!("running continuous_recognition_events_stream example...");
+    info!("-----------------------------------------------------");
+
+    let filename = helpers::get_sample_file("turn_on_the_lamp.wav");
+
+    let mut speech_recognizer = helpers::speech_recognizer_from_wav_file(&filename);
+
+    let mut events = speech_recognizer
+        .events(EventBuffering::Bounded(32))
+        .unwrap();
+
+    if let Err(err) = speech_recognizer.start_continuous_recognition_async().await {
+        error!("start_continuous_recognition_async error {:?}", err);
+    }
+
+    while let Some(event) = events.next().await {
+        match event {
+            SpeechRecognizerEvent::Recognizing(event) => {
+                info!("recognizing: {}", event.result.text);
+            }
+            SpeechRecognizerEvent::Recognized(event) => {
+                info!("recognized: {}", event.result.text);
+            }
+            SpeechRecognizerEvent::Canceled(event) => {
+                info!("canceled: {:?}", event);
+                break;
+            }
+            SpeechRecognizerEvent::SessionStopped(_) => break,
+            event => info!("{:?}", event),
+        }
+    }
+
+    speech_recognizer
+        .stop_continuous_recognition_async()
+        .await
+        .unwrap();
+
+    info!("example finished!");
+}
@@ -0,0 +1,45 @@
+use super::helpers;
+use cognitive_services_speech_sdk_rs::audio::AudioConfig;
+use cognitive_services_speech_sdk_rs::speech::{
+    EmbeddedSpeechConfig, HybridSpeechConfig, SpeechConfig, SpeechRecognizer,
+};
+use log::*;
+use std::env;
+
+#[allow(dead_code)]
+pub async fn run_example() {
+    info!("---------------------------------------------");
+    info!("running hybrid recognize_from_file example...");
+    info!("---------------------------------------------");
+
+    let filename = helpers::get_sample_file("hello_rust.wav");
+    let audio_config = AudioConfig::from_wav_file_input(&filename).unwrap();
+
+    let cloud_config = SpeechConfig::from_subscription(
+        env::var("MSSubscriptionKey").unwrap(),
+        env::var("MSServiceRegion").unwrap(),
+    )
+    .unwrap();
+
+    let mut embedded_config =
+        EmbeddedSpeechConfig::from_path(env::var("ModelPath").unwrap()).unwrap();
+    let models = embedded_config.get_speech_recognition_models().unwrap();
+    let model = models.first().unwrap();
+    info!("Using first model: {:?}", model);
+    embedded_config
+        .set_speech_recognition_model(model, env::var("ModelKey").unwrap())
+        .unwrap();
+
+    let speech_config = HybridSpeechConfig::from_configs(&cloud_config, &embedded_config).unwrap();
+
+    let mut speech_recognizer =
+        SpeechRecognizer::from_hybrid_config(speech_config, audio_config).unwrap();
+
+    let result = speech_recognizer.recognize_once_async().await.unwrap();
+    info!(
+        "got recognition {:?} from {:?} backend",
+        result,
+        result.backend()
+    );
+    info!("example finished!");
+}
@@ -1,9 +1,11 @@
+mod continuous_recognition_events_stream;
 mod continuous_recognition_from_file;
 mod continuous_recognition_pull_stream;
 mod continuous_recognition_push_stream;
 mod embedded_recognize_once_async_from_file;
 mod from_microphone;
 mod helpers;
+mod hybrid_recognize_once_async_from_file;
 mod recognize_once_async_from_file;
 mod recognize_once_async_from_push_stream;
 
@@ -19,9 +21,11 @@ async fn main() {
     continuous_recognition_push_stream::run_example().await;
     recognize_once_async_from_push_stream::run_example().await;
     continuous_recognition_pull_stream::run_example().await;
+    continuous_recognition_events_stream::run_example().await;
     // works only on system with properly configured microphone
     // from_microphone::run_example().await;
 
     // not available in public release yet
     //embedded_recognize_once_async_from_file::run_example().await;
+    //hybrid_recognize_once_async_from_file::run_example().await;
 }
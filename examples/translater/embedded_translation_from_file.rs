@@ -0,0 +1,37 @@
+use super::helpers;
+use cognitive_services_speech_sdk_rs::audio::AudioConfig;
+use cognitive_services_speech_sdk_rs::speech::EmbeddedSpeechConfig;
+use cognitive_services_speech_sdk_rs::translation::TranslationRecognizer;
+use log::*;
+use std::env;
+
+#[allow(dead_code)]
+pub async fn run_example() {
+    info!("-------------------------------------------------");
+    info!("running embedded_translation_from_file example...");
+    info!("-------------------------------------------------");
+
+    let filename = helpers::get_sample_file("hello_rust.wav");
+    let audio_config = AudioConfig::from_wav_file_input(&filename).unwrap();
+
+    let mut speech_config =
+        EmbeddedSpeechConfig::from_path(env::var("ModelPath").unwrap()).unwrap();
+
+    let models = speech_config.get_speech_translation_models().unwrap();
+    let model = models.first().unwrap();
+    info!("Using first model: {:?}", model);
+
+    speech_config
+        .set_speech_translation_model(model, env::var("ModelKey").unwrap())
+        .unwrap();
+    speech_config
+        .add_target_language(model.target_languages.first().unwrap().as_str())
+        .unwrap();
+
+    let mut translation_recognizer =
+        TranslationRecognizer::from_embedded_config(speech_config, audio_config).unwrap();
+
+    let result = translation_recognizer.recognize_once_async().await;
+    info!("got translation {:?}", result);
+    info!("example finished!");
+}
@@ -1,4 +1,5 @@
 mod continuous_translation_from_file;
+mod embedded_translation_from_file;
 mod helpers;
 
 #[tokio::main]
@@ -14,4 +15,5 @@ async fn main() {
 
     // not available in public release yet
     //embedded_recognize_once_async_from_file::run_example().await;
+    //embedded_translation_from_file::run_example().await;
 }
@@ -2,22 +2,44 @@
 mod audio_config;
 mod audio_input_stream;
 mod audio_output_stream;
+mod audio_processing_flags;
+mod audio_processing_options;
 mod audio_stream_container_format;
 mod audio_stream_format;
+mod blocking_reader;
+mod microphone_array_geometry;
+mod microphone_array_type;
+mod preset_microphone_array_geometry;
 mod pull_audio_input_stream;
 mod pull_audio_output_stream;
+mod pull_stream_sources;
 mod push_audio_input_stream;
 mod push_audio_output_stream;
+mod push_stream_sinks;
+mod speaker_reference_channel;
+mod wav_file_input;
 
 // re-export structs directly under audio module
 pub use self::audio_config::AudioConfig;
 pub use self::audio_input_stream::AudioInputStream;
 pub use self::audio_output_stream::AudioOutputStream;
+pub use self::audio_processing_flags::AudioProcessingFlags;
+pub use self::audio_processing_options::AudioProcessingOptions;
 pub use self::audio_stream_container_format::AudioStreamContainerFormat;
 pub use self::audio_stream_format::AudioStreamFormat;
+pub(crate) use self::blocking_reader::BlockingReader;
+pub use self::microphone_array_geometry::{MicrophoneArrayGeometry, MicrophoneCoordinates};
+pub use self::microphone_array_type::MicrophoneArrayType;
+pub use self::preset_microphone_array_geometry::PresetMicrophoneArrayGeometry;
 pub use self::pull_audio_input_stream::PullAudioInputStream;
 pub use self::pull_audio_input_stream::PullAudioInputStreamCallbacks;
 pub use self::pull_audio_output_stream::PullAudioOutputStream;
+pub use self::pull_stream_sources::{ChannelPullSource, ReaderPullSource};
 pub use self::push_audio_input_stream::PushAudioInputStream;
 pub use self::push_audio_output_stream::PushAudioOutputStream;
 pub use self::push_audio_output_stream::PushAudioOutputStreamCallbacks;
+pub use self::push_stream_sinks::{ChannelPushSink, VecPushSink, WavFilePushSink};
+pub use self::speaker_reference_channel::SpeakerReferenceChannel;
+pub use self::wav_file_input::{
+    WavFileInput, WavFormat, WavSampleFormat, WAV_INPUT_SAMPLES_PER_SECOND,
+};
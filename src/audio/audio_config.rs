@@ -1,4 +1,4 @@
-use crate::audio::{AudioInputStream, AudioOutputStream};
+use crate::audio::{AudioInputStream, AudioOutputStream, AudioProcessingOptions};
 use crate::common::{PropertyCollection, PropertyId};
 use crate::error::{convert_err, Result};
 use crate::ffi::{
@@ -9,8 +9,9 @@ use crate::ffi::{
     audio_config_create_audio_output_from_a_speaker,
     audio_config_create_audio_output_from_default_speaker,
     audio_config_create_audio_output_from_stream,
-    audio_config_create_audio_output_from_wav_file_name, audio_config_get_property_bag,
-    audio_config_release, SmartHandle, SPXAUDIOCONFIGHANDLE, SPXPROPERTYBAGHANDLE,
+    audio_config_create_audio_output_from_wav_file_name, audio_config_get_audio_processing_options,
+    audio_config_get_property_bag, audio_config_release, audio_config_set_audio_processing_options,
+    SmartHandle, SPXAUDIOCONFIGHANDLE, SPXAUDIOPROCESSINGOPTIONSHANDLE, SPXPROPERTYBAGHANDLE,
 };
 use log::*;
 use std::ffi::CString;
@@ -56,6 +57,17 @@ impl AudioConfig {
         }
     }
 
+    /// Creates audio config from stream input with audio processing options,
+    /// e.g. echo cancellation or beamforming performed on multichannel stream input.
+    pub fn from_stream_input_with_processing(
+        stream: &dyn AudioInputStream,
+        audio_processing_options: &AudioProcessingOptions,
+    ) -> Result<AudioConfig> {
+        let audio_config = AudioConfig::from_stream_input(stream)?;
+        audio_config.set_audio_processing_options(audio_processing_options)?;
+        Ok(audio_config)
+    }
+
     pub fn from_wav_file_input(file_name: &str) -> Result<AudioConfig> {
         unsafe {
             let mut handle: MaybeUninit<SPXAUDIOCONFIGHANDLE> = MaybeUninit::uninit();
@@ -82,6 +94,16 @@ impl AudioConfig {
         }
     }
 
+    /// Creates audio config from default microphone input with audio processing options,
+    /// e.g. echo cancellation, noise suppression or microphone array beamforming.
+    pub fn from_default_microphone_input_with_processing(
+        audio_processing_options: &AudioProcessingOptions,
+    ) -> Result<AudioConfig> {
+        let audio_config = AudioConfig::from_default_microphone_input()?;
+        audio_config.set_audio_processing_options(audio_processing_options)?;
+        Ok(audio_config)
+    }
+
     pub fn from_microphone_input(device_name: &str) -> Result<AudioConfig> {
         unsafe {
             let mut handle: MaybeUninit<SPXAUDIOCONFIGHANDLE> = MaybeUninit::uninit();
@@ -165,4 +187,31 @@ impl AudioConfig {
     pub fn get_property_by_string(&self, id: &str) -> Result<String> {
         self.properties.get_property_by_string(id, "")
     }
+
+    /// Returns audio processing options of audio config.
+    pub fn get_audio_processing_options(&self) -> Result<AudioProcessingOptions> {
+        unsafe {
+            let mut options_handle: MaybeUninit<SPXAUDIOPROCESSINGOPTIONSHANDLE> =
+                MaybeUninit::uninit();
+            let ret = audio_config_get_audio_processing_options(
+                self.handle.inner(),
+                options_handle.as_mut_ptr(),
+            );
+            convert_err(ret, "AudioConfig.get_audio_processing_options error")?;
+            AudioProcessingOptions::from_handle(options_handle.assume_init())
+        }
+    }
+
+    fn set_audio_processing_options(
+        &self,
+        audio_processing_options: &AudioProcessingOptions,
+    ) -> Result<()> {
+        unsafe {
+            let ret = audio_config_set_audio_processing_options(
+                self.handle.inner(),
+                audio_processing_options.handle.inner(),
+            );
+            convert_err(ret, "AudioConfig.set_audio_processing_options error")
+        }
+    }
 }
@@ -0,0 +1,64 @@
+use std::ops::{BitOr, BitOrAssign};
+
+/// AudioProcessingFlags defines which built-in input audio processing is performed.
+/// Flags can be combined using *|* operator, e.g.
+/// *AudioProcessingFlags::ENABLE_DEFAULT | AudioProcessingFlags::DISABLE_GAIN_CONTROL*.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AudioProcessingFlags(i32);
+
+impl AudioProcessingFlags {
+    /// Disables built-in input audio processing.
+    pub const NONE: AudioProcessingFlags = AudioProcessingFlags(0);
+
+    /// Enables default built-in input audio processing,
+    /// i.e. noise suppression, echo cancellation, dereverberation and automatic gain control.
+    pub const ENABLE_DEFAULT: AudioProcessingFlags = AudioProcessingFlags(1);
+
+    /// Disables dereverberation in the default audio processing pipeline.
+    pub const DISABLE_DEREVERBERATION: AudioProcessingFlags = AudioProcessingFlags(2);
+
+    /// Disables noise suppression in the default audio processing pipeline.
+    pub const DISABLE_NOISE_SUPPRESSION: AudioProcessingFlags = AudioProcessingFlags(4);
+
+    /// Disables automatic gain control in the default audio processing pipeline.
+    pub const DISABLE_GAIN_CONTROL: AudioProcessingFlags = AudioProcessingFlags(8);
+
+    /// Disables echo cancellation in the default audio processing pipeline.
+    pub const DISABLE_ECHO_CANCELLATION: AudioProcessingFlags = AudioProcessingFlags(16);
+
+    /// Enables voice activity detection in input audio processing.
+    pub const ENABLE_VOICE_ACTIVITY_DETECTION: AudioProcessingFlags = AudioProcessingFlags(32);
+
+    /// Enables the new version (V2) of input audio processing with improved echo cancellation performance.
+    /// This flag is mutually exclusive with *ENABLE_DEFAULT* flag.
+    pub const ENABLE_V2: AudioProcessingFlags = AudioProcessingFlags(64);
+
+    /// Returns true if all flags of *other* are set.
+    pub fn contains(&self, other: AudioProcessingFlags) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    pub fn bits(&self) -> i32 {
+        self.0
+    }
+}
+
+impl BitOr for AudioProcessingFlags {
+    type Output = AudioProcessingFlags;
+
+    fn bitor(self, rhs: AudioProcessingFlags) -> AudioProcessingFlags {
+        AudioProcessingFlags(self.0 | rhs.0)
+    }
+}
+
+impl BitOrAssign for AudioProcessingFlags {
+    fn bitor_assign(&mut self, rhs: AudioProcessingFlags) {
+        self.0 |= rhs.0;
+    }
+}
+
+impl From<i32> for AudioProcessingFlags {
+    fn from(value: i32) -> Self {
+        AudioProcessingFlags(value)
+    }
+}
@@ -0,0 +1,249 @@
+use crate::audio::{
+    AudioProcessingFlags, MicrophoneArrayGeometry, MicrophoneArrayType, MicrophoneCoordinates,
+    PresetMicrophoneArrayGeometry, SpeakerReferenceChannel,
+};
+use crate::common::PropertyCollection;
+use crate::error::{convert_err, Result};
+use crate::ffi::{
+    audio_processing_options_create,
+    audio_processing_options_create_from_microphone_array_geometry,
+    audio_processing_options_create_from_preset_microphone_array_geometry,
+    audio_processing_options_get_audio_processing_flags,
+    audio_processing_options_get_beamforming_end_angle,
+    audio_processing_options_get_beamforming_start_angle,
+    audio_processing_options_get_microphone_array_type,
+    audio_processing_options_get_microphone_coordinates,
+    audio_processing_options_get_microphone_count,
+    audio_processing_options_get_preset_microphone_array_geometry,
+    audio_processing_options_get_property_bag,
+    audio_processing_options_get_speaker_reference_channel, audio_processing_options_release,
+    AudioProcessingOptions_MicrophoneArrayGeometry, AudioProcessingOptions_MicrophoneCoordinates,
+    SmartHandle, SPXAUDIOPROCESSINGOPTIONSHANDLE, SPXPROPERTYBAGHANDLE,
+};
+use std::convert::TryFrom;
+use std::mem::MaybeUninit;
+
+/// AudioProcessingOptions represents audio processing options used with *AudioConfig*,
+/// e.g. echo cancellation, noise suppression or beamforming with microphone arrays.
+#[derive(Debug)]
+pub struct AudioProcessingOptions {
+    pub handle: SmartHandle<SPXAUDIOPROCESSINGOPTIONSHANDLE>,
+    pub properties: PropertyCollection,
+}
+
+impl AudioProcessingOptions {
+    /// # Safety
+    /// `handle` must be a valid handle to a live audio processing options.
+    pub(crate) unsafe fn from_handle(
+        handle: SPXAUDIOPROCESSINGOPTIONSHANDLE,
+    ) -> Result<AudioProcessingOptions> {
+        unsafe {
+            let mut prop_bag_handle: MaybeUninit<SPXPROPERTYBAGHANDLE> = MaybeUninit::uninit();
+            let ret =
+                audio_processing_options_get_property_bag(handle, prop_bag_handle.as_mut_ptr());
+            convert_err(ret, "AudioProcessingOptions::from_handle error")?;
+
+            let properties = PropertyCollection::from_handle(prop_bag_handle.assume_init());
+
+            Ok(AudioProcessingOptions {
+                handle: SmartHandle::create(
+                    "AudioProcessingOptions",
+                    handle,
+                    audio_processing_options_release,
+                ),
+                properties,
+            })
+        }
+    }
+
+    /// Creates audio processing options with given flags.
+    /// Input is expected to be mono (single microphone) without speaker reference channel.
+    pub fn new(flags: AudioProcessingFlags) -> Result<AudioProcessingOptions> {
+        unsafe {
+            let mut handle: MaybeUninit<SPXAUDIOPROCESSINGOPTIONSHANDLE> = MaybeUninit::uninit();
+            let ret = audio_processing_options_create(handle.as_mut_ptr(), flags.bits());
+            convert_err(ret, "AudioProcessingOptions::new error")?;
+            AudioProcessingOptions::from_handle(handle.assume_init())
+        }
+    }
+
+    /// Creates audio processing options with given flags and preset microphone array geometry.
+    pub fn from_preset_microphone_array_geometry(
+        flags: AudioProcessingFlags,
+        geometry: PresetMicrophoneArrayGeometry,
+        speaker_reference_channel: SpeakerReferenceChannel,
+    ) -> Result<AudioProcessingOptions> {
+        unsafe {
+            let mut handle: MaybeUninit<SPXAUDIOPROCESSINGOPTIONSHANDLE> = MaybeUninit::uninit();
+            let ret = audio_processing_options_create_from_preset_microphone_array_geometry(
+                handle.as_mut_ptr(),
+                flags.bits(),
+                geometry as u32,
+                speaker_reference_channel as u32,
+            );
+            convert_err(
+                ret,
+                "AudioProcessingOptions::from_preset_microphone_array_geometry error",
+            )?;
+            AudioProcessingOptions::from_handle(handle.assume_init())
+        }
+    }
+
+    /// Creates audio processing options with given flags and custom microphone array geometry.
+    pub fn from_microphone_array_geometry(
+        flags: AudioProcessingFlags,
+        geometry: &MicrophoneArrayGeometry,
+        speaker_reference_channel: SpeakerReferenceChannel,
+    ) -> Result<AudioProcessingOptions> {
+        unsafe {
+            let mut coordinates: Vec<AudioProcessingOptions_MicrophoneCoordinates> = geometry
+                .microphone_coordinates
+                .iter()
+                .map(|c| AudioProcessingOptions_MicrophoneCoordinates {
+                    X: c.x,
+                    Y: c.y,
+                    Z: c.z,
+                })
+                .collect();
+            let native_geometry = AudioProcessingOptions_MicrophoneArrayGeometry {
+                microphoneArrayType: geometry.array_type as u32,
+                beamformingStartAngle: geometry.beamforming_start_angle,
+                beamformingEndAngle: geometry.beamforming_end_angle,
+                numberOfMicrophones: u16::try_from(coordinates.len())?,
+                microphoneCoordinates: coordinates.as_mut_ptr(),
+            };
+            let mut handle: MaybeUninit<SPXAUDIOPROCESSINGOPTIONSHANDLE> = MaybeUninit::uninit();
+            let ret = audio_processing_options_create_from_microphone_array_geometry(
+                handle.as_mut_ptr(),
+                flags.bits(),
+                &native_geometry,
+                speaker_reference_channel as u32,
+            );
+            convert_err(
+                ret,
+                "AudioProcessingOptions::from_microphone_array_geometry error",
+            )?;
+            AudioProcessingOptions::from_handle(handle.assume_init())
+        }
+    }
+
+    pub fn get_audio_processing_flags(&self) -> Result<AudioProcessingFlags> {
+        unsafe {
+            let mut flags = 0;
+            let ret = audio_processing_options_get_audio_processing_flags(
+                self.handle.inner(),
+                &mut flags,
+            );
+            convert_err(
+                ret,
+                "AudioProcessingOptions.get_audio_processing_flags error",
+            )?;
+            Ok(flags.into())
+        }
+    }
+
+    pub fn get_preset_microphone_array_geometry(&self) -> Result<PresetMicrophoneArrayGeometry> {
+        unsafe {
+            let mut geometry = 0;
+            let ret = audio_processing_options_get_preset_microphone_array_geometry(
+                self.handle.inner(),
+                &mut geometry,
+            );
+            convert_err(
+                ret,
+                "AudioProcessingOptions.get_preset_microphone_array_geometry error",
+            )?;
+            Ok(geometry.into())
+        }
+    }
+
+    pub fn get_microphone_array_type(&self) -> Result<MicrophoneArrayType> {
+        unsafe {
+            let mut array_type = 0;
+            let ret = audio_processing_options_get_microphone_array_type(
+                self.handle.inner(),
+                &mut array_type,
+            );
+            convert_err(
+                ret,
+                "AudioProcessingOptions.get_microphone_array_type error",
+            )?;
+            Ok(array_type.into())
+        }
+    }
+
+    /// Returns start angle for beamforming in degrees.
+    pub fn get_beamforming_start_angle(&self) -> Result<u16> {
+        unsafe {
+            let mut angle = 0;
+            let ret = audio_processing_options_get_beamforming_start_angle(
+                self.handle.inner(),
+                &mut angle,
+            );
+            convert_err(
+                ret,
+                "AudioProcessingOptions.get_beamforming_start_angle error",
+            )?;
+            Ok(angle)
+        }
+    }
+
+    /// Returns end angle for beamforming in degrees.
+    pub fn get_beamforming_end_angle(&self) -> Result<u16> {
+        unsafe {
+            let mut angle = 0;
+            let ret =
+                audio_processing_options_get_beamforming_end_angle(self.handle.inner(), &mut angle);
+            convert_err(
+                ret,
+                "AudioProcessingOptions.get_beamforming_end_angle error",
+            )?;
+            Ok(angle)
+        }
+    }
+
+    pub fn get_microphone_coordinates(&self) -> Result<Vec<MicrophoneCoordinates>> {
+        unsafe {
+            let mut count = 0;
+            let mut ret =
+                audio_processing_options_get_microphone_count(self.handle.inner(), &mut count);
+            convert_err(
+                ret,
+                "AudioProcessingOptions.get_microphone_coordinates(count) error",
+            )?;
+            let mut coordinates =
+                vec![
+                    AudioProcessingOptions_MicrophoneCoordinates { X: 0, Y: 0, Z: 0 };
+                    count as usize
+                ];
+            ret = audio_processing_options_get_microphone_coordinates(
+                self.handle.inner(),
+                coordinates.as_mut_ptr(),
+                count,
+            );
+            convert_err(
+                ret,
+                "AudioProcessingOptions.get_microphone_coordinates error",
+            )?;
+            Ok(coordinates
+                .iter()
+                .map(|c| MicrophoneCoordinates::new(c.X, c.Y, c.Z))
+                .collect())
+        }
+    }
+
+    pub fn get_speaker_reference_channel(&self) -> Result<SpeakerReferenceChannel> {
+        unsafe {
+            let mut channel = 0;
+            let ret = audio_processing_options_get_speaker_reference_channel(
+                self.handle.inner(),
+                &mut channel,
+            );
+            convert_err(
+                ret,
+                "AudioProcessingOptions.get_speaker_reference_channel error",
+            )?;
+            Ok(channel.into())
+        }
+    }
+}
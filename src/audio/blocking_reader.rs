@@ -0,0 +1,94 @@
+use crate::error::Result;
+use crate::ffi::{SharedHandle, SPXAUDIOSTREAMHANDLE};
+use std::future::Future;
+use std::io;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use tokio::io::ReadBuf;
+use tokio::task::JoinHandle;
+
+/// Reads at most given number of bytes from native stream. Blocks until
+/// data are available, returns empty vector once the stream is finished.
+pub(crate) type BlockingReadFn = fn(SPXAUDIOSTREAMHANDLE, usize) -> Result<Vec<u8>>;
+
+/// Implements *AsyncRead* on top of blocking native stream read.
+/// Native read is executed on tokio blocking thread pool, the task owns clone
+/// of the handle so that stream is not released while the read is still in progress.
+/// Data not fitting into caller's buffer are kept for the next read.
+#[derive(Debug)]
+pub(crate) struct BlockingReader {
+    handle: SharedHandle<SPXAUDIOSTREAMHANDLE>,
+    read_fn: BlockingReadFn,
+    pending_read: Option<JoinHandle<Result<Vec<u8>>>>,
+    read_buffer: Vec<u8>,
+}
+
+impl BlockingReader {
+    pub(crate) fn new(handle: SharedHandle<SPXAUDIOSTREAMHANDLE>, read_fn: BlockingReadFn) -> Self {
+        BlockingReader {
+            handle,
+            read_fn,
+            pending_read: None,
+            read_buffer: Vec::new(),
+        }
+    }
+
+    /// Returns true while native read started by *poll_read* is in progress.
+    pub(crate) fn is_reading(&self) -> bool {
+        self.pending_read.is_some()
+    }
+
+    /// Returns number of bytes already read from native stream
+    /// but not yet returned to the caller.
+    pub(crate) fn buffered_len(&self) -> usize {
+        self.read_buffer.len()
+    }
+
+    /// Moves already read data into *buf*, returns number of bytes moved.
+    pub(crate) fn take_buffered(&mut self, buf: &mut [u8]) -> usize {
+        let len = self.read_buffer.len().min(buf.len());
+        buf[..len].copy_from_slice(&self.read_buffer[..len]);
+        self.read_buffer.drain(..len);
+        len
+    }
+
+    /// Drops already read data, e.g. when position of native stream changes.
+    pub(crate) fn discard_buffered(&mut self) {
+        self.read_buffer.clear();
+    }
+
+    pub(crate) fn poll_read(
+        &mut self,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        if self.read_buffer.is_empty() {
+            if buf.remaining() == 0 {
+                return Poll::Ready(Ok(()));
+            }
+            if self.pending_read.is_none() {
+                let handle = self.handle.clone();
+                let read_fn = self.read_fn;
+                let size = buf.remaining().min(u32::MAX as usize);
+                self.pending_read = Some(tokio::task::spawn_blocking(move || {
+                    read_fn(handle.inner(), size)
+                }));
+            }
+            let pending_read = self.pending_read.as_mut().unwrap();
+            let data = match Pin::new(pending_read).poll(cx) {
+                Poll::Pending => return Poll::Pending,
+                Poll::Ready(result) => {
+                    self.pending_read = None;
+                    result
+                        .map_err(io::Error::other)?
+                        .map_err(io::Error::other)?
+                }
+            };
+            self.read_buffer = data;
+        }
+        let len = self.read_buffer.len().min(buf.remaining());
+        buf.put_slice(&self.read_buffer[..len]);
+        self.read_buffer.drain(..len);
+        Poll::Ready(Ok(()))
+    }
+}
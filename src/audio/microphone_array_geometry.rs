@@ -0,0 +1,61 @@
+use crate::audio::MicrophoneArrayType;
+
+/// MicrophoneCoordinates represents coordinates of a microphone in millimeters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct MicrophoneCoordinates {
+    pub x: i32,
+    pub y: i32,
+    pub z: i32,
+}
+
+impl MicrophoneCoordinates {
+    pub fn new(x: i32, y: i32, z: i32) -> Self {
+        MicrophoneCoordinates { x, y, z }
+    }
+}
+
+/// MicrophoneArrayGeometry represents custom geometry of a microphone array.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MicrophoneArrayGeometry {
+    pub array_type: MicrophoneArrayType,
+    /// Start angle for beamforming in degrees.
+    pub beamforming_start_angle: u16,
+    /// End angle for beamforming in degrees.
+    pub beamforming_end_angle: u16,
+    pub microphone_coordinates: Vec<MicrophoneCoordinates>,
+}
+
+impl MicrophoneArrayGeometry {
+    /// Creates microphone array geometry with default beamforming angles,
+    /// i.e. 0 - 180 degrees for linear arrays and 0 - 360 degrees for planar arrays.
+    pub fn new(
+        array_type: MicrophoneArrayType,
+        microphone_coordinates: Vec<MicrophoneCoordinates>,
+    ) -> Self {
+        let beamforming_end_angle = match array_type {
+            MicrophoneArrayType::Linear => 180,
+            MicrophoneArrayType::Planar => 360,
+        };
+        MicrophoneArrayGeometry {
+            array_type,
+            beamforming_start_angle: 0,
+            beamforming_end_angle,
+            microphone_coordinates,
+        }
+    }
+
+    /// Creates microphone array geometry with given beamforming angles in degrees.
+    pub fn with_beamforming_angles(
+        array_type: MicrophoneArrayType,
+        beamforming_start_angle: u16,
+        beamforming_end_angle: u16,
+        microphone_coordinates: Vec<MicrophoneCoordinates>,
+    ) -> Self {
+        MicrophoneArrayGeometry {
+            array_type,
+            beamforming_start_angle,
+            beamforming_end_angle,
+            microphone_coordinates,
+        }
+    }
+}
@@ -0,0 +1,24 @@
+/// MicrophoneArrayType defines the type of a microphone array.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MicrophoneArrayType {
+    /// Linear indicates that the microphone array has microphones in a straight line.
+    Linear = 0,
+
+    /// Planar indicates that the microphone array has microphones in a plane.
+    Planar = 1,
+}
+
+impl MicrophoneArrayType {
+    pub fn from_u32(array_type: u32) -> Self {
+        match array_type {
+            0 => MicrophoneArrayType::Linear,
+            _ => MicrophoneArrayType::Planar,
+        }
+    }
+}
+
+impl From<u32> for MicrophoneArrayType {
+    fn from(value: u32) -> Self {
+        MicrophoneArrayType::from_u32(value)
+    }
+}
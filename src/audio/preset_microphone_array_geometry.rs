@@ -0,0 +1,50 @@
+/// PresetMicrophoneArrayGeometry defines preset microphone array geometries.
+/// See [microphone array recommendations](https://docs.microsoft.com/azure/cognitive-services/speech-service/speech-devices-sdk-microphone)
+/// for details.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PresetMicrophoneArrayGeometry {
+    /// Uninitialized indicates that the microphone array geometry was not specified.
+    Uninitialized = 0,
+
+    /// Circular7 indicates a microphone array with one microphone in the center
+    /// and six microphones evenly spaced in a circle with radius approximately equal to 42.5 mm.
+    Circular7 = 1,
+
+    /// Circular4 indicates a microphone array with one microphone in the center
+    /// and three microphones evenly spaced in a circle with radius approximately equal to 42.5 mm.
+    Circular4 = 2,
+
+    /// Linear4 indicates a microphone array with four linearly placed microphones
+    /// with 40 mm spacing between them.
+    Linear4 = 3,
+
+    /// Linear2 indicates a microphone array with two linearly placed microphones
+    /// with 40 mm spacing between them.
+    Linear2 = 4,
+
+    /// Mono indicates a microphone array with a single microphone.
+    Mono = 5,
+
+    /// Custom indicates a microphone array with custom geometry.
+    Custom = 6,
+}
+
+impl PresetMicrophoneArrayGeometry {
+    pub fn from_u32(geometry: u32) -> Self {
+        match geometry {
+            1 => PresetMicrophoneArrayGeometry::Circular7,
+            2 => PresetMicrophoneArrayGeometry::Circular4,
+            3 => PresetMicrophoneArrayGeometry::Linear4,
+            4 => PresetMicrophoneArrayGeometry::Linear2,
+            5 => PresetMicrophoneArrayGeometry::Mono,
+            6 => PresetMicrophoneArrayGeometry::Custom,
+            _ => PresetMicrophoneArrayGeometry::Uninitialized,
+        }
+    }
+}
+
+impl From<u32> for PresetMicrophoneArrayGeometry {
+    fn from(value: u32) -> Self {
+        PresetMicrophoneArrayGeometry::from_u32(value)
+    }
+}
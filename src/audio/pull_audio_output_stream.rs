@@ -1,17 +1,22 @@
-use crate::audio::AudioOutputStream;
+use crate::audio::{AudioOutputStream, BlockingReader};
 use crate::error::{convert_err, Result};
 use crate::ffi::{
     audio_stream_create_pull_audio_output_stream, audio_stream_release,
-    pull_audio_output_stream_read, SmartHandle, SPXAUDIOSTREAMHANDLE,
+    pull_audio_output_stream_read, SharedHandle, SmartHandle, SPXAUDIOSTREAMHANDLE,
 };
 use std::convert::TryFrom;
+use std::io;
 use std::mem::MaybeUninit;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use tokio::io::{AsyncRead, ReadBuf};
 
 /// PullAudioOutputStream represents audio output stream with audio data pulled (read) by service calling Speech Synthetizer via *read* method.
 /// Speech Synthetizer's caller is activelly pulling (reading) already synthetized audio data.
 #[derive(Debug)]
 pub struct PullAudioOutputStream {
-    pub handle: SmartHandle<SPXAUDIOSTREAMHANDLE>,
+    handle: SharedHandle<SPXAUDIOSTREAMHANDLE>,
+    reader: BlockingReader,
 }
 
 impl AudioOutputStream for PullAudioOutputStream {
@@ -24,8 +29,18 @@ impl PullAudioOutputStream {
     /// # Safety
     /// `handle` must be a valid handle to a live pull audio output stream.
     pub unsafe fn from_handle(handle: SPXAUDIOSTREAMHANDLE) -> Result<Self> {
+        // SAFETY: native pull stream buffer is synchronized, it is written
+        // by synthesizer thread while being read by application
+        let handle = SharedHandle::new(SmartHandle::create(
+            "PullAudioOutputStream",
+            handle,
+            audio_stream_release,
+        ));
         Ok(PullAudioOutputStream {
-            handle: SmartHandle::create("PullAudioOutputStream", handle, audio_stream_release),
+            reader: BlockingReader::new(handle.clone(), |handle, size| {
+                PullAudioOutputStream::read_from_handle(handle, size as u32)
+            }),
+            handle,
         })
     }
 
@@ -42,13 +57,16 @@ impl PullAudioOutputStream {
     /// The maximal number of bytes to be read is determined from the size parameter.
     /// If there is no data immediately available, read() blocks until the next data becomes available.
     pub fn read(&self, size: u32) -> Result<Vec<u8>> {
+        PullAudioOutputStream::read_from_handle(self.handle.inner(), size)
+    }
+
+    fn read_from_handle(handle: SPXAUDIOSTREAMHANDLE, size: u32) -> Result<Vec<u8>> {
         unsafe {
             let mut buf_vec = vec![0u8; size as usize];
             let c_buf: *mut u8 = &mut buf_vec[..] as *const _ as *mut u8;
 
             let mut filled_size: u32 = 0;
-            let ret =
-                pull_audio_output_stream_read(self.handle.inner(), c_buf, size, &mut filled_size);
+            let ret = pull_audio_output_stream_read(handle, c_buf, size, &mut filled_size);
             convert_err(ret, "PullAudioOutputStream.read error")?;
 
             let converted_size = usize::try_from(filled_size)?;
@@ -57,3 +75,16 @@ impl PullAudioOutputStream {
         }
     }
 }
+
+/// Reads synthesized audio from the stream, e.g. to copy it into HTTP response
+/// via *tokio::io::copy*. Since native read blocks until data are available
+/// it is executed on tokio blocking thread pool. Returns 0 (EOF) once synthesis is done.
+impl AsyncRead for PullAudioOutputStream {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        self.reader.poll_read(cx, buf)
+    }
+}
@@ -0,0 +1,144 @@
+use crate::audio::PullAudioInputStreamCallbacks;
+use crate::error::Result;
+use log::*;
+use std::fs::File;
+use std::io::{BufReader, Cursor, Read};
+use std::thread;
+use std::time::{Duration, Instant};
+use tokio::sync::mpsc::Receiver;
+
+/// Throttles reads so that data are delivered no faster than
+/// given byte rate, i.e. the way live microphone would deliver them.
+#[derive(Debug)]
+struct Pacing {
+    bytes_per_second: u32,
+    started: Option<Instant>,
+    bytes_delivered: u64,
+}
+
+impl Pacing {
+    fn wait(&mut self, bytes: usize) {
+        let started = *self.started.get_or_insert_with(Instant::now);
+        self.bytes_delivered += bytes as u64;
+        let due = Duration::from_secs_f64(
+            self.bytes_delivered as f64 / f64::from(self.bytes_per_second.max(1)),
+        );
+        let elapsed = started.elapsed();
+        if due > elapsed {
+            thread::sleep(due - elapsed);
+        }
+    }
+}
+
+/// Pull audio input stream source reading audio data from any *std::io::Read*,
+/// e.g. file or in-memory buffer. Data are passed to the recognizer as they are,
+/// i.e. they must match the format of pull stream. Optionally reads can be paced
+/// in real time to simulate live microphone (see *set_real_time_pacing*).
+#[derive(Debug)]
+pub struct ReaderPullSource<R> {
+    reader: R,
+    pacing: Option<Pacing>,
+}
+
+impl<R: Read + Send> ReaderPullSource<R> {
+    pub fn new(reader: R) -> Self {
+        ReaderPullSource {
+            reader,
+            pacing: None,
+        }
+    }
+
+    /// Delivers data no faster than given number of bytes per second,
+    /// e.g. 32000 for 16kHz 16bit mono PCM.
+    pub fn set_real_time_pacing(&mut self, bytes_per_second: u32) {
+        self.pacing = Some(Pacing {
+            bytes_per_second,
+            started: None,
+            bytes_delivered: 0,
+        });
+    }
+}
+
+impl ReaderPullSource<BufReader<File>> {
+    pub fn from_file(filename: &str) -> Result<Self> {
+        Ok(ReaderPullSource::new(BufReader::new(File::open(filename)?)))
+    }
+}
+
+impl ReaderPullSource<Cursor<Vec<u8>>> {
+    pub fn from_bytes(data: Vec<u8>) -> Self {
+        ReaderPullSource::new(Cursor::new(data))
+    }
+}
+
+impl<R: Read + Send> PullAudioInputStreamCallbacks for ReaderPullSource<R> {
+    fn read(&mut self, data_buffer: &mut [u8]) -> u32 {
+        let mut filled = 0;
+        // fill whole buffer unless end of stream is reached
+        while filled < data_buffer.len() {
+            match self.reader.read(&mut data_buffer[filled..]) {
+                Ok(0) => break,
+                Ok(n) => filled += n,
+                Err(err) if err.kind() == std::io::ErrorKind::Interrupted => continue,
+                Err(err) => {
+                    error!("ReaderPullSource.read error {:?}", err);
+                    break;
+                }
+            }
+        }
+        if let Some(pacing) = &mut self.pacing {
+            pacing.wait(filled);
+        }
+        filled as u32
+    }
+
+    fn close(&mut self) {
+        debug!("ReaderPullSource.close called");
+    }
+
+    fn get_property(&mut self, _id: i32) -> Result<String> {
+        Ok("".to_owned())
+    }
+}
+
+/// Pull audio input stream source receiving audio chunks from bounded
+/// *tokio::sync::mpsc* channel. Read blocks until next chunk arrives,
+/// stream ends when all senders are dropped.
+#[derive(Debug)]
+pub struct ChannelPullSource {
+    receiver: Receiver<Vec<u8>>,
+    pending: Vec<u8>,
+}
+
+impl ChannelPullSource {
+    pub fn new(receiver: Receiver<Vec<u8>>) -> Self {
+        ChannelPullSource {
+            receiver,
+            pending: Vec::new(),
+        }
+    }
+}
+
+impl PullAudioInputStreamCallbacks for ChannelPullSource {
+    fn read(&mut self, data_buffer: &mut [u8]) -> u32 {
+        while self.pending.is_empty() {
+            match self.receiver.blocking_recv() {
+                Some(chunk) => self.pending = chunk,
+                None => return 0,
+            }
+        }
+        let len = self.pending.len().min(data_buffer.len());
+        data_buffer[..len].copy_from_slice(&self.pending[..len]);
+        self.pending.drain(..len);
+        len as u32
+    }
+
+    fn close(&mut self) {
+        debug!("ChannelPullSource.close called");
+        self.receiver.close();
+    }
+
+    fn get_property(&mut self, _id: i32) -> Result<String> {
+        Ok("".to_owned())
+    }
+}
@@ -9,7 +9,11 @@ use crate::ffi::{
 };
 use log::*;
 use std::ffi::CString;
+use std::io;
 use std::mem::MaybeUninit;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use tokio::io::AsyncWrite;
 
 /// PushAudioInputStream represents audio input stream with audio data pushed by audio producer *write* method.
 /// Passing audio input is controlled by audio producer.
@@ -123,3 +127,33 @@ impl PushAudioInputStream {
         }
     }
 }
+
+/// Writes audio data into the stream so that audio can be copied into recognizer
+/// from any async source, e.g. via *tokio::io::copy*. Writing never blocks since
+/// data are buffered by the stream, shutdown closes the stream (see *close_stream*).
+impl AsyncWrite for PushAudioInputStream {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        _cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        if buf.is_empty() {
+            return Poll::Ready(Ok(0));
+        }
+        let len = buf.len().min(u32::MAX as usize);
+        Poll::Ready(
+            self.get_mut()
+                .write(&buf[..len])
+                .map(|_| len)
+                .map_err(io::Error::other),
+        )
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Poll::Ready(self.close_stream().map_err(io::Error::other))
+    }
+}
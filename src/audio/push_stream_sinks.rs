@@ -0,0 +1,176 @@
+use crate::audio::PushAudioOutputStreamCallbacks;
+use crate::error::Result;
+use log::*;
+use std::convert::TryFrom;
+use std::fs::File;
+use std::io::{BufWriter, Seek, SeekFrom, Write};
+use std::sync::{Arc, Mutex};
+use tokio::sync::mpsc::Sender;
+
+/// Push audio output stream sink collecting synthesized audio into *Vec<u8>*.
+/// Collected data can be accessed via buffer returned by *get_buffer*
+/// (call it before passing sink into *PushAudioOutputStream::set_callbacks*).
+#[derive(Debug, Default)]
+pub struct VecPushSink {
+    buffer: Arc<Mutex<Vec<u8>>>,
+}
+
+impl VecPushSink {
+    pub fn new() -> Self {
+        VecPushSink::default()
+    }
+
+    pub fn get_buffer(&self) -> Arc<Mutex<Vec<u8>>> {
+        self.buffer.clone()
+    }
+}
+
+impl PushAudioOutputStreamCallbacks for VecPushSink {
+    fn write(&mut self, data_buffer: &[u8]) -> u32 {
+        match self.buffer.lock() {
+            Ok(mut buffer) => {
+                buffer.extend_from_slice(data_buffer);
+                data_buffer.len() as u32
+            }
+            Err(err) => {
+                error!("VecPushSink.write error {:?}", err);
+                0
+            }
+        }
+    }
+
+    fn close(&mut self) {
+        debug!("VecPushSink.close called");
+    }
+}
+
+const WAV_HEADER_SIZE: u32 = 44;
+/// RIFF chunk size (data size + rest of the header) must fit into u32.
+const MAX_WAV_DATA_SIZE: u32 = u32::MAX - (WAV_HEADER_SIZE - 8);
+
+/// Push audio output stream sink writing synthesized PCM audio into WAV file.
+/// Synthesizer output format must be raw PCM matching parameters of the sink
+/// (e.g. *SpeechSynthesisOutputFormat::Raw16Khz16BitMonoPcm*). WAV header
+/// is finalized when the stream is closed. WAV file can hold at most 4GiB
+/// of audio, data exceeding the limit are dropped.
+#[derive(Debug)]
+pub struct WavFilePushSink {
+    writer: BufWriter<File>,
+    samples_per_second: u32,
+    bits_per_sample: u16,
+    channels: u16,
+    data_size: u32,
+}
+
+impl WavFilePushSink {
+    /// Creates WAV file. Bits per sample defaults to 16, channels to 1.
+    pub fn create(
+        filename: &str,
+        samples_per_second: u32,
+        bits_per_sample: Option<u8>,
+        channels: Option<u8>,
+    ) -> Result<Self> {
+        let mut sink = WavFilePushSink {
+            writer: BufWriter::new(File::create(filename)?),
+            samples_per_second,
+            bits_per_sample: u16::from(bits_per_sample.unwrap_or(16)),
+            channels: u16::from(channels.unwrap_or(1)),
+            data_size: 0,
+        };
+        sink.write_header()?;
+        Ok(sink)
+    }
+
+    fn write_header(&mut self) -> Result<()> {
+        let block_align = self.channels * self.bits_per_sample / 8;
+        let byte_rate = self.samples_per_second * u32::from(block_align);
+        let writer = &mut self.writer;
+        writer.seek(SeekFrom::Start(0))?;
+        writer.write_all(b"RIFF")?;
+        writer.write_all(&(WAV_HEADER_SIZE - 8 + self.data_size).to_le_bytes())?;
+        writer.write_all(b"WAVEfmt ")?;
+        writer.write_all(&16u32.to_le_bytes())?;
+        // PCM format tag
+        writer.write_all(&1u16.to_le_bytes())?;
+        writer.write_all(&self.channels.to_le_bytes())?;
+        writer.write_all(&self.samples_per_second.to_le_bytes())?;
+        writer.write_all(&byte_rate.to_le_bytes())?;
+        writer.write_all(&block_align.to_le_bytes())?;
+        writer.write_all(&self.bits_per_sample.to_le_bytes())?;
+        writer.write_all(b"data")?;
+        writer.write_all(&self.data_size.to_le_bytes())?;
+        Ok(())
+    }
+
+    fn finalize(&mut self) -> Result<()> {
+        self.write_header()?;
+        self.writer.seek(SeekFrom::End(0))?;
+        self.writer.flush()?;
+        Ok(())
+    }
+}
+
+impl PushAudioOutputStreamCallbacks for WavFilePushSink {
+    fn write(&mut self, data_buffer: &[u8]) -> u32 {
+        let data_size = u32::try_from(data_buffer.len())
+            .ok()
+            .and_then(|len| self.data_size.checked_add(len))
+            .filter(|data_size| *data_size <= MAX_WAV_DATA_SIZE);
+        let data_size = match data_size {
+            Some(data_size) => data_size,
+            None => {
+                error!("WavFilePushSink.write error: WAV file size limit reached");
+                return 0;
+            }
+        };
+        if let Err(err) = self.writer.write_all(data_buffer) {
+            error!("WavFilePushSink.write error {:?}", err);
+            return 0;
+        }
+        self.data_size = data_size;
+        data_buffer.len() as u32
+    }
+
+    fn close(&mut self) {
+        debug!("WavFilePushSink.close called");
+        if let Err(err) = self.finalize() {
+            error!("WavFilePushSink.close error {:?}", err);
+        }
+    }
+}
+
+/// Push audio output stream sink sending synthesized audio chunks into
+/// *tokio::sync::mpsc* channel. When channel is full synthesizer is blocked
+/// until receiver catches up. Channel is closed when the stream is closed.
+#[derive(Debug)]
+pub struct ChannelPushSink {
+    sender: Option<Sender<Vec<u8>>>,
+}
+
+impl ChannelPushSink {
+    pub fn new(sender: Sender<Vec<u8>>) -> Self {
+        ChannelPushSink {
+            sender: Some(sender),
+        }
+    }
+}
+
+impl PushAudioOutputStreamCallbacks for ChannelPushSink {
+    fn write(&mut self, data_buffer: &[u8]) -> u32 {
+        match &self.sender {
+            Some(sender) => match sender.blocking_send(data_buffer.to_vec()) {
+                Ok(()) => data_buffer.len() as u32,
+                Err(_) => {
+                    debug!("ChannelPushSink.write receiver dropped");
+                    0
+                }
+            },
+            None => 0,
+        }
+    }
+
+    fn close(&mut self) {
+        debug!("ChannelPushSink.close called");
+        self.sender = None;
+    }
+}
@@ -0,0 +1,25 @@
+/// SpeakerReferenceChannel defines the position of the speaker reference channel
+/// in the microphone input, used for echo cancellation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpeakerReferenceChannel {
+    /// None indicates that the input doesn't contain a speaker reference channel.
+    None = 0,
+
+    /// LastChannel indicates that the last channel of the input contains a speaker reference channel.
+    LastChannel = 1,
+}
+
+impl SpeakerReferenceChannel {
+    pub fn from_u32(channel: u32) -> Self {
+        match channel {
+            0 => SpeakerReferenceChannel::None,
+            _ => SpeakerReferenceChannel::LastChannel,
+        }
+    }
+}
+
+impl From<u32> for SpeakerReferenceChannel {
+    fn from(value: u32) -> Self {
+        SpeakerReferenceChannel::from_u32(value)
+    }
+}
@@ -0,0 +1,289 @@
+use crate::audio::{AudioConfig, AudioStreamFormat, PushAudioInputStream};
+use crate::error::{Error, ErrorRootCause, Result};
+use std::convert::TryFrom;
+
+/// Sample rate (Hz) of audio produced by *WavFileInput*.
+pub const WAV_INPUT_SAMPLES_PER_SECOND: u32 = 16000;
+
+const MIN_SAMPLES_PER_SECOND: u32 = 8000;
+const MAX_SAMPLES_PER_SECOND: u32 = 48000;
+/// Chunk size used when pushing audio into stream (100ms of 16kHz 16bit mono audio).
+const PUSH_CHUNK_SIZE: usize = 3200;
+/// Number of zero crossings of resampling kernel on each side of its center.
+const RESAMPLE_ZERO_CROSSINGS: u32 = 16;
+/// Low-pass cutoff as fraction of Nyquist frequency, leaves room for transition band.
+const RESAMPLE_ROLLOFF: f64 = 0.9;
+
+const WAVE_FORMAT_PCM: u16 = 0x0001;
+const WAVE_FORMAT_IEEE_FLOAT: u16 = 0x0003;
+const WAVE_FORMAT_EXTENSIBLE: u16 = 0xFFFE;
+
+/// Encoding of samples in WAV file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WavSampleFormat {
+    /// Integer PCM samples (8bit unsigned, 16/24/32bit signed).
+    Int,
+    /// IEEE float samples (32/64bit).
+    Float,
+}
+
+/// Format of audio data as found in WAV file header.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WavFormat {
+    pub samples_per_second: u32,
+    pub bits_per_sample: u16,
+    pub channels: u16,
+    pub sample_format: WavSampleFormat,
+}
+
+/// WavFileInput parses RIFF/WAVE file in Rust and converts its audio
+/// (8-48kHz, 8/16/24/32bit integer or 32/64bit float, any number of channels)
+/// into 16kHz 16bit mono PCM supported by speech service. Channels are downmixed
+/// by averaging, sample rate is converted by band-limited (windowed-sinc) interpolation.
+/// Converted audio is fed into recognizer via *PushAudioInputStream*,
+/// see *to_audio_config* and *push_into_stream*.
+#[derive(Debug, Clone)]
+pub struct WavFileInput {
+    pub source_format: WavFormat,
+    /// Converted 16kHz 16bit mono little endian PCM data.
+    pub data: Vec<u8>,
+}
+
+fn invalid_wav(message: &str) -> Error {
+    Error::new(
+        format!("WavFileInput: {}", message),
+        ErrorRootCause::InvalidWavFile,
+    )
+}
+
+fn read_u16(bytes: &[u8], pos: usize) -> u16 {
+    u16::from_le_bytes([bytes[pos], bytes[pos + 1]])
+}
+
+fn read_u32(bytes: &[u8], pos: usize) -> u32 {
+    u32::from_le_bytes([bytes[pos], bytes[pos + 1], bytes[pos + 2], bytes[pos + 3]])
+}
+
+impl WavFileInput {
+    pub fn from_file(filename: &str) -> Result<Self> {
+        let bytes = std::fs::read(filename)?;
+        WavFileInput::from_bytes(&bytes)
+    }
+
+    /// Parses and converts content of WAV file.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let (source_format, samples) = WavFileInput::parse(bytes)?;
+        let mono = downmix(&source_format, samples)?;
+        let resampled = resample(
+            &mono,
+            source_format.samples_per_second,
+            WAV_INPUT_SAMPLES_PER_SECOND,
+        );
+        let mut data = Vec::with_capacity(resampled.len() * 2);
+        for sample in resampled {
+            let sample = (sample.clamp(-1.0, 1.0) * f32::from(i16::MAX)).round() as i16;
+            data.extend_from_slice(&sample.to_le_bytes());
+        }
+        Ok(WavFileInput {
+            source_format,
+            data,
+        })
+    }
+
+    /// Returns format of converted audio data.
+    pub fn get_stream_format(&self) -> Result<AudioStreamFormat> {
+        AudioStreamFormat::get_wave_format_pcm(WAV_INPUT_SAMPLES_PER_SECOND, Some(16), Some(1))
+    }
+
+    /// Writes converted audio into push stream and closes the stream.
+    /// Stream must be created with format returned by *get_stream_format*.
+    pub fn push_into_stream(&self, stream: &mut PushAudioInputStream) -> Result<()> {
+        for chunk in self.data.chunks(PUSH_CHUNK_SIZE) {
+            stream.write(chunk)?;
+        }
+        stream.close_stream()
+    }
+
+    /// Creates audio config with push stream already containing converted audio.
+    pub fn to_audio_config(&self) -> Result<AudioConfig> {
+        let mut stream =
+            PushAudioInputStream::create_push_stream_from_format(self.get_stream_format()?)?;
+        let audio_config = AudioConfig::from_stream_input(&stream)?;
+        self.push_into_stream(&mut stream)?;
+        Ok(audio_config)
+    }
+
+    /// Parses RIFF/WAVE header and returns audio format together with raw sample data.
+    fn parse(bytes: &[u8]) -> Result<(WavFormat, &[u8])> {
+        if bytes.len() < 12 || &bytes[0..4] != b"RIFF" || &bytes[8..12] != b"WAVE" {
+            return Err(invalid_wav("missing RIFF/WAVE header"));
+        }
+        let mut format = None;
+        let mut pos = 12;
+        while pos + 8 <= bytes.len() {
+            let chunk_id = &bytes[pos..pos + 4];
+            let chunk_size = usize::try_from(read_u32(bytes, pos + 4))?;
+            let body_start = pos + 8;
+            match chunk_id {
+                b"fmt " => {
+                    if chunk_size < 16 || body_start + chunk_size > bytes.len() {
+                        return Err(invalid_wav("truncated fmt chunk"));
+                    }
+                    format = Some(WavFileInput::parse_format(
+                        &bytes[body_start..body_start + chunk_size],
+                    )?);
+                }
+                b"data" => {
+                    let format =
+                        format.ok_or_else(|| invalid_wav("data chunk before fmt chunk"))?;
+                    // tolerate data chunk size exceeding file length (e.g. unfinished recordings)
+                    let body_end = bytes.len().min(body_start + chunk_size);
+                    return Ok((format, &bytes[body_start..body_end]));
+                }
+                _ => {}
+            }
+            // chunks are word aligned
+            pos = body_start + chunk_size + (chunk_size & 1);
+        }
+        match format {
+            Some(_) => Err(invalid_wav("missing data chunk")),
+            None => Err(invalid_wav("missing fmt chunk")),
+        }
+    }
+
+    fn parse_format(fmt: &[u8]) -> Result<WavFormat> {
+        let mut format_tag = read_u16(fmt, 0);
+        let channels = read_u16(fmt, 2);
+        let samples_per_second = read_u32(fmt, 4);
+        let bits_per_sample = read_u16(fmt, 14);
+        if format_tag == WAVE_FORMAT_EXTENSIBLE {
+            if fmt.len() < 26 {
+                return Err(invalid_wav("truncated WAVE_FORMAT_EXTENSIBLE fmt chunk"));
+            }
+            // first two bytes of sub format GUID hold actual format tag
+            format_tag = read_u16(fmt, 24);
+        }
+        let sample_format = match (format_tag, bits_per_sample) {
+            (WAVE_FORMAT_PCM, 8)
+            | (WAVE_FORMAT_PCM, 16)
+            | (WAVE_FORMAT_PCM, 24)
+            | (WAVE_FORMAT_PCM, 32) => WavSampleFormat::Int,
+            (WAVE_FORMAT_IEEE_FLOAT, 32) | (WAVE_FORMAT_IEEE_FLOAT, 64) => WavSampleFormat::Float,
+            (WAVE_FORMAT_PCM, _) | (WAVE_FORMAT_IEEE_FLOAT, _) => {
+                return Err(invalid_wav(&format!(
+                    "unsupported bits per sample: {}",
+                    bits_per_sample
+                )))
+            }
+            _ => {
+                return Err(invalid_wav(&format!(
+                    "unsupported format tag: {:#06x}",
+                    format_tag
+                )))
+            }
+        };
+        if channels == 0 {
+            return Err(invalid_wav("number of channels is zero"));
+        }
+        if !(MIN_SAMPLES_PER_SECOND..=MAX_SAMPLES_PER_SECOND).contains(&samples_per_second) {
+            return Err(invalid_wav(&format!(
+                "unsupported sample rate: {}Hz (supported {}-{}Hz)",
+                samples_per_second, MIN_SAMPLES_PER_SECOND, MAX_SAMPLES_PER_SECOND
+            )));
+        }
+        Ok(WavFormat {
+            samples_per_second,
+            bits_per_sample,
+            channels,
+            sample_format,
+        })
+    }
+}
+
+/// Decodes samples into range -1.0..1.0 and averages channels of each frame.
+fn downmix(format: &WavFormat, data: &[u8]) -> Result<Vec<f32>> {
+    let sample_size = usize::from(format.bits_per_sample / 8);
+    let channels = usize::from(format.channels);
+    let frame_size = sample_size * channels;
+    if data.len() < frame_size {
+        return Err(invalid_wav("data chunk contains no complete audio frame"));
+    }
+    let decode = |sample: &[u8]| -> f32 {
+        match (format.sample_format, sample_size) {
+            (WavSampleFormat::Int, 1) => (f32::from(sample[0]) - 128.0) / 128.0,
+            (WavSampleFormat::Int, 2) => {
+                f32::from(i16::from_le_bytes([sample[0], sample[1]])) / 32768.0
+            }
+            (WavSampleFormat::Int, 3) => {
+                // shift 24bit sample to upper bytes of i32 to keep the sign
+                let value = i32::from_le_bytes([0, sample[0], sample[1], sample[2]]);
+                value as f32 / 2_147_483_648.0
+            }
+            (WavSampleFormat::Int, _) => {
+                let value = i32::from_le_bytes([sample[0], sample[1], sample[2], sample[3]]);
+                value as f32 / 2_147_483_648.0
+            }
+            (WavSampleFormat::Float, 4) => {
+                f32::from_le_bytes([sample[0], sample[1], sample[2], sample[3]])
+            }
+            (WavSampleFormat::Float, _) => f64::from_le_bytes([
+                sample[0], sample[1], sample[2], sample[3], sample[4], sample[5], sample[6],
+                sample[7],
+            ]) as f32,
+        }
+    };
+    Ok(data
+        .chunks_exact(frame_size)
+        .map(|frame| frame.chunks_exact(sample_size).map(decode).sum::<f32>() / channels as f32)
+        .collect())
+}
+
+/// Converts sample rate using windowed-sinc interpolation. Kernel is low-pass
+/// filter with cutoff just below Nyquist frequency of the lower of both rates,
+/// so that downsampling does not fold higher frequencies back into speech band.
+fn resample(samples: &[f32], from_rate: u32, to_rate: u32) -> Vec<f32> {
+    if from_rate == to_rate || samples.is_empty() {
+        return samples.to_vec();
+    }
+    let ratio = f64::from(from_rate) / f64::from(to_rate);
+    // cutoff relative to Nyquist frequency of input
+    let cutoff = RESAMPLE_ROLLOFF * ratio.recip().min(1.0);
+    // half width of kernel in input samples
+    let half_width = f64::from(RESAMPLE_ZERO_CROSSINGS) / cutoff;
+    let last = samples.len() - 1;
+    let out_len = ((samples.len() as f64) / ratio).floor() as usize;
+    (0..out_len)
+        .map(|i| {
+            let position = i as f64 * ratio;
+            let first = (position - half_width).ceil().max(0.0) as usize;
+            let end = ((position + half_width).floor() as usize).min(last);
+            let mut acc = 0.0;
+            let mut weight_sum = 0.0;
+            for (offset, sample) in samples[first..=end].iter().enumerate() {
+                let distance = position - (first + offset) as f64;
+                let weight = sinc_kernel(distance, cutoff, half_width);
+                acc += f64::from(*sample) * weight;
+                weight_sum += weight;
+            }
+            // normalize to unity gain, also compensates for kernel cut at signal edges
+            if weight_sum > 0.0 {
+                (acc / weight_sum) as f32
+            } else {
+                samples[position as usize]
+            }
+        })
+        .collect()
+}
+
+/// Blackman windowed sinc low-pass filter evaluated at distance (in input samples)
+/// from interpolated position.
+fn sinc_kernel(distance: f64, cutoff: f64, half_width: f64) -> f64 {
+    if distance.abs() >= half_width {
+        return 0.0;
+    }
+    let x = std::f64::consts::PI * distance * cutoff;
+    let sinc = if x == 0.0 { 1.0 } else { x.sin() / x };
+    let w = std::f64::consts::PI * distance / half_width;
+    let window = 0.42 + 0.5 * w.cos() + 0.08 * (2.0 * w).cos();
+    cutoff * sinc * window
+}
@@ -1,27 +1,43 @@
 //! Package common contains the definitions for many of the shared objects and properties in the Speech SDK.
 mod cancellation_error_code;
 mod cancellation_reason;
+mod event_stream;
 mod output_format;
 mod profanity_option;
+mod pronunciation_assessment_grading_system;
+mod pronunciation_assessment_granularity;
 mod property_collection;
 mod property_id;
+mod recognition_factor_scope;
 mod result_reason;
 mod service_property_channel;
+mod speech_backend;
 mod speech_synthesis_boundary_type;
 mod speech_synthesis_output_format;
 mod stream_status;
 mod synthesis_voice_type;
+mod ticks;
+mod token_provider;
 
 // re-export structs directly under common module
 pub use self::cancellation_error_code::CancellationErrorCode;
 pub use self::cancellation_reason::CancellationReason;
+pub(crate) use self::event_stream::event_channel;
+pub use self::event_stream::{EventBuffering, EventStream};
 pub use self::output_format::OutputFormat;
 pub use self::profanity_option::ProfanityOption;
+pub use self::pronunciation_assessment_grading_system::PronunciationAssessmentGradingSystem;
+pub use self::pronunciation_assessment_granularity::PronunciationAssessmentGranularity;
 pub use self::property_collection::PropertyCollection;
 pub use self::property_id::PropertyId;
+pub use self::recognition_factor_scope::RecognitionFactorScope;
 pub use self::result_reason::ResultReason;
 pub use self::service_property_channel::ServicePropertyChannel;
+pub use self::speech_backend::SpeechBackend;
 pub use self::speech_synthesis_boundary_type::SpeechSynthesisBoundaryType;
 pub use self::speech_synthesis_output_format::SpeechSynthesisOutputFormat;
 pub use self::stream_status::StreamStatus;
 pub use self::synthesis_voice_type::SynthesisVoiceType;
+pub use self::ticks::{Ticks, TICKS_PER_SECOND};
+pub(crate) use self::token_provider::apply_token;
+pub use self::token_provider::{CachingTokenProvider, TokenProvider, DEFAULT_TOKEN_REFRESH_MARGIN};
@@ -0,0 +1,122 @@
+use futures_core::Stream;
+use log::*;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use tokio::sync::mpsc::{
+    channel, error::TrySendError, unbounded_channel, Receiver, Sender, UnboundedReceiver,
+    UnboundedSender,
+};
+
+/// Defines how events are buffered between native callbacks
+/// and the consumer of *EventStream*.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum EventBuffering {
+    /// Events are never dropped, buffer grows as long as consumer lags behind.
+    #[default]
+    Unbounded,
+    /// At most given number of events is buffered. When buffer is full
+    /// native callback thread is blocked until consumer catches up (backpressure).
+    Bounded(usize),
+    /// At most given number of events is buffered. When buffer is full
+    /// new events are dropped.
+    DropNewest(usize),
+}
+
+/// Stream of events returned by *events* method of recognizers,
+/// synthesizer and dialog service connector. Stream ends when
+/// the object which produced it is dropped or its callbacks are replaced.
+#[derive(Debug)]
+pub struct EventStream<E> {
+    receiver: EventReceiver<E>,
+}
+
+#[derive(Debug)]
+enum EventReceiver<E> {
+    Unbounded(UnboundedReceiver<E>),
+    Bounded(Receiver<E>),
+}
+
+/// Sending part of *EventStream* used within native callbacks.
+#[derive(Debug)]
+pub(crate) struct EventSender<E> {
+    sender: EventSenderInner<E>,
+}
+
+#[derive(Debug)]
+enum EventSenderInner<E> {
+    Unbounded(UnboundedSender<E>),
+    Bounded(Sender<E>),
+    DropNewest(Sender<E>),
+}
+
+impl<E> Clone for EventSender<E> {
+    fn clone(&self) -> Self {
+        let sender = match &self.sender {
+            EventSenderInner::Unbounded(sender) => EventSenderInner::Unbounded(sender.clone()),
+            EventSenderInner::Bounded(sender) => EventSenderInner::Bounded(sender.clone()),
+            EventSenderInner::DropNewest(sender) => EventSenderInner::DropNewest(sender.clone()),
+        };
+        EventSender { sender }
+    }
+}
+
+/// Creates connected event sender and event stream.
+pub(crate) fn event_channel<E>(buffering: EventBuffering) -> (EventSender<E>, EventStream<E>) {
+    let (sender, receiver) = match buffering {
+        EventBuffering::Unbounded => {
+            let (sender, receiver) = unbounded_channel();
+            (
+                EventSenderInner::Unbounded(sender),
+                EventReceiver::Unbounded(receiver),
+            )
+        }
+        EventBuffering::Bounded(capacity) => {
+            let (sender, receiver) = channel(capacity.max(1));
+            (
+                EventSenderInner::Bounded(sender),
+                EventReceiver::Bounded(receiver),
+            )
+        }
+        EventBuffering::DropNewest(capacity) => {
+            let (sender, receiver) = channel(capacity.max(1));
+            (
+                EventSenderInner::DropNewest(sender),
+                EventReceiver::Bounded(receiver),
+            )
+        }
+    };
+    (EventSender { sender }, EventStream { receiver })
+}
+
+impl<E> EventSender<E> {
+    /// Sends event to the stream. Must not be called from within
+    /// async runtime since bounded sender might block.
+    pub(crate) fn send(&self, event: E) {
+        let sent = match &self.sender {
+            EventSenderInner::Unbounded(sender) => sender.send(event).is_ok(),
+            EventSenderInner::Bounded(sender) => sender.blocking_send(event).is_ok(),
+            EventSenderInner::DropNewest(sender) => match sender.try_send(event) {
+                Ok(()) => true,
+                Err(TrySendError::Full(_)) => {
+                    warn!("EventSender: event stream buffer is full, dropping event");
+                    true
+                }
+                Err(TrySendError::Closed(_)) => false,
+            },
+        };
+        if !sent {
+            trace!("EventSender: event stream closed, dropping event");
+        }
+    }
+}
+
+impl<E> Stream for EventStream<E> {
+    type Item = E;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<E>> {
+        match &mut self.receiver {
+            EventReceiver::Unbounded(receiver) => receiver.poll_recv(cx),
+            EventReceiver::Bounded(receiver) => receiver.poll_recv(cx),
+        }
+    }
+}
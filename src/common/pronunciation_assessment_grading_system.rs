@@ -0,0 +1,15 @@
+/// PronunciationAssessmentGradingSystem defines the point system for pronunciation score calibration.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PronunciationAssessmentGradingSystem {
+    /// Five point calibration.
+    FivePoint = 1,
+
+    /// Hundred mark.
+    HundredMark = 2,
+}
+
+impl From<PronunciationAssessmentGradingSystem> for u32 {
+    fn from(grading_system: PronunciationAssessmentGradingSystem) -> Self {
+        grading_system as u32
+    }
+}
@@ -0,0 +1,18 @@
+/// PronunciationAssessmentGranularity defines the pronunciation evaluation granularity.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PronunciationAssessmentGranularity {
+    /// Shows the score on the full text, word and phoneme level.
+    Phoneme = 1,
+
+    /// Shows the score on the full text and word level.
+    Word = 2,
+
+    /// Shows the score on the full text level only.
+    FullText = 3,
+}
+
+impl From<PronunciationAssessmentGranularity> for u32 {
+    fn from(granularity: PronunciationAssessmentGranularity) -> Self {
+        granularity as u32
+    }
+}
@@ -1,10 +1,11 @@
 use crate::common::PropertyId;
 use crate::error::{convert_err, Result};
 use crate::ffi::{
-    property_bag_free_string, property_bag_get_string, property_bag_release,
+    property_bag_create, property_bag_free_string, property_bag_get_string, property_bag_release,
     property_bag_set_string, SmartHandle, NULL_C_STR_PTR, SPXPROPERTYBAGHANDLE,
 };
 use std::ffi::{CStr, CString};
+use std::mem::MaybeUninit;
 
 /// PropertyCollection is a class to retrieve or set a property value from a property collection.
 #[derive(Debug)]
@@ -23,6 +24,16 @@ impl PropertyCollection {
         }
     }
 
+    /// Creates new empty PropertyCollection.
+    pub fn new() -> Result<PropertyCollection> {
+        unsafe {
+            let mut handle: MaybeUninit<SPXPROPERTYBAGHANDLE> = MaybeUninit::uninit();
+            let ret = property_bag_create(handle.as_mut_ptr());
+            convert_err(ret, "PropertyCollection::new error")?;
+            Ok(PropertyCollection::from_handle(handle.assume_init()))
+        }
+    }
+
     /// SetProperty sets the value of a property.
     pub fn set_property_by_string<S>(&mut self, prop_name: S, prop_val: S) -> Result<()>
     where
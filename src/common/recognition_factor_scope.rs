@@ -0,0 +1,19 @@
+/// RecognitionFactorScope defines the scope to which recognition factor
+/// of *GrammarList* is applied.
+#[derive(Debug)]
+pub enum RecognitionFactorScope {
+    /// Recognition factor applies to grammars that can be referenced as individual partial phrases.
+    PartialPhrase = 1,
+}
+
+impl From<RecognitionFactorScope> for u32 {
+    fn from(scope: RecognitionFactorScope) -> Self {
+        scope as u32
+    }
+}
+
+impl From<RecognitionFactorScope> for i32 {
+    fn from(scope: RecognitionFactorScope) -> Self {
+        scope as i32
+    }
+}
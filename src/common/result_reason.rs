@@ -43,6 +43,39 @@ pub enum ResultReason {
     /// SynthesizingAudioStarted indicates the speech synthesis is now started
     SynthesizingAudioStarted = 12,
 
+    /// TranslatingParticipantSpeech indicates the translation result contains hypothesis text and its translation(s)
+    /// of a participant in a conversation.
+    TranslatingParticipantSpeech = 13,
+
+    /// TranslatedParticipantSpeech indicates the translation result contains final text and corresponding translation(s)
+    /// of a participant in a conversation.
+    TranslatedParticipantSpeech = 14,
+
+    /// TranslatedInstantMessage indicates the translation result contains an instant message and its translation(s).
+    TranslatedInstantMessage = 15,
+
+    /// TranslatedParticipantInstantMessage indicates the translation result contains an instant message
+    /// of a participant in a conversation and its translation(s).
+    TranslatedParticipantInstantMessage = 16,
+
+    /// EnrollingVoiceProfile indicates the voice profile is being enrolled and customers need to send more audio to create a voice profile.
+    EnrollingVoiceProfile = 17,
+
+    /// EnrolledVoiceProfile indicates the voice profile has been enrolled.
+    EnrolledVoiceProfile = 18,
+
+    /// RecognizedSpeakers indicates successful identification of some speakers.
+    RecognizedSpeakers = 19,
+
+    /// RecognizedSpeaker indicates successfully verified one speaker.
+    RecognizedSpeaker = 20,
+
+    /// ResetVoiceProfile indicates a voice profile has been reset successfully.
+    ResetVoiceProfile = 21,
+
+    /// DeletedVoiceProfile indicates a voice profile has been deleted successfully.
+    DeletedVoiceProfile = 22,
+
     /// VoicesListRetrieved indicates the voices list has been retrieved successfully.
     VoicesListRetrieved = 23,
 }
@@ -63,6 +96,16 @@ impl ResultReason {
             10 => ResultReason::RecognizingKeyword,
             11 => ResultReason::RecognizedKeyword,
             12 => ResultReason::SynthesizingAudioStarted,
+            13 => ResultReason::TranslatingParticipantSpeech,
+            14 => ResultReason::TranslatedParticipantSpeech,
+            15 => ResultReason::TranslatedInstantMessage,
+            16 => ResultReason::TranslatedParticipantInstantMessage,
+            17 => ResultReason::EnrollingVoiceProfile,
+            18 => ResultReason::EnrolledVoiceProfile,
+            19 => ResultReason::RecognizedSpeakers,
+            20 => ResultReason::RecognizedSpeaker,
+            21 => ResultReason::ResetVoiceProfile,
+            22 => ResultReason::DeletedVoiceProfile,
             _ => ResultReason::VoicesListRetrieved,
         }
     }
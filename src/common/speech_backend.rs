@@ -0,0 +1,26 @@
+/// SpeechBackend defines which backend produced recognition or synthesis result.
+/// Relevant mainly when recognizer or synthesizer is created from *HybridSpeechConfig*.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpeechBackend {
+    /// Result was produced by the speech service (online).
+    Cloud,
+
+    /// Result was produced by the embedded (offline) model.
+    Embedded,
+
+    /// Backend was not reported for the result.
+    Unknown,
+}
+
+impl SpeechBackend {
+    /// Parses value of *SpeechServiceResponseRecognitionBackend*
+    /// or *SpeechServiceResponseSynthesisBackend* result property,
+    /// which is either *online* or *offline*.
+    pub fn from_property(value: &str) -> Self {
+        match value {
+            "online" => SpeechBackend::Cloud,
+            "offline" => SpeechBackend::Embedded,
+            _ => SpeechBackend::Unknown,
+        }
+    }
+}
@@ -0,0 +1,34 @@
+use std::time::Duration;
+
+/// Number of ticks per second. Speech service reports offsets
+/// and durations in ticks, i.e. 100-nanosecond units.
+pub const TICKS_PER_SECOND: u64 = 10_000_000;
+
+const NANOS_PER_TICK: u64 = 100;
+
+/// Conversions between *std::time::Duration* and 100-nanosecond ticks
+/// used by speech service. Offsets and durations exposed by results and events
+/// are already converted to *Duration*, use *as_ticks* to get original value.
+pub trait Ticks {
+    /// Creates value from number of 100-nanosecond ticks.
+    fn from_ticks(ticks: u64) -> Self;
+
+    /// Returns value as number of 100-nanosecond ticks (truncated),
+    /// saturates at *u64::MAX* for values exceeding ~58 thousand years.
+    fn as_ticks(&self) -> u64;
+}
+
+impl Ticks for Duration {
+    fn from_ticks(ticks: u64) -> Self {
+        Duration::new(
+            ticks / TICKS_PER_SECOND,
+            ((ticks % TICKS_PER_SECOND) * NANOS_PER_TICK) as u32,
+        )
+    }
+
+    fn as_ticks(&self) -> u64 {
+        self.as_secs()
+            .saturating_mul(TICKS_PER_SECOND)
+            .saturating_add(u64::from(self.subsec_nanos()) / NANOS_PER_TICK)
+    }
+}
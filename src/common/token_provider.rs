@@ -0,0 +1,104 @@
+use crate::common::{PropertyCollection, PropertyId};
+use crate::error::{Error, ErrorRootCause, Result};
+use log::*;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// Default margin before token expiration when *CachingTokenProvider* refreshes the token.
+pub const DEFAULT_TOKEN_REFRESH_MARGIN: Duration = Duration::from_secs(60);
+
+/// This trait must be implemented by authorization token providers.
+/// Provider is called when recognizer or synthesizer is set up with it
+/// and then whenever the native layer requests a new token,
+/// i.e. it may be called from the native SDK thread.
+/// Token fetching failures should be reported with *Error::token_provider*,
+/// other errors returned by provider are wrapped into
+/// *ErrorRootCause::TokenProviderError* when token is applied.
+pub trait TokenProvider: Send + Sync {
+    /// Returns valid authorization token.
+    fn get_token(&self) -> Result<String>;
+}
+
+impl<F> TokenProvider for F
+where
+    F: Fn() -> Result<String> + Send + Sync,
+{
+    fn get_token(&self) -> Result<String> {
+        self()
+    }
+}
+
+impl<T: TokenProvider + ?Sized> TokenProvider for Arc<T> {
+    fn get_token(&self) -> Result<String> {
+        (**self).get_token()
+    }
+}
+
+/// CachingTokenProvider caches token returned by the fetch closure
+/// and calls the closure again only when cached token is about to expire.
+/// Fetch closure returns token together with its validity duration,
+/// e.g. 10 minutes for tokens issued by the speech service STS endpoint.
+/// Wrap provider in *Arc* to share it among multiple recognizers and synthesizers.
+pub struct CachingTokenProvider<F> {
+    fetch: F,
+    refresh_margin: Duration,
+    cached: Mutex<Option<(String, Instant)>>,
+}
+
+impl<F> CachingTokenProvider<F>
+where
+    F: Fn() -> Result<(String, Duration)> + Send + Sync,
+{
+    /// Creates caching provider which refreshes token
+    /// *DEFAULT_TOKEN_REFRESH_MARGIN* before its expiration.
+    pub fn new(fetch: F) -> Self {
+        CachingTokenProvider::with_refresh_margin(fetch, DEFAULT_TOKEN_REFRESH_MARGIN)
+    }
+
+    /// Creates caching provider which refreshes token
+    /// *refresh_margin* before its expiration.
+    pub fn with_refresh_margin(fetch: F, refresh_margin: Duration) -> Self {
+        CachingTokenProvider {
+            fetch,
+            refresh_margin,
+            cached: Mutex::new(None),
+        }
+    }
+
+    /// Drops cached token so that next *get_token* call fetches new one.
+    pub fn invalidate(&self) {
+        *self.cached.lock().unwrap() = None;
+    }
+}
+
+impl<F> TokenProvider for CachingTokenProvider<F>
+where
+    F: Fn() -> Result<(String, Duration)> + Send + Sync,
+{
+    fn get_token(&self) -> Result<String> {
+        let mut cached = self.cached.lock().unwrap();
+        if let Some((token, refresh_at)) = &*cached {
+            if Instant::now() < *refresh_at {
+                return Ok(token.clone());
+            }
+        }
+        trace!("CachingTokenProvider fetching new token");
+        let (token, valid_for) = (self.fetch)()?;
+        let refresh_at = Instant::now() + valid_for.saturating_sub(self.refresh_margin);
+        *cached = Some((token.clone(), refresh_at));
+        Ok(token)
+    }
+}
+
+/// Fetches token from the provider and sets it as authorization token
+/// into given properties of recognizer or synthesizer.
+pub(crate) fn apply_token(
+    provider: &dyn TokenProvider,
+    properties: &mut PropertyCollection,
+) -> Result<()> {
+    let token = provider.get_token().map_err(|err| match err.caused_by {
+        ErrorRootCause::TokenProviderError(_) => err,
+        _ => Error::token_provider(err),
+    })?;
+    properties.set_property(PropertyId::SpeechServiceAuthorizationToken, token)
+}
@@ -0,0 +1,23 @@
+//! Package diagnostics provides access to logging of the native Speech SDK core.
+//! Native log lines can be forwarded into the *log* crate facade (*EventLogger*),
+//! written into a file (*FileLogger*) or kept in in-memory ring buffer (*MemoryLogger*)
+//! which can be dumped on demand.
+mod event_logger;
+mod file_logger;
+mod log_level;
+mod memory_logger;
+
+// re-export structs directly under diagnostics module
+pub use self::event_logger::{EventLogger, NATIVE_LOG_TARGET};
+pub use self::file_logger::FileLogger;
+pub use self::log_level::LogLevel;
+pub use self::memory_logger::MemoryLogger;
+
+use std::ffi::CString;
+
+/// Joins filters into format expected by native logging,
+/// i.e. only lines containing at least one of the filters are logged.
+/// Empty slice clears the filters.
+fn filters_to_cstring(filters: &[&str]) -> crate::error::Result<CString> {
+    Ok(CString::new(filters.join(";"))?)
+}
@@ -0,0 +1,84 @@
+use crate::diagnostics::{filters_to_cstring, LogLevel};
+use crate::error::{convert_err, Result};
+use crate::ffi::{
+    diagnostics_logmessage_set_callback, diagnostics_logmessage_set_filters,
+    diagnostics_set_log_level,
+};
+use std::ffi::{CStr, CString};
+use std::os::raw::c_char;
+
+/// Target under which native log lines are emitted into the *log* crate facade.
+pub const NATIVE_LOG_TARGET: &str = "cognitive_services_speech_sdk_rs::native";
+
+/// EventLogger forwards native Speech SDK log lines into the *log* crate facade
+/// under target *cognitive_services_speech_sdk_rs::native*.
+/// Level of each line is derived from native trace level (error, warning, info, verbose).
+/// Event logger is global, i.e. it is shared by all speech SDK objects in the process.
+pub struct EventLogger;
+
+impl EventLogger {
+    /// Starts forwarding native log lines into the *log* crate facade.
+    pub fn start() -> Result<()> {
+        unsafe {
+            let ret = diagnostics_logmessage_set_callback(Some(Self::cb_log_line));
+            convert_err(ret, "EventLogger::start error")
+        }
+    }
+
+    /// Stops forwarding native log lines.
+    pub fn stop() -> Result<()> {
+        unsafe {
+            let ret = diagnostics_logmessage_set_callback(None);
+            convert_err(ret, "EventLogger::stop error")
+        }
+    }
+
+    /// Sets filters for forwarded log lines. Only lines containing
+    /// at least one of the filters are forwarded. Empty slice clears the filters.
+    pub fn set_filters(filters: &[&str]) -> Result<()> {
+        unsafe {
+            let c_filters = filters_to_cstring(filters)?;
+            let ret = diagnostics_logmessage_set_filters(c_filters.as_ptr());
+            convert_err(ret, "EventLogger::set_filters error")
+        }
+    }
+
+    /// Sets level of forwarded log lines.
+    pub fn set_level(level: LogLevel) -> Result<()> {
+        unsafe {
+            let c_logger = CString::new("event")?;
+            let c_level = CString::new(level.as_str())?;
+            diagnostics_set_log_level(c_logger.as_ptr(), c_level.as_ptr());
+            Ok(())
+        }
+    }
+
+    /// Maps native log line to log level based on native trace marker contained in the line,
+    /// e.g. *SPX_TRACE_ERROR* or *SPX_DBG_TRACE_VERBOSE*.
+    pub(crate) fn log_line_level(log_line: &str) -> log::Level {
+        if log_line.contains("_ERROR") {
+            log::Level::Error
+        } else if log_line.contains("_WARNING") {
+            log::Level::Warn
+        } else if log_line.contains("_INFO") {
+            log::Level::Info
+        } else {
+            log::Level::Trace
+        }
+    }
+
+    #[allow(non_snake_case)]
+    unsafe extern "C" fn cb_log_line(logLine: *const c_char) {
+        if logLine.is_null() {
+            return;
+        }
+        let log_line = CStr::from_ptr(logLine).to_string_lossy();
+        let log_line = log_line.trim_end();
+        log::log!(
+            target: NATIVE_LOG_TARGET,
+            Self::log_line_level(log_line),
+            "{}",
+            log_line
+        );
+    }
+}
@@ -0,0 +1,58 @@
+use crate::common::{PropertyCollection, PropertyId};
+use crate::diagnostics::{filters_to_cstring, LogLevel};
+use crate::error::{convert_err, Result};
+use crate::ffi::{
+    diagnostics_log_apply_properties, diagnostics_log_start_logging, diagnostics_log_stop_logging,
+    diagnostics_set_log_level,
+};
+use std::ffi::CString;
+use std::ptr;
+
+/// FileLogger writes native Speech SDK log lines into a file.
+/// File logger is global, i.e. it is shared by all speech SDK objects in the process.
+pub struct FileLogger;
+
+impl FileLogger {
+    /// Starts logging into file *path*. When *append* is true
+    /// log lines are appended to existing file, otherwise file is overwritten.
+    pub fn start(path: &str, append: bool) -> Result<()> {
+        let mut properties = PropertyCollection::new()?;
+        properties.set_property(PropertyId::SpeechLogFilename, path)?;
+        properties
+            .set_property_by_string("SPEECH-AppendToLogFile", if append { "1" } else { "0" })?;
+        unsafe {
+            let ret = diagnostics_log_start_logging(properties.handle.inner(), ptr::null_mut());
+            convert_err(ret, "FileLogger::start error")
+        }
+    }
+
+    /// Stops logging into file.
+    pub fn stop() -> Result<()> {
+        unsafe {
+            let ret = diagnostics_log_stop_logging();
+            convert_err(ret, "FileLogger::stop error")
+        }
+    }
+
+    /// Sets filters for logged lines. Only lines containing
+    /// at least one of the filters are logged. Empty slice clears the filters.
+    pub fn set_filters(filters: &[&str]) -> Result<()> {
+        let c_filters = filters_to_cstring(filters)?;
+        let mut properties = PropertyCollection::new()?;
+        properties.set_property_by_string("SPEECH-LogFileFilters", c_filters.to_str()?)?;
+        unsafe {
+            let ret = diagnostics_log_apply_properties(properties.handle.inner(), ptr::null_mut());
+            convert_err(ret, "FileLogger::set_filters error")
+        }
+    }
+
+    /// Sets level of logged lines.
+    pub fn set_level(level: LogLevel) -> Result<()> {
+        unsafe {
+            let c_logger = CString::new("file")?;
+            let c_level = CString::new(level.as_str())?;
+            diagnostics_set_log_level(c_logger.as_ptr(), c_level.as_ptr());
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,38 @@
+/// LogLevel defines the level of native Speech SDK logging.
+/// Each level includes also all levels listed above it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogLevel {
+    /// Error indicates only errors are logged.
+    Error,
+
+    /// Warning indicates errors and warnings are logged.
+    Warning,
+
+    /// Info indicates errors, warnings and informational messages are logged.
+    Info,
+
+    /// Verbose indicates all messages are logged. This is the default.
+    Verbose,
+}
+
+impl LogLevel {
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            LogLevel::Error => "error",
+            LogLevel::Warning => "warning",
+            LogLevel::Info => "info",
+            LogLevel::Verbose => "verbose",
+        }
+    }
+}
+
+impl From<LogLevel> for log::Level {
+    fn from(level: LogLevel) -> Self {
+        match level {
+            LogLevel::Error => log::Level::Error,
+            LogLevel::Warning => log::Level::Warn,
+            LogLevel::Info => log::Level::Info,
+            LogLevel::Verbose => log::Level::Trace,
+        }
+    }
+}
@@ -0,0 +1,112 @@
+use crate::diagnostics::{filters_to_cstring, EventLogger, LogLevel, NATIVE_LOG_TARGET};
+use crate::error::{convert_err, Result};
+use crate::ffi::{
+    diagnostics_log_memory_dump, diagnostics_log_memory_get_line,
+    diagnostics_log_memory_get_line_num_newest, diagnostics_log_memory_get_line_num_oldest,
+    diagnostics_log_memory_set_filters, diagnostics_log_memory_start_logging,
+    diagnostics_log_memory_stop_logging, diagnostics_set_log_level, NULL_C_STR_PTR,
+};
+use std::ffi::{CStr, CString};
+
+/// MemoryLogger keeps native Speech SDK log lines in in-memory ring buffer
+/// which can be dumped on demand, e.g. when a recognition is canceled.
+/// Memory logger is global, i.e. it is shared by all speech SDK objects in the process.
+pub struct MemoryLogger;
+
+impl MemoryLogger {
+    /// Starts logging into in-memory ring buffer.
+    pub fn start() {
+        unsafe { diagnostics_log_memory_start_logging() }
+    }
+
+    /// Stops logging into in-memory ring buffer.
+    pub fn stop() {
+        unsafe { diagnostics_log_memory_stop_logging() }
+    }
+
+    /// Sets filters for logged lines. Only lines containing
+    /// at least one of the filters are logged. Empty slice clears the filters.
+    pub fn set_filters(filters: &[&str]) -> Result<()> {
+        unsafe {
+            let c_filters = filters_to_cstring(filters)?;
+            diagnostics_log_memory_set_filters(c_filters.as_ptr());
+            Ok(())
+        }
+    }
+
+    /// Sets level of logged lines.
+    pub fn set_level(level: LogLevel) -> Result<()> {
+        unsafe {
+            let c_logger = CString::new("memory")?;
+            let c_level = CString::new(level.as_str())?;
+            diagnostics_set_log_level(c_logger.as_ptr(), c_level.as_ptr());
+            Ok(())
+        }
+    }
+
+    /// Returns content of in-memory ring buffer, from the oldest line to the newest one.
+    pub fn lines() -> Vec<String> {
+        unsafe {
+            let oldest = diagnostics_log_memory_get_line_num_oldest();
+            let newest = diagnostics_log_memory_get_line_num_newest();
+            let mut lines = vec![];
+            for line_num in oldest..newest {
+                let line = diagnostics_log_memory_get_line(line_num);
+                if !line.is_null() {
+                    lines.push(CStr::from_ptr(line).to_string_lossy().trim_end().to_owned());
+                }
+            }
+            lines
+        }
+    }
+
+    /// Dumps content of in-memory ring buffer into file *path*.
+    /// Each line is prefixed with *line_prefix*.
+    pub fn dump(path: &str, line_prefix: &str) -> Result<()> {
+        unsafe {
+            let c_path = CString::new(path)?;
+            let c_prefix = CString::new(line_prefix)?;
+            let ret = diagnostics_log_memory_dump(c_path.as_ptr(), c_prefix.as_ptr(), false, false);
+            convert_err(ret, "MemoryLogger::dump error")
+        }
+    }
+
+    /// Dumps content of in-memory ring buffer into standard error output.
+    pub fn dump_to_stderr() -> Result<()> {
+        unsafe {
+            let ret = diagnostics_log_memory_dump(NULL_C_STR_PTR, NULL_C_STR_PTR, false, true);
+            convert_err(ret, "MemoryLogger::dump_to_stderr error")
+        }
+    }
+
+    /// Dumps content of in-memory ring buffer into the *log* crate facade
+    /// under target *cognitive_services_speech_sdk_rs::native*.
+    pub fn dump_to_log() {
+        for line in MemoryLogger::lines() {
+            log::log!(
+                target: NATIVE_LOG_TARGET,
+                EventLogger::log_line_level(&line),
+                "{}",
+                line
+            );
+        }
+    }
+
+    /// Wraps callback so that content of in-memory ring buffer is dumped
+    /// into the *log* crate facade before the callback is called.
+    /// Intended to be used with *set_canceled_cb* of recognizers and synthesizers, e.g.
+    /// ```ignore
+    /// speech_recognizer.set_canceled_cb(MemoryLogger::dump_on_canceled(|event| {
+    ///     error!("recognition canceled {:?}", event);
+    /// }))?;
+    /// ```
+    pub fn dump_on_canceled<E, F>(f: F) -> impl Fn(E) + 'static + Send
+    where
+        F: Fn(E) + 'static + Send,
+    {
+        move |event| {
+            MemoryLogger::dump_to_log();
+            f(event)
+        }
+    }
+}
@@ -0,0 +1,114 @@
+use crate::error::Result;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+/// Activity is a typed representation of Bot Framework activity
+/// exchanged with the dialog backend. It can be sent via
+/// *DialogServiceConnector::send_typed_activity_async* and obtained from
+/// received activity via *ActivityReceivedEvent::get_typed_activity*.
+/// Fields not covered by this struct are preserved in *extra*.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Activity {
+    #[serde(rename = "type")]
+    pub activity_type: ActivityType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    /// SSML or plain text to be spoken by the client.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub speak: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_hint: Option<InputHint>,
+    /// Name of the event, applicable to event activities.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub locale: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub attachments: Vec<Attachment>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// Type of the activity. Activity types not listed here
+/// (e.g. *invoke* or *conversationUpdate*) are kept in *Other*
+/// so that they survive serialization round-trip.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ActivityType {
+    #[default]
+    Message,
+    Event,
+    Typing,
+    EndOfConversation,
+    Trace,
+    #[serde(untagged)]
+    Other(String),
+}
+
+/// Indicates whether bot is accepting, expecting or ignoring user input
+/// after the activity is delivered to the client. Values not listed here
+/// are kept in *Other*.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum InputHint {
+    AcceptingInput,
+    ExpectingInput,
+    IgnoringInput,
+    #[serde(untagged)]
+    Other(String),
+}
+
+/// Attachment (e.g. adaptive card or media) carried by the activity.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Attachment {
+    pub content_type: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+impl Activity {
+    /// Creates message activity with given text.
+    pub fn message<S: Into<String>>(text: S) -> Self {
+        Activity {
+            activity_type: ActivityType::Message,
+            text: Some(text.into()),
+            ..Default::default()
+        }
+    }
+
+    /// Creates event activity with given name and optional value.
+    pub fn event<S: Into<String>>(name: S, value: Option<Value>) -> Self {
+        Activity {
+            activity_type: ActivityType::Event,
+            name: Some(name.into()),
+            value,
+            ..Default::default()
+        }
+    }
+
+    /// Creates typing activity.
+    pub fn typing() -> Self {
+        Activity {
+            activity_type: ActivityType::Typing,
+            ..Default::default()
+        }
+    }
+
+    pub fn from_json(json: &str) -> Result<Self> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    pub fn to_json(&self) -> Result<String> {
+        Ok(serde_json::to_string(self)?)
+    }
+}
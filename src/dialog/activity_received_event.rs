@@ -1,4 +1,5 @@
 use crate::audio::PullAudioOutputStream;
+use crate::dialog::Activity;
 use crate::error::{convert_err, Result};
 use crate::ffi::{
     dialog_service_connector_activity_received_event_get_activity,
@@ -52,6 +53,11 @@ impl ActivityReceivedEvent {
         }
     }
 
+    /// Parses received activity JSON into typed *Activity*.
+    pub fn get_typed_activity(&self) -> Result<Activity> {
+        Activity::from_json(&self.activity)
+    }
+
     pub fn has_audio(&self) -> bool {
         unsafe { dialog_service_connector_activity_received_event_has_audio(self.handle.inner()) }
     }
@@ -1,24 +1,42 @@
 use crate::audio::AudioConfig;
-use crate::common::{PropertyCollection, PropertyId};
-use crate::dialog::{ActivityReceivedEvent, DialogServiceConfig};
+use crate::common::{
+    apply_token, event_channel, EventBuffering, EventStream, PropertyCollection, PropertyId,
+    TokenProvider,
+};
+use crate::dialog::{
+    Activity, ActivityReceivedEvent, DialogServiceConfig, DialogServiceConnectorEvent,
+    TurnStatusReceivedEvent,
+};
 use crate::error::{convert_err, Result};
 use crate::ffi::{
     dialog_service_connector_activity_received_set_callback,
-    dialog_service_connector_canceled_set_callback, dialog_service_connector_connect,
+    dialog_service_connector_async_reco_result_handle_release,
+    dialog_service_connector_async_string_handle_release,
+    dialog_service_connector_async_void_handle_release,
+    dialog_service_connector_canceled_set_callback, dialog_service_connector_connect_async,
+    dialog_service_connector_connect_async_wait_for,
     dialog_service_connector_create_dialog_service_connector_from_config,
-    dialog_service_connector_disconnect, dialog_service_connector_get_property_bag,
-    dialog_service_connector_handle_release, dialog_service_connector_listen_once,
+    dialog_service_connector_disconnect_async, dialog_service_connector_disconnect_async_wait_for,
+    dialog_service_connector_get_property_bag, dialog_service_connector_handle_release,
+    dialog_service_connector_listen_once_async,
+    dialog_service_connector_listen_once_async_wait_for,
     dialog_service_connector_recognized_set_callback,
-    dialog_service_connector_recognizing_set_callback, dialog_service_connector_send_activity,
+    dialog_service_connector_recognizing_set_callback,
+    dialog_service_connector_send_activity_async,
+    dialog_service_connector_send_activity_async_wait_for,
     dialog_service_connector_session_started_set_callback,
     dialog_service_connector_session_stopped_set_callback,
-    dialog_service_connector_start_keyword_recognition,
-    dialog_service_connector_stop_keyword_recognition, SmartHandle, SPXEVENTHANDLE, SPXHANDLE,
-    SPXPROPERTYBAGHANDLE, SPXRECOHANDLE, SPXRESULTHANDLE,
+    dialog_service_connector_start_keyword_recognition_async,
+    dialog_service_connector_start_keyword_recognition_async_wait_for,
+    dialog_service_connector_stop_keyword_recognition_async,
+    dialog_service_connector_stop_keyword_recognition_async_wait_for,
+    dialog_service_connector_turn_status_received_set_callback, wait_for_async, SmartHandle,
+    SPXASYNCHANDLE, SPXEVENTHANDLE, SPXHANDLE, SPXPROPERTYBAGHANDLE, SPXRECOHANDLE,
+    SPXRESULTHANDLE,
 };
 use crate::speech::{
-    KeywordRecognitionModel, SessionEvent, SpeechRecognitionCanceledEvent, SpeechRecognitionEvent,
-    SpeechRecognitionResult,
+    KeywordRecognitionModel, Recognizer, SealedRecognizer, SessionEvent,
+    SpeechRecognitionCanceledEvent, SpeechRecognitionEvent, SpeechRecognitionResult,
 };
 use log::*;
 use std::ffi::{CStr, CString};
@@ -31,16 +49,26 @@ pub struct SendActivityOutcome {
     pub interaction_id: String,
 }
 
-/// DialogServiceConnector connects to a speech enabled dialog backend.
-pub struct DialogServiceConnector {
-    pub properties: PropertyCollection,
-    pub handle: SmartHandle<SPXRECOHANDLE>,
+/// A separate internal struct to hold all the callback closures for the dialog service connector events.
+/// By creating a separate struct, and then boxing this struct inside our DialogServiceConnector,
+/// we can ensure the DialogServiceConnector itself can be moved freely by end users,
+/// and the callbacks will remain at a fixed memory address on the heap.
+struct CallbackBag {
     session_started_cb: Option<Box<dyn Fn(SessionEvent) + Send>>,
     session_stopped_cb: Option<Box<dyn Fn(SessionEvent) + Send>>,
     canceled_cb: Option<Box<dyn Fn(SpeechRecognitionCanceledEvent) + Send>>,
     recognizing_cb: Option<Box<dyn Fn(SpeechRecognitionEvent) + Send>>,
     recognized_cb: Option<Box<dyn Fn(SpeechRecognitionEvent) + Send>>,
     activity_received_cb: Option<Box<dyn Fn(ActivityReceivedEvent) + Send>>,
+    turn_status_received_cb: Option<Box<dyn Fn(TurnStatusReceivedEvent) + Send>>,
+}
+
+/// DialogServiceConnector connects to a speech enabled dialog backend.
+pub struct DialogServiceConnector {
+    pub properties: PropertyCollection,
+    pub handle: SmartHandle<SPXRECOHANDLE>,
+    callback_bag: Box<CallbackBag>,
+    token_provider: Option<Box<dyn TokenProvider>>,
 }
 
 impl fmt::Debug for DialogServiceConnector {
@@ -52,6 +80,14 @@ impl fmt::Debug for DialogServiceConnector {
     }
 }
 
+impl SealedRecognizer for DialogServiceConnector {}
+
+impl Recognizer for DialogServiceConnector {
+    fn get_handle(&self) -> SPXRECOHANDLE {
+        self.handle.inner()
+    }
+}
+
 impl DialogServiceConnector {
     /// # Safety
     /// `handle` must be a valid handle to a live dialog service connector.
@@ -68,12 +104,16 @@ impl DialogServiceConnector {
                     handle,
                     dialog_service_connector_handle_release,
                 ),
-                session_started_cb: None,
-                session_stopped_cb: None,
-                canceled_cb: None,
-                recognizing_cb: None,
-                recognized_cb: None,
-                activity_received_cb: None,
+                callback_bag: Box::new(CallbackBag {
+                    session_started_cb: None,
+                    session_stopped_cb: None,
+                    canceled_cb: None,
+                    recognizing_cb: None,
+                    recognized_cb: None,
+                    activity_received_cb: None,
+                    turn_status_received_cb: None,
+                }),
+                token_provider: None,
             })
         }
     }
@@ -109,48 +149,125 @@ impl DialogServiceConnector {
     }
 
     pub async fn connect_async(&self) -> Result<()> {
-        unsafe {
-            let ret = dialog_service_connector_connect(self.handle.inner());
+        self.refresh_auth_token()?;
+        let handle_async = unsafe {
+            let mut handle_async: MaybeUninit<SPXASYNCHANDLE> = MaybeUninit::uninit();
+            let ret = dialog_service_connector_connect_async(
+                self.handle.inner(),
+                handle_async.as_mut_ptr(),
+            );
             convert_err(ret, "DialogServiceConnector.connect_async error")?;
-            Ok(())
-        }
+            SmartHandle::create(
+                "handle_async_connect",
+                handle_async.assume_init(),
+                dialog_service_connector_async_void_handle_release,
+            )
+        };
+        wait_for_async(handle_async, |handle_async| unsafe {
+            let ret = dialog_service_connector_connect_async_wait_for(handle_async, u32::MAX);
+            convert_err(ret, "DialogServiceConnector.connect_async_wait_for error")
+        })
+        .await
     }
 
     pub async fn disconnect_async(&self) -> Result<()> {
-        unsafe {
-            let ret = dialog_service_connector_disconnect(self.handle.inner());
+        let handle_async = unsafe {
+            let mut handle_async: MaybeUninit<SPXASYNCHANDLE> = MaybeUninit::uninit();
+            let ret = dialog_service_connector_disconnect_async(
+                self.handle.inner(),
+                handle_async.as_mut_ptr(),
+            );
             convert_err(ret, "DialogServiceConnector.disconnect_async error")?;
-            Ok(())
-        }
+            SmartHandle::create(
+                "handle_async_disconnect",
+                handle_async.assume_init(),
+                dialog_service_connector_async_void_handle_release,
+            )
+        };
+        wait_for_async(handle_async, |handle_async| unsafe {
+            let ret = dialog_service_connector_disconnect_async_wait_for(handle_async, u32::MAX);
+            convert_err(
+                ret,
+                "DialogServiceConnector.disconnect_async_wait_for error",
+            )
+        })
+        .await
     }
 
     /// Sends an activity to the backing dialog.
     pub async fn send_activity_async(&self, message: String) -> Result<SendActivityOutcome> {
-        unsafe {
-            let c_buf: *mut c_char = &mut [0u8; 37] as *const _ as *mut c_char;
+        self.refresh_auth_token()?;
+        let handle_async = unsafe {
             let c_message = CString::new(message)?;
-            let ret = dialog_service_connector_send_activity(
+            let mut handle_async: MaybeUninit<SPXASYNCHANDLE> = MaybeUninit::uninit();
+            let ret = dialog_service_connector_send_activity_async(
                 self.handle.inner(),
                 c_message.as_ptr(),
-                c_buf,
+                handle_async.as_mut_ptr(),
             );
             convert_err(ret, "DialogServiceConnector.send_activity_async error")?;
+            SmartHandle::create(
+                "handle_async_send_activity",
+                handle_async.assume_init(),
+                dialog_service_connector_async_string_handle_release,
+            )
+        };
+        wait_for_async(handle_async, |handle_async| unsafe {
+            let mut buf = [0u8; 37];
+            let c_buf = buf.as_mut_ptr() as *mut c_char;
+            let ret = dialog_service_connector_send_activity_async_wait_for(
+                handle_async,
+                u32::MAX,
+                c_buf,
+            );
+            convert_err(
+                ret,
+                "DialogServiceConnector.send_activity_async_wait_for error",
+            )?;
             let interaction_id = CStr::from_ptr(c_buf).to_str()?.to_owned();
             Ok(SendActivityOutcome { interaction_id })
-        }
+        })
+        .await
+    }
+
+    /// Sends typed activity to the backing dialog.
+    pub async fn send_typed_activity_async(
+        &self,
+        activity: &Activity,
+    ) -> Result<SendActivityOutcome> {
+        self.send_activity_async(activity.to_json()?).await
     }
 
     /// ListenOnceAsync starts a listening session that will terminate after the first utterance.
     pub async fn listen_once_async(&self) -> Result<SpeechRecognitionResult> {
-        unsafe {
-            let mut result_handle: MaybeUninit<SPXRESULTHANDLE> = MaybeUninit::uninit();
-            let ret = dialog_service_connector_listen_once(
+        self.refresh_auth_token()?;
+        let handle_async = unsafe {
+            let mut handle_async: MaybeUninit<SPXASYNCHANDLE> = MaybeUninit::uninit();
+            let ret = dialog_service_connector_listen_once_async(
                 self.handle.inner(),
-                result_handle.as_mut_ptr(),
+                handle_async.as_mut_ptr(),
             );
             convert_err(ret, "DialogServiceConnector.listen_once_async error")?;
+            SmartHandle::create(
+                "handle_async_listen_once",
+                handle_async.assume_init(),
+                dialog_service_connector_async_reco_result_handle_release,
+            )
+        };
+        wait_for_async(handle_async, |handle_async| unsafe {
+            let mut result_handle: MaybeUninit<SPXRESULTHANDLE> = MaybeUninit::uninit();
+            let ret = dialog_service_connector_listen_once_async_wait_for(
+                handle_async,
+                u32::MAX,
+                result_handle.as_mut_ptr(),
+            );
+            convert_err(
+                ret,
+                "DialogServiceConnector.listen_once_async_wait_for error",
+            )?;
             SpeechRecognitionResult::from_handle(result_handle.assume_init())
-        }
+        })
+        .await
     }
 
     /// StartKeywordRecognitionAsync initiates keyword recognition.
@@ -158,29 +275,66 @@ impl DialogServiceConnector {
         &self,
         model: &KeywordRecognitionModel,
     ) -> Result<()> {
-        unsafe {
-            let ret = dialog_service_connector_start_keyword_recognition(
+        self.refresh_auth_token()?;
+        let handle_async = unsafe {
+            let mut handle_async: MaybeUninit<SPXASYNCHANDLE> = MaybeUninit::uninit();
+            let ret = dialog_service_connector_start_keyword_recognition_async(
                 self.handle.inner(),
                 model.handle.inner(),
+                handle_async.as_mut_ptr(),
             );
             convert_err(
                 ret,
                 "DialogServiceConnector.start_keyword_recognition_async error",
             )?;
-            Ok(())
-        }
+            SmartHandle::create(
+                "handle_async_start_keyword",
+                handle_async.assume_init(),
+                dialog_service_connector_async_void_handle_release,
+            )
+        };
+        wait_for_async(handle_async, |handle_async| unsafe {
+            let ret = dialog_service_connector_start_keyword_recognition_async_wait_for(
+                handle_async,
+                u32::MAX,
+            );
+            convert_err(
+                ret,
+                "DialogServiceConnector.start_keyword_recognition_async_wait_for error",
+            )
+        })
+        .await
     }
 
     /// StopKeywordRecognitionAsync stops keyword recognition.
     pub async fn stop_keyword_recognition_async(&self) -> Result<()> {
-        unsafe {
-            let ret = dialog_service_connector_stop_keyword_recognition(self.handle.inner());
+        let handle_async = unsafe {
+            let mut handle_async: MaybeUninit<SPXASYNCHANDLE> = MaybeUninit::uninit();
+            let ret = dialog_service_connector_stop_keyword_recognition_async(
+                self.handle.inner(),
+                handle_async.as_mut_ptr(),
+            );
             convert_err(
                 ret,
                 "DialogServiceConnector.stop_keyword_recognition_async error",
             )?;
-            Ok(())
-        }
+            SmartHandle::create(
+                "handle_async_stop_keyword",
+                handle_async.assume_init(),
+                dialog_service_connector_async_void_handle_release,
+            )
+        };
+        wait_for_async(handle_async, |handle_async| unsafe {
+            let ret = dialog_service_connector_stop_keyword_recognition_async_wait_for(
+                handle_async,
+                u32::MAX,
+            );
+            convert_err(
+                ret,
+                "DialogServiceConnector.stop_keyword_recognition_async_wait_for error",
+            )
+        })
+        .await
     }
 
     pub fn get_auth_token(&self) -> Result<String> {
@@ -197,6 +351,35 @@ impl DialogServiceConnector {
             .set_property(PropertyId::SpeechServiceAuthorizationToken, auth_token)
     }
 
+    /// Sets authorization token provider. Dialog service connector has no native
+    /// token requested event, hence token is fetched from the provider right away and then
+    /// before each connect, listen, keyword recognition and send activity call.
+    /// Use *CachingTokenProvider* so that these calls do not fetch new token every time.
+    pub fn set_token_provider<P>(&mut self, provider: P) -> Result<()>
+    where
+        P: TokenProvider + 'static,
+    {
+        apply_token(&provider, &mut self.properties)?;
+        self.token_provider = Some(Box::new(provider));
+        Ok(())
+    }
+
+    fn refresh_auth_token(&self) -> Result<()> {
+        if let Some(provider) = &self.token_provider {
+            unsafe {
+                let mut prop_bag_handle: MaybeUninit<SPXPROPERTYBAGHANDLE> = MaybeUninit::uninit();
+                let ret = dialog_service_connector_get_property_bag(
+                    self.handle.inner(),
+                    prop_bag_handle.as_mut_ptr(),
+                );
+                convert_err(ret, "DialogServiceConnector.refresh_auth_token error")?;
+                let mut properties = PropertyCollection::from_handle(prop_bag_handle.assume_init());
+                apply_token(provider.as_ref(), &mut properties)?;
+            }
+        }
+        Ok(())
+    }
+
     pub fn get_speech_activity_template(&self) -> Result<String> {
         self.properties
             .get_property(PropertyId::ConversationSpeechActivityTemplate, "")
@@ -211,16 +394,54 @@ impl DialogServiceConnector {
         )
     }
 
+    /// Returns stream of all dialog service connector events so that they can be consumed
+    /// from async code instead of registering individual callbacks.
+    /// Replaces callbacks previously set via *set_*_cb* methods.
+    /// Stream ends when the connector is dropped.
+    pub fn events(
+        &mut self,
+        buffering: EventBuffering,
+    ) -> Result<EventStream<DialogServiceConnectorEvent>> {
+        let (sender, stream) = event_channel(buffering);
+        let tx = sender.clone();
+        self.set_session_started_cb(move |event| {
+            tx.send(DialogServiceConnectorEvent::SessionStarted(event))
+        })?;
+        let tx = sender.clone();
+        self.set_session_stopped_cb(move |event| {
+            tx.send(DialogServiceConnectorEvent::SessionStopped(event))
+        })?;
+        let tx = sender.clone();
+        self.set_recognizing_cb(move |event| {
+            tx.send(DialogServiceConnectorEvent::Recognizing(event))
+        })?;
+        let tx = sender.clone();
+        self.set_recognized_cb(move |event| {
+            tx.send(DialogServiceConnectorEvent::Recognized(event))
+        })?;
+        let tx = sender.clone();
+        self.set_canceled_cb(move |event| tx.send(DialogServiceConnectorEvent::Canceled(event)))?;
+        let tx = sender.clone();
+        self.set_activity_received_cb(move |event| {
+            tx.send(DialogServiceConnectorEvent::ActivityReceived(event))
+        })?;
+        let tx = sender;
+        self.set_turn_status_received_cb(move |event| {
+            tx.send(DialogServiceConnectorEvent::TurnStatusReceived(event))
+        })?;
+        Ok(stream)
+    }
+
     pub fn set_session_started_cb<F>(&mut self, f: F) -> Result<()>
     where
         F: Fn(SessionEvent) + 'static + Send,
     {
-        self.session_started_cb = Some(Box::new(f));
+        self.callback_bag.session_started_cb = Some(Box::new(f));
         unsafe {
             let ret = dialog_service_connector_session_started_set_callback(
                 self.handle.inner(),
                 Some(Self::cb_session_started),
-                self as *const _ as *mut c_void,
+                &*self.callback_bag as *const _ as *mut c_void,
             );
             convert_err(ret, "DialogServiceConnector.set_session_started_cb error")?;
             Ok(())
@@ -231,12 +452,12 @@ impl DialogServiceConnector {
     where
         F: Fn(SessionEvent) + 'static + Send,
     {
-        self.session_stopped_cb = Some(Box::new(f));
+        self.callback_bag.session_stopped_cb = Some(Box::new(f));
         unsafe {
             let ret = dialog_service_connector_session_stopped_set_callback(
                 self.handle.inner(),
                 Some(Self::cb_session_stopped),
-                self as *const _ as *mut c_void,
+                &*self.callback_bag as *const _ as *mut c_void,
             );
             convert_err(ret, "DialogServiceConnector.set_session_stopped_cb error")?;
             Ok(())
@@ -247,12 +468,12 @@ impl DialogServiceConnector {
     where
         F: Fn(SpeechRecognitionCanceledEvent) + 'static + Send,
     {
-        self.canceled_cb = Some(Box::new(f));
+        self.callback_bag.canceled_cb = Some(Box::new(f));
         unsafe {
             let ret = dialog_service_connector_canceled_set_callback(
                 self.handle.inner(),
                 Some(Self::cb_canceled),
-                self as *const _ as *mut c_void,
+                &*self.callback_bag as *const _ as *mut c_void,
             );
             convert_err(ret, "DialogServiceConnector.set_canceled_cb error")?;
             Ok(())
@@ -263,12 +484,12 @@ impl DialogServiceConnector {
     where
         F: Fn(SpeechRecognitionEvent) + 'static + Send,
     {
-        self.recognizing_cb = Some(Box::new(f));
+        self.callback_bag.recognizing_cb = Some(Box::new(f));
         unsafe {
             let ret = dialog_service_connector_recognizing_set_callback(
                 self.handle.inner(),
                 Some(Self::cb_recognizing),
-                self as *const _ as *mut c_void,
+                &*self.callback_bag as *const _ as *mut c_void,
             );
             convert_err(ret, "DialogServiceConnector.set_recognizing_cb error")?;
             Ok(())
@@ -279,12 +500,12 @@ impl DialogServiceConnector {
     where
         F: Fn(SpeechRecognitionEvent) + 'static + Send,
     {
-        self.recognized_cb = Some(Box::new(f));
+        self.callback_bag.recognized_cb = Some(Box::new(f));
         unsafe {
             let ret = dialog_service_connector_recognized_set_callback(
                 self.handle.inner(),
                 Some(Self::cb_recognized),
-                self as *const _ as *mut c_void,
+                &*self.callback_bag as *const _ as *mut c_void,
             );
             convert_err(ret, "DialogServiceConnector.set_recognized_cb error")?;
             Ok(())
@@ -293,20 +514,40 @@ impl DialogServiceConnector {
 
     pub fn set_activity_received_cb<F>(&mut self, f: F) -> Result<()>
     where
-        F: Fn(SpeechRecognitionEvent) + 'static + Send,
+        F: Fn(ActivityReceivedEvent) + 'static + Send,
     {
-        self.recognized_cb = Some(Box::new(f));
+        self.callback_bag.activity_received_cb = Some(Box::new(f));
         unsafe {
             let ret = dialog_service_connector_activity_received_set_callback(
                 self.handle.inner(),
                 Some(Self::cb_activity_received),
-                self as *const _ as *mut c_void,
+                &*self.callback_bag as *const _ as *mut c_void,
             );
             convert_err(ret, "DialogServiceConnector.set_activity_received_cb error")?;
             Ok(())
         }
     }
 
+    /// Sets callback invoked when the dialog backend reports status of a turn.
+    pub fn set_turn_status_received_cb<F>(&mut self, f: F) -> Result<()>
+    where
+        F: Fn(TurnStatusReceivedEvent) + 'static + Send,
+    {
+        self.callback_bag.turn_status_received_cb = Some(Box::new(f));
+        unsafe {
+            let ret = dialog_service_connector_turn_status_received_set_callback(
+                self.handle.inner(),
+                Some(Self::cb_turn_status_received),
+                &*self.callback_bag as *const _ as *mut c_void,
+            );
+            convert_err(
+                ret,
+                "DialogServiceConnector.set_turn_status_received_cb error",
+            )?;
+            Ok(())
+        }
+    }
+
     #[allow(non_snake_case)]
     #[allow(unused_variables)]
     unsafe extern "C" fn cb_session_started(
@@ -315,9 +556,8 @@ impl DialogServiceConnector {
         pvContext: *mut c_void,
     ) {
         trace!("DialogServiceConnector::cb_session_started called");
-        let dialog_service_connector = &mut *(pvContext as *mut DialogServiceConnector);
-        trace!("dialog_service_connector {:?}", dialog_service_connector);
-        if let Some(cb) = &dialog_service_connector.session_started_cb {
+        let callback_bag = &mut *(pvContext as *mut CallbackBag);
+        if let Some(cb) = &callback_bag.session_started_cb {
             trace!("session_started_cb defined");
             match SessionEvent::from_handle(hevent) {
                 Ok(event) => {
@@ -339,8 +579,8 @@ impl DialogServiceConnector {
         pvContext: *mut c_void,
     ) {
         trace!("DialogServiceConnector::cb_session_stopped called");
-        let dialog_service_connector = &mut *(pvContext as *mut DialogServiceConnector);
-        if let Some(cb) = &dialog_service_connector.session_stopped_cb {
+        let callback_bag = &mut *(pvContext as *mut CallbackBag);
+        if let Some(cb) = &callback_bag.session_stopped_cb {
             trace!("cb_session_stopped defined");
             match SessionEvent::from_handle(hevent) {
                 Ok(event) => {
@@ -362,8 +602,8 @@ impl DialogServiceConnector {
         pvContext: *mut c_void,
     ) {
         trace!("DialogServiceConnector::cb_canceled called");
-        let dialog_service_connector = &mut *(pvContext as *mut DialogServiceConnector);
-        if let Some(cb) = &dialog_service_connector.canceled_cb {
+        let callback_bag = &mut *(pvContext as *mut CallbackBag);
+        if let Some(cb) = &callback_bag.canceled_cb {
             trace!("canceled_cb defined");
             match SpeechRecognitionCanceledEvent::from_handle(hevent) {
                 Ok(event) => {
@@ -385,8 +625,8 @@ impl DialogServiceConnector {
         pvContext: *mut c_void,
     ) {
         trace!("DialogServiceConnector::cb_recognizing called");
-        let dialog_service_connector = &mut *(pvContext as *mut DialogServiceConnector);
-        if let Some(cb) = &dialog_service_connector.recognizing_cb {
+        let callback_bag = &mut *(pvContext as *mut CallbackBag);
+        if let Some(cb) = &callback_bag.recognizing_cb {
             trace!("recognizing_cb defined");
             match SpeechRecognitionEvent::from_handle(hevent) {
                 Ok(event) => {
@@ -408,8 +648,8 @@ impl DialogServiceConnector {
         pvContext: *mut c_void,
     ) {
         trace!("DialogServiceConnector::cb_recognized called");
-        let dialog_service_connector = &mut *(pvContext as *mut DialogServiceConnector);
-        if let Some(cb) = &dialog_service_connector.recognized_cb {
+        let callback_bag = &mut *(pvContext as *mut CallbackBag);
+        if let Some(cb) = &callback_bag.recognized_cb {
             trace!("recognized_cb defined");
             match SpeechRecognitionEvent::from_handle(hevent) {
                 Ok(event) => {
@@ -431,8 +671,8 @@ impl DialogServiceConnector {
         pvContext: *mut c_void,
     ) {
         trace!("DialogServiceConnector::cb_activity_received called");
-        let dialog_service_connector = &mut *(pvContext as *mut DialogServiceConnector);
-        if let Some(cb) = &dialog_service_connector.activity_received_cb {
+        let callback_bag = &mut *(pvContext as *mut CallbackBag);
+        if let Some(cb) = &callback_bag.activity_received_cb {
             trace!("cb_activity_received defined");
             match ActivityReceivedEvent::from_handle(hevent) {
                 Ok(event) => {
@@ -448,4 +688,89 @@ impl DialogServiceConnector {
             }
         }
     }
+
+    #[allow(non_snake_case)]
+    #[allow(unused_variables)]
+    unsafe extern "C" fn cb_turn_status_received(
+        hreco: SPXRECOHANDLE,
+        hevent: SPXEVENTHANDLE,
+        pvContext: *mut c_void,
+    ) {
+        trace!("DialogServiceConnector::cb_turn_status_received called");
+        let callback_bag = &mut *(pvContext as *mut CallbackBag);
+        if let Some(cb) = &callback_bag.turn_status_received_cb {
+            trace!("cb_turn_status_received defined");
+            match TurnStatusReceivedEvent::from_handle(hevent) {
+                Ok(event) => {
+                    trace!("calling cb with event {:?}", event);
+                    cb(event);
+                }
+                Err(err) => {
+                    error!(
+                        "DialogServiceConnector::cb_turn_status_received error {:?}",
+                        err
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// Native connector can outlive this struct when future of pending operation
+/// (e.g. listen_once_async) is dropped, so registered callbacks are disconnected
+/// before callback bag is freed.
+impl Drop for DialogServiceConnector {
+    fn drop(&mut self) {
+        unsafe {
+            if self.callback_bag.session_started_cb.is_some() {
+                dialog_service_connector_session_started_set_callback(
+                    self.handle.inner(),
+                    None,
+                    std::ptr::null_mut(),
+                );
+            }
+            if self.callback_bag.session_stopped_cb.is_some() {
+                dialog_service_connector_session_stopped_set_callback(
+                    self.handle.inner(),
+                    None,
+                    std::ptr::null_mut(),
+                );
+            }
+            if self.callback_bag.canceled_cb.is_some() {
+                dialog_service_connector_canceled_set_callback(
+                    self.handle.inner(),
+                    None,
+                    std::ptr::null_mut(),
+                );
+            }
+            if self.callback_bag.recognizing_cb.is_some() {
+                dialog_service_connector_recognizing_set_callback(
+                    self.handle.inner(),
+                    None,
+                    std::ptr::null_mut(),
+                );
+            }
+            if self.callback_bag.recognized_cb.is_some() {
+                dialog_service_connector_recognized_set_callback(
+                    self.handle.inner(),
+                    None,
+                    std::ptr::null_mut(),
+                );
+            }
+            if self.callback_bag.activity_received_cb.is_some() {
+                dialog_service_connector_activity_received_set_callback(
+                    self.handle.inner(),
+                    None,
+                    std::ptr::null_mut(),
+                );
+            }
+            if self.callback_bag.turn_status_received_cb.is_some() {
+                dialog_service_connector_turn_status_received_set_callback(
+                    self.handle.inner(),
+                    None,
+                    std::ptr::null_mut(),
+                );
+            }
+        }
+    }
 }
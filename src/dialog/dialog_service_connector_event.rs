@@ -0,0 +1,15 @@
+use crate::dialog::{ActivityReceivedEvent, TurnStatusReceivedEvent};
+use crate::speech::{SessionEvent, SpeechRecognitionCanceledEvent, SpeechRecognitionEvent};
+
+/// Event yielded by stream returned from *DialogServiceConnector::events*.
+/// Each variant corresponds to one of the *DialogServiceConnector::set_*_cb* callbacks.
+#[derive(Debug)]
+pub enum DialogServiceConnectorEvent {
+    SessionStarted(SessionEvent),
+    SessionStopped(SessionEvent),
+    Recognizing(SpeechRecognitionEvent),
+    Recognized(SpeechRecognitionEvent),
+    Canceled(SpeechRecognitionCanceledEvent),
+    ActivityReceived(ActivityReceivedEvent),
+    TurnStatusReceived(TurnStatusReceivedEvent),
+}
@@ -0,0 +1,86 @@
+use crate::error::{convert_err, Result};
+use crate::ffi::{
+    dialog_service_connector_turn_status_received_get_conversation_id,
+    dialog_service_connector_turn_status_received_get_conversation_id_size,
+    dialog_service_connector_turn_status_received_get_interaction_id,
+    dialog_service_connector_turn_status_received_get_interaction_id_size,
+    dialog_service_connector_turn_status_received_get_status,
+    dialog_service_connector_turn_status_received_release, SmartHandle, SPXEVENTHANDLE,
+};
+use std::ffi::CStr;
+use std::os::raw::c_char;
+
+/// Event passed into callback registered by *DialogServiceConnector::set_turn_status_received_cb*.
+/// Reports the final status of a dialog turn, i.e. whether bot finished
+/// processing of the interaction (status code 200) or failed.
+#[derive(Debug)]
+pub struct TurnStatusReceivedEvent {
+    pub handle: SmartHandle<SPXEVENTHANDLE>,
+    pub interaction_id: String,
+    pub conversation_id: String,
+    pub status_code: i32,
+}
+
+impl TurnStatusReceivedEvent {
+    /// # Safety
+    /// `handle` must be a valid handle to a live turn status received event.
+    pub unsafe fn from_handle(handle: SPXEVENTHANDLE) -> Result<TurnStatusReceivedEvent> {
+        unsafe {
+            let mut size = 0;
+            let mut ret = dialog_service_connector_turn_status_received_get_interaction_id_size(
+                handle, &mut size,
+            );
+            convert_err(
+                ret,
+                "TurnStatusReceivedEvent::from_handle(get interaction id size) error",
+            )?;
+            let mut buf_vec = vec![0u8; size + 1];
+            let c_buf: *mut c_char = &mut buf_vec[..] as *const _ as *mut c_char;
+            ret = dialog_service_connector_turn_status_received_get_interaction_id(
+                handle, c_buf, size,
+            );
+            convert_err(
+                ret,
+                "TurnStatusReceivedEvent::from_handle(get interaction id) error",
+            )?;
+            let interaction_id = CStr::from_ptr(c_buf).to_str()?.to_owned();
+
+            ret = dialog_service_connector_turn_status_received_get_conversation_id_size(
+                handle, &mut size,
+            );
+            convert_err(
+                ret,
+                "TurnStatusReceivedEvent::from_handle(get conversation id size) error",
+            )?;
+            let mut buf_vec = vec![0u8; size + 1];
+            let c_buf: *mut c_char = &mut buf_vec[..] as *const _ as *mut c_char;
+            ret = dialog_service_connector_turn_status_received_get_conversation_id(
+                handle, c_buf, size,
+            );
+            convert_err(
+                ret,
+                "TurnStatusReceivedEvent::from_handle(get conversation id) error",
+            )?;
+            let conversation_id = CStr::from_ptr(c_buf).to_str()?.to_owned();
+
+            let mut status_code = 0;
+            ret =
+                dialog_service_connector_turn_status_received_get_status(handle, &mut status_code);
+            convert_err(
+                ret,
+                "TurnStatusReceivedEvent::from_handle(get status) error",
+            )?;
+
+            Ok(TurnStatusReceivedEvent {
+                handle: SmartHandle::create(
+                    "TurnStatusReceivedEvent",
+                    handle,
+                    dialog_service_connector_turn_status_received_release,
+                ),
+                interaction_id,
+                conversation_id,
+                status_code,
+            })
+        }
+    }
+}
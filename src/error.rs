@@ -3,10 +3,12 @@ use crate::ffi::{
     error_get_error_code, error_get_message, error_release, AZAC_HANDLE, SPX_NOERROR,
 };
 use std::ffi::{CStr, NulError};
+use std::io;
 use std::num::TryFromIntError;
 use std::result;
 use std::str::Utf8Error;
 use std::string::FromUtf8Error;
+use tokio::task::JoinError;
 
 /// Enumeration of error root causes. Where appropriate
 /// it wraps underlying error.
@@ -18,6 +20,11 @@ pub enum ErrorRootCause {
     FromUtf8Error(FromUtf8Error),
     Utf8Error(Utf8Error),
     TryFromIntError(TryFromIntError),
+    JoinError(JoinError),
+    JsonError(serde_json::Error),
+    TokenProviderError(Box<dyn std::error::Error + Send + Sync>),
+    IoError(io::Error),
+    InvalidWavFile,
 }
 
 /// Error struct represents error than can occur
@@ -42,6 +49,18 @@ impl Error {
         Error { message, caused_by }
     }
 
+    /// Creates error reporting failure of *TokenProvider* to fetch token.
+    pub fn token_provider<E>(error: E) -> Self
+    where
+        E: Into<Box<dyn std::error::Error + Send + Sync>>,
+    {
+        let error = error.into();
+        Error {
+            message: format!("token provider error: {}", error),
+            caused_by: ErrorRootCause::TokenProviderError(error),
+        }
+    }
+
     /// Returns description based on API error code.
     pub fn api_error_desc(error_root_cause: &ErrorRootCause) -> Option<String> {
         match error_root_cause {
@@ -168,3 +187,30 @@ impl From<TryFromIntError> for Error {
         }
     }
 }
+
+impl From<JoinError> for Error {
+    fn from(error: JoinError) -> Error {
+        Error {
+            message: format!("tokio::task::JoinError: {}", error),
+            caused_by: ErrorRootCause::JoinError(error),
+        }
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(error: serde_json::Error) -> Error {
+        Error {
+            message: format!("serde_json::Error: {}", error),
+            caused_by: ErrorRootCause::JsonError(error),
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(error: io::Error) -> Error {
+        Error {
+            message: format!("std::io::Error: {}", error),
+            caused_by: ErrorRootCause::IoError(error),
+        }
+    }
+}
@@ -12,7 +12,9 @@ use log::*;
 use std::fmt::Debug;
 use std::fmt::Display;
 use std::fmt::Formatter;
+use std::ops::Deref;
 use std::os::raw::c_char;
+use std::sync::Arc;
 pub const NULL_C_STR_PTR: *const c_char = 0 as *const c_char;
 
 // using std::mem::MaybeUninit::uninit(); instead
@@ -74,3 +76,71 @@ impl<T: Copy + Debug> Display for SmartHandle<T> {
 /// Send implementation so that we can send SmartHandles
 /// accross threads.
 unsafe impl<T: Copy + Debug> Send for SmartHandle<T> {}
+
+/// Reference counted SmartHandle which can be shared with blocking tasks
+/// (see *run_blocking*). SmartHandle itself is not Sync as not every native
+/// object may be used from multiple threads concurrently (e.g. results or events),
+/// SharedHandle is thus created only for handles of objects which can,
+/// see safety comments next to *SharedHandle::new* calls.
+#[derive(Debug)]
+pub struct SharedHandle<T: Copy + Debug>(Arc<SmartHandle<T>>);
+
+impl<T: Copy + Debug> SharedHandle<T> {
+    /// # Safety
+    /// Native object behind `handle` must be safe to use from multiple threads concurrently.
+    #[inline(always)]
+    pub(crate) unsafe fn new(handle: SmartHandle<T>) -> SharedHandle<T> {
+        SharedHandle(Arc::new(handle))
+    }
+}
+
+impl<T: Copy + Debug> Clone for SharedHandle<T> {
+    fn clone(&self) -> Self {
+        SharedHandle(self.0.clone())
+    }
+}
+
+impl<T: Copy + Debug> Deref for SharedHandle<T> {
+    type Target = SmartHandle<T>;
+
+    fn deref(&self) -> &SmartHandle<T> {
+        &self.0
+    }
+}
+
+/// SharedHandle wraps only handles of thread safe native objects, see *SharedHandle::new*.
+unsafe impl<T: Copy + Debug> Sync for SharedHandle<T> {}
+unsafe impl<T: Copy + Debug> Send for SharedHandle<T> {}
+
+/// Waits for native asynchronous operation represented by async handle
+/// to complete. Native *_async_wait_for functions block calling thread,
+/// that is why *wait_fn* is executed on tokio blocking thread pool
+/// so that tokio worker threads are never blocked.
+/// SmartHandle is moved into the blocking task which keeps async
+/// handle alive until *wait_fn* returns, even when awaiting future is dropped.
+/// Dropping the future does not cancel native operation, it keeps running
+/// and its result is discarded. Native objects can thus outlive their Rust
+/// owners, which is why structs registering native callbacks disconnect them
+/// in their Drop implementations before callback bag is freed.
+pub(crate) async fn wait_for_async<R, F>(
+    handle: SmartHandle<SPXASYNCHANDLE>,
+    wait_fn: F,
+) -> crate::error::Result<R>
+where
+    R: Send + 'static,
+    F: FnOnce(SPXASYNCHANDLE) -> crate::error::Result<R> + Send + 'static,
+{
+    tokio::task::spawn_blocking(move || wait_fn(handle.inner())).await?
+}
+
+/// Runs blocking native call (e.g. synchronous round-trip to the speech service)
+/// on tokio blocking thread pool so that tokio worker threads are never blocked.
+/// Handles used by *call_fn* must be moved into it (e.g. cloned SharedHandle)
+/// so that they stay alive until *call_fn* returns, even when awaiting future is dropped.
+pub(crate) async fn run_blocking<R, F>(call_fn: F) -> crate::error::Result<R>
+where
+    R: Send + 'static,
+    F: FnOnce() -> crate::error::Result<R> + Send + 'static,
+{
+    tokio::task::spawn_blocking(call_fn).await?
+}
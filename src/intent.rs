@@ -0,0 +1,20 @@
+//! Package intent provides functionality for intent recognition (offline pattern matching
+//! or Conversational Language Understanding) along with related models, results and events.
+mod conversational_language_understanding_model;
+mod intent_recognition_canceled_event;
+mod intent_recognition_event;
+mod intent_recognition_result;
+mod intent_recognizer;
+mod language_understanding_model;
+mod pattern_matching_model;
+
+// re-export structs directly under intent module
+pub use self::conversational_language_understanding_model::ConversationalLanguageUnderstandingModel;
+pub use self::intent_recognition_canceled_event::IntentRecognitionCanceledEvent;
+pub use self::intent_recognition_event::IntentRecognitionEvent;
+pub use self::intent_recognition_result::IntentRecognitionResult;
+pub use self::intent_recognizer::IntentRecognizer;
+pub use self::language_understanding_model::LanguageUnderstandingModel;
+pub use self::pattern_matching_model::{
+    PatternMatchingEntity, PatternMatchingIntent, PatternMatchingModel,
+};
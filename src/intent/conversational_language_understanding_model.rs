@@ -0,0 +1,27 @@
+/// ConversationalLanguageUnderstandingModel represents
+/// Conversational Language Understanding (CLU) project deployment
+/// evaluated by language service. Apply it to recognizer
+/// with *IntentRecognizer::apply_language_models*.
+#[derive(Debug, Clone)]
+pub struct ConversationalLanguageUnderstandingModel {
+    pub language_resource_key: String,
+    pub endpoint: String,
+    pub project_name: String,
+    pub deployment_name: String,
+}
+
+impl ConversationalLanguageUnderstandingModel {
+    pub fn new<S: Into<String>>(
+        language_resource_key: S,
+        endpoint: S,
+        project_name: S,
+        deployment_name: S,
+    ) -> Self {
+        ConversationalLanguageUnderstandingModel {
+            language_resource_key: language_resource_key.into(),
+            endpoint: endpoint.into(),
+            project_name: project_name.into(),
+            deployment_name: deployment_name.into(),
+        }
+    }
+}
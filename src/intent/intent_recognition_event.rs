@@ -0,0 +1,30 @@
+use crate::error::{convert_err, Result};
+use crate::ffi::{recognizer_recognition_event_get_result, SPXEVENTHANDLE, SPXRESULTHANDLE};
+use crate::intent::IntentRecognitionResult;
+use crate::speech::RecognitionEvent;
+use log::*;
+use std::mem::MaybeUninit;
+
+/// Recognition event extending *RecognitionEvent* passed into callbacks *set_recognizing_cb* and *set_recognized_cb*.
+#[derive(Debug)]
+pub struct IntentRecognitionEvent {
+    pub base: RecognitionEvent,
+    pub result: IntentRecognitionResult,
+}
+
+impl IntentRecognitionEvent {
+    /// # Safety
+    /// `handle` must be a valid handle to a live intent recognition event.
+    pub unsafe fn from_handle(handle: SPXEVENTHANDLE) -> Result<IntentRecognitionEvent> {
+        unsafe {
+            let base = RecognitionEvent::from_handle(handle)?;
+            let mut result_handle: MaybeUninit<SPXRESULTHANDLE> = MaybeUninit::uninit();
+            trace!("calling recognizer_recognition_event_get_result");
+            let ret = recognizer_recognition_event_get_result(handle, result_handle.as_mut_ptr());
+            convert_err(ret, "IntentRecognitionEvent::from_handle error")?;
+            trace!("called recognizer_recognition_event_get_result");
+            let result = IntentRecognitionResult::from_handle(result_handle.assume_init())?;
+            Ok(IntentRecognitionEvent { base, result })
+        }
+    }
+}
@@ -0,0 +1,82 @@
+use crate::error::{convert_err, Result};
+use crate::ffi::{intent_result_get_intent_id, SPXRESULTHANDLE};
+use crate::speech::SpeechRecognitionResult;
+use log::*;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::ffi::CStr;
+use std::fmt;
+
+/// Represents intent recognition result contained within callback event *IntentRecognitionEvent*.
+/// Extends *SpeechRecognitionResult* with recognized intent and extracted entities.
+pub struct IntentRecognitionResult {
+    pub base: SpeechRecognitionResult,
+    /// Identifier of recognized intent, empty if no intent was recognized.
+    pub intent_id: String,
+    /// Entities extracted by pattern matching model, keyed by entity id.
+    pub entities: HashMap<String, String>,
+}
+
+impl fmt::Debug for IntentRecognitionResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("IntentRecognitionResult")
+            .field("base", &self.base)
+            .field("intent_id", &self.intent_id)
+            .field("entities", &self.entities)
+            .finish()
+    }
+}
+
+impl IntentRecognitionResult {
+    /// # Safety
+    /// `handle` must be a valid handle to a live intent recognition result.
+    pub unsafe fn from_handle(handle: SPXRESULTHANDLE) -> Result<IntentRecognitionResult> {
+        unsafe {
+            let base = SpeechRecognitionResult::from_handle(handle)?;
+
+            let mut c_buf = [0; 1024];
+            let ret = intent_result_get_intent_id(
+                base.handle.inner(),
+                c_buf.as_mut_ptr(),
+                c_buf.len() as u32,
+            );
+            convert_err(
+                ret,
+                "IntentRecognitionResult::from_handle(intent_result_get_intent_id) error",
+            )?;
+            let intent_id = CStr::from_ptr(c_buf.as_ptr()).to_str()?.to_owned();
+
+            let entities_json = base
+                .properties
+                .get_property_by_string("LanguageUnderstandingSLE_JsonResult", "")?;
+            let entities = IntentRecognitionResult::parse_entities(&entities_json);
+
+            Ok(IntentRecognitionResult {
+                base,
+                intent_id,
+                entities,
+            })
+        }
+    }
+
+    /// Entities are returned as flat JSON object of entity id and entity value.
+    fn parse_entities(json: &str) -> HashMap<String, String> {
+        let mut entities = HashMap::new();
+        if json.is_empty() {
+            return entities;
+        }
+        match serde_json::from_str::<HashMap<String, Value>>(json) {
+            Ok(values) => {
+                for (id, value) in values {
+                    let value = match value {
+                        Value::String(value) => value,
+                        value => value.to_string(),
+                    };
+                    entities.insert(id, value);
+                }
+            }
+            Err(err) => warn!("Error when parsing intent entities {:?}", err),
+        }
+        entities
+    }
+}
@@ -0,0 +1,680 @@
+use crate::audio::AudioConfig;
+use crate::common::{PropertyCollection, PropertyId};
+use crate::error::{convert_err, Result};
+use crate::ffi::{
+    intent_recognizer_add_conversational_language_understanding_model,
+    intent_recognizer_add_intent, intent_recognizer_add_intent_with_model_id,
+    intent_recognizer_clear_language_models, intent_recognizer_import_pattern_matching_model,
+    intent_recognizer_recognize_text_once, intent_trigger_create_from_language_understanding_model,
+    intent_trigger_create_from_phrase, intent_trigger_handle_release,
+    language_understanding_model__handle_release, pattern_matching_model_create,
+    recognizer_async_handle_release, recognizer_canceled_set_callback,
+    recognizer_create_intent_recognizer_from_config, recognizer_get_property_bag,
+    recognizer_handle_release, recognizer_recognize_once_async,
+    recognizer_recognize_once_async_wait_for, recognizer_recognized_set_callback,
+    recognizer_recognizing_set_callback, recognizer_session_started_set_callback,
+    recognizer_session_stopped_set_callback, recognizer_speech_end_detected_set_callback,
+    recognizer_speech_start_detected_set_callback, recognizer_start_continuous_recognition_async,
+    recognizer_start_continuous_recognition_async_wait_for,
+    recognizer_stop_continuous_recognition_async,
+    recognizer_stop_continuous_recognition_async_wait_for, wait_for_async, SmartHandle,
+    SPXASYNCHANDLE, SPXEVENTHANDLE, SPXLUMODELHANDLE, SPXPROPERTYBAGHANDLE, SPXRECOHANDLE,
+    SPXRESULTHANDLE, SPXTRIGGERHANDLE,
+};
+use crate::intent::{
+    IntentRecognitionCanceledEvent, IntentRecognitionEvent, IntentRecognitionResult,
+    LanguageUnderstandingModel,
+};
+use crate::speech::{RecognitionEvent, Recognizer, SealedRecognizer, SessionEvent, SpeechConfig};
+use log::*;
+use std::boxed::Box;
+use std::ffi::CString;
+use std::fmt;
+use std::mem::MaybeUninit;
+use std::os::raw::c_void;
+
+/// A separate internal struct to hold all the callback closures for the intent recognizer events.
+/// By creating a separate struct, and then boxing this struct inside our IntentRecognizer,
+/// we can ensure the IntentRecognizer itself can be moved freely by end users,
+/// and the callbacks will remain at a fixed memory address on the heap.
+struct CallbackBag {
+    session_started_cb: Option<Box<dyn Fn(SessionEvent) + Send>>,
+    session_stopped_cb: Option<Box<dyn Fn(SessionEvent) + Send>>,
+    speech_start_detected_cb: Option<Box<dyn Fn(RecognitionEvent) + Send>>,
+    speech_end_detected_cb: Option<Box<dyn Fn(RecognitionEvent) + Send>>,
+    canceled_cb: Option<Box<dyn Fn(IntentRecognitionCanceledEvent) + Send>>,
+    recognizing_cb: Option<Box<dyn Fn(IntentRecognitionEvent) + Send>>,
+    recognized_cb: Option<Box<dyn Fn(IntentRecognitionEvent) + Send>>,
+}
+
+/// IntentRecognizer struct holds functionality for intent recognition,
+/// i.e. speech-to-text recognition followed by evaluation of language understanding models.
+pub struct IntentRecognizer {
+    pub(crate) handle: SmartHandle<SPXRECOHANDLE>,
+    properties: PropertyCollection,
+    callback_bag: Box<CallbackBag>,
+}
+
+impl fmt::Debug for IntentRecognizer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("IntentRecognizer")
+            .field("handle", &self.handle)
+            .field("properties", &self.properties)
+            .finish()
+    }
+}
+
+impl SealedRecognizer for IntentRecognizer {}
+
+impl Recognizer for IntentRecognizer {
+    fn get_handle(&self) -> SPXRECOHANDLE {
+        self.handle.inner()
+    }
+}
+
+impl IntentRecognizer {
+    /// # Safety
+    /// `handle` must be a valid handle to a live intent recognizer.
+    unsafe fn from_handle(handle: SPXRECOHANDLE) -> Result<IntentRecognizer> {
+        unsafe {
+            let mut prop_bag_handle: MaybeUninit<SPXPROPERTYBAGHANDLE> = MaybeUninit::uninit();
+            let ret = recognizer_get_property_bag(handle, prop_bag_handle.as_mut_ptr());
+            convert_err(ret, "IntentRecognizer::from_handle error")?;
+
+            let property_bag = PropertyCollection::from_handle(prop_bag_handle.assume_init());
+
+            let result = IntentRecognizer {
+                handle: SmartHandle::create("IntentRecognizer", handle, recognizer_handle_release),
+                properties: property_bag,
+                callback_bag: Box::new(CallbackBag {
+                    session_started_cb: None,
+                    session_stopped_cb: None,
+                    speech_start_detected_cb: None,
+                    speech_end_detected_cb: None,
+                    canceled_cb: None,
+                    recognizing_cb: None,
+                    recognized_cb: None,
+                }),
+            };
+            Ok(result)
+        }
+    }
+
+    pub fn from_config(
+        speech_config: SpeechConfig,
+        audio_config: AudioConfig,
+    ) -> Result<IntentRecognizer> {
+        unsafe {
+            let mut handle: MaybeUninit<SPXRECOHANDLE> = MaybeUninit::uninit();
+            convert_err(
+                recognizer_create_intent_recognizer_from_config(
+                    handle.as_mut_ptr(),
+                    speech_config.handle.inner(),
+                    audio_config.handle.inner(),
+                ),
+                "IntentRecognizer.from_config error",
+            )?;
+            IntentRecognizer::from_handle(handle.assume_init())
+        }
+    }
+
+    /// Adds simple phrase that may be spoken by the user, indicating a specific user intent.
+    /// Phrase must be matched exactly.
+    pub fn add_intent(&mut self, phrase: &str, intent_id: &str) -> Result<()> {
+        unsafe {
+            let c_phrase = CString::new(phrase)?;
+            let c_intent_id = CString::new(intent_id)?;
+            let mut trigger_handle: MaybeUninit<SPXTRIGGERHANDLE> = MaybeUninit::uninit();
+            let ret =
+                intent_trigger_create_from_phrase(trigger_handle.as_mut_ptr(), c_phrase.as_ptr());
+            convert_err(ret, "IntentRecognizer.add_intent(create trigger) error")?;
+            let trigger = SmartHandle::create(
+                "IntentTrigger",
+                trigger_handle.assume_init(),
+                intent_trigger_handle_release,
+            );
+            let ret = intent_recognizer_add_intent(
+                self.handle.inner(),
+                c_intent_id.as_ptr(),
+                trigger.inner(),
+            );
+            convert_err(ret, "IntentRecognizer.add_intent error")
+        }
+    }
+
+    /// Clears all language models currently used by recognizer
+    /// and applies specified pattern matching and CLU models.
+    pub fn apply_language_models(&mut self, models: &[LanguageUnderstandingModel]) -> Result<()> {
+        unsafe {
+            let ret = intent_recognizer_clear_language_models(self.handle.inner());
+            convert_err(ret, "IntentRecognizer.apply_language_models(clear) error")?;
+        }
+        for model in models {
+            match model {
+                LanguageUnderstandingModel::PatternMatching(model) => unsafe {
+                    let c_model_id = CString::new(model.model_id.as_str())?;
+                    let mut model_handle: MaybeUninit<SPXLUMODELHANDLE> = MaybeUninit::uninit();
+                    let ret = pattern_matching_model_create(
+                        model_handle.as_mut_ptr(),
+                        self.handle.inner(),
+                        c_model_id.as_ptr(),
+                    );
+                    convert_err(
+                        ret,
+                        "IntentRecognizer.apply_language_models(pattern_matching_model_create) error",
+                    )?;
+                    let model_handle = SmartHandle::create(
+                        "PatternMatchingModel",
+                        model_handle.assume_init(),
+                        language_understanding_model__handle_release,
+                    );
+                    model.populate(model_handle.inner())?;
+
+                    let mut trigger_handle: MaybeUninit<SPXTRIGGERHANDLE> = MaybeUninit::uninit();
+                    let ret = intent_trigger_create_from_language_understanding_model(
+                        trigger_handle.as_mut_ptr(),
+                        model_handle.inner(),
+                        std::ptr::null(),
+                    );
+                    convert_err(
+                        ret,
+                        "IntentRecognizer.apply_language_models(create trigger) error",
+                    )?;
+                    let trigger = SmartHandle::create(
+                        "IntentTrigger",
+                        trigger_handle.assume_init(),
+                        intent_trigger_handle_release,
+                    );
+                    let ret = intent_recognizer_add_intent_with_model_id(
+                        self.handle.inner(),
+                        trigger.inner(),
+                        c_model_id.as_ptr(),
+                    );
+                    convert_err(
+                        ret,
+                        "IntentRecognizer.apply_language_models(add_intent_with_model_id) error",
+                    )?;
+                },
+                LanguageUnderstandingModel::ConversationalLanguageUnderstanding(model) => unsafe {
+                    let c_key = CString::new(model.language_resource_key.as_str())?;
+                    let c_endpoint = CString::new(model.endpoint.as_str())?;
+                    let c_project_name = CString::new(model.project_name.as_str())?;
+                    let c_deployment_name = CString::new(model.deployment_name.as_str())?;
+                    let ret = intent_recognizer_add_conversational_language_understanding_model(
+                        self.handle.inner(),
+                        c_key.as_ptr(),
+                        c_endpoint.as_ptr(),
+                        c_project_name.as_ptr(),
+                        c_deployment_name.as_ptr(),
+                    );
+                    convert_err(
+                        ret,
+                        "IntentRecognizer.apply_language_models(add_conversational_language_understanding_model) error",
+                    )?;
+                },
+            }
+        }
+        Ok(())
+    }
+
+    /// Imports pattern matching model from JSON (as exported from Language Studio).
+    pub fn import_pattern_matching_model(&mut self, json: &str) -> Result<()> {
+        unsafe {
+            let c_json = CString::new(json)?;
+            let ret = intent_recognizer_import_pattern_matching_model(
+                self.handle.inner(),
+                c_json.as_ptr(),
+            );
+            convert_err(ret, "IntentRecognizer.import_pattern_matching_model error")
+        }
+    }
+
+    pub fn set_session_started_cb<F>(&mut self, f: F) -> Result<()>
+    where
+        F: Fn(SessionEvent) + 'static + Send,
+    {
+        self.callback_bag.session_started_cb = Some(Box::new(f));
+        unsafe {
+            let ret = recognizer_session_started_set_callback(
+                self.handle.inner(),
+                Some(Self::cb_session_started),
+                &*self.callback_bag as *const _ as *mut c_void,
+            );
+            convert_err(ret, "IntentRecognizer.set_session_started_cb error")?;
+            Ok(())
+        }
+    }
+
+    pub fn set_session_stopped_cb<F>(&mut self, f: F) -> Result<()>
+    where
+        F: Fn(SessionEvent) + 'static + Send,
+    {
+        self.callback_bag.session_stopped_cb = Some(Box::new(f));
+        unsafe {
+            let ret = recognizer_session_stopped_set_callback(
+                self.handle.inner(),
+                Some(Self::cb_session_stopped),
+                &*self.callback_bag as *const _ as *mut c_void,
+            );
+            convert_err(ret, "IntentRecognizer.set_session_stopped_cb error")?;
+            Ok(())
+        }
+    }
+
+    pub fn set_speech_start_detected_cb<F>(&mut self, f: F) -> Result<()>
+    where
+        F: Fn(RecognitionEvent) + 'static + Send,
+    {
+        self.callback_bag.speech_start_detected_cb = Some(Box::new(f));
+        unsafe {
+            let ret = recognizer_speech_start_detected_set_callback(
+                self.handle.inner(),
+                Some(Self::cb_speech_start_detected),
+                &*self.callback_bag as *const _ as *mut c_void,
+            );
+            convert_err(ret, "IntentRecognizer.set_speech_start_detected_cb error")?;
+            Ok(())
+        }
+    }
+
+    pub fn set_speech_end_detected_cb<F>(&mut self, f: F) -> Result<()>
+    where
+        F: Fn(RecognitionEvent) + 'static + Send,
+    {
+        self.callback_bag.speech_end_detected_cb = Some(Box::new(f));
+        unsafe {
+            let ret = recognizer_speech_end_detected_set_callback(
+                self.handle.inner(),
+                Some(Self::cb_speech_end_detected),
+                &*self.callback_bag as *const _ as *mut c_void,
+            );
+            convert_err(ret, "IntentRecognizer.set_speech_end_detected_cb error")?;
+            Ok(())
+        }
+    }
+
+    /// Canceled signals for events containing canceled recognition results
+    /// (indicating a recognition attempt that was canceled as a result or a direct cancellation request
+    /// or, alternatively, a transport or protocol failure).
+    pub fn set_canceled_cb<F>(&mut self, f: F) -> Result<()>
+    where
+        F: Fn(IntentRecognitionCanceledEvent) + 'static + Send,
+    {
+        self.callback_bag.canceled_cb = Some(Box::new(f));
+        unsafe {
+            let ret = recognizer_canceled_set_callback(
+                self.handle.inner(),
+                Some(Self::cb_canceled),
+                &*self.callback_bag as *const _ as *mut c_void,
+            );
+            convert_err(ret, "IntentRecognizer.set_canceled_cb error")?;
+            Ok(())
+        }
+    }
+
+    pub fn set_recognizing_cb<F>(&mut self, f: F) -> Result<()>
+    where
+        F: Fn(IntentRecognitionEvent) + 'static + Send,
+    {
+        self.callback_bag.recognizing_cb = Some(Box::new(f));
+        unsafe {
+            let ret = recognizer_recognizing_set_callback(
+                self.handle.inner(),
+                Some(Self::cb_recognizing),
+                &*self.callback_bag as *const _ as *mut c_void,
+            );
+            convert_err(ret, "IntentRecognizer.set_recognizing_cb error")?;
+            Ok(())
+        }
+    }
+
+    pub fn set_recognized_cb<F>(&mut self, f: F) -> Result<()>
+    where
+        F: Fn(IntentRecognitionEvent) + 'static + Send,
+    {
+        self.callback_bag.recognized_cb = Some(Box::new(f));
+        unsafe {
+            let ret = recognizer_recognized_set_callback(
+                self.handle.inner(),
+                Some(Self::cb_recognized),
+                &*self.callback_bag as *const _ as *mut c_void,
+            );
+            convert_err(ret, "IntentRecognizer.set_recognized_cb error")?;
+            Ok(())
+        }
+    }
+
+    #[allow(non_snake_case)]
+    #[allow(unused_variables)]
+    unsafe extern "C" fn cb_session_started(
+        hreco: SPXRECOHANDLE,
+        hevent: SPXEVENTHANDLE,
+        pvContext: *mut c_void,
+    ) {
+        trace!("IntentRecognizer::cb_session_started called");
+        let callback_bag = &mut *(pvContext as *mut CallbackBag);
+        if let Some(cb) = &callback_bag.session_started_cb {
+            trace!("session_started_cb defined");
+            match SessionEvent::from_handle(hevent) {
+                Ok(event) => {
+                    trace!("calling cb with event {:?}", event);
+                    cb(event);
+                }
+                Err(err) => {
+                    error!("IntentRecognizer::cb_session_started error {:?}", err);
+                }
+            }
+        }
+    }
+
+    #[allow(non_snake_case)]
+    #[allow(unused_variables)]
+    unsafe extern "C" fn cb_session_stopped(
+        hreco: SPXRECOHANDLE,
+        hevent: SPXEVENTHANDLE,
+        pvContext: *mut c_void,
+    ) {
+        trace!("IntentRecognizer::cb_session_stopped called");
+        let callback_bag = &mut *(pvContext as *mut CallbackBag);
+        if let Some(cb) = &callback_bag.session_stopped_cb {
+            trace!("session_stopped_cb defined");
+            match SessionEvent::from_handle(hevent) {
+                Ok(event) => {
+                    trace!("calling cb with event {:?}", event);
+                    cb(event);
+                }
+                Err(err) => {
+                    error!("IntentRecognizer::cb_session_stopped error {:?}", err);
+                }
+            }
+        }
+    }
+
+    #[allow(non_snake_case)]
+    #[allow(unused_variables)]
+    unsafe extern "C" fn cb_speech_start_detected(
+        hreco: SPXRECOHANDLE,
+        hevent: SPXEVENTHANDLE,
+        pvContext: *mut c_void,
+    ) {
+        trace!("IntentRecognizer::cb_speech_start_detected called");
+        let callback_bag = &mut *(pvContext as *mut CallbackBag);
+        if let Some(cb) = &callback_bag.speech_start_detected_cb {
+            trace!("speech_start_detected_cb defined");
+            match RecognitionEvent::from_handle(hevent) {
+                Ok(event) => {
+                    trace!("calling cb with event {:?}", event);
+                    cb(event);
+                }
+                Err(err) => {
+                    error!("IntentRecognizer::cb_speech_start_detected error {:?}", err);
+                }
+            }
+        }
+    }
+
+    #[allow(non_snake_case)]
+    #[allow(unused_variables)]
+    unsafe extern "C" fn cb_speech_end_detected(
+        hreco: SPXRECOHANDLE,
+        hevent: SPXEVENTHANDLE,
+        pvContext: *mut c_void,
+    ) {
+        trace!("IntentRecognizer::cb_speech_end_detected called");
+        let callback_bag = &mut *(pvContext as *mut CallbackBag);
+        if let Some(cb) = &callback_bag.speech_end_detected_cb {
+            trace!("speech_end_detected_cb defined");
+            match RecognitionEvent::from_handle(hevent) {
+                Ok(event) => {
+                    trace!("calling cb with event {:?}", event);
+                    cb(event);
+                }
+                Err(err) => {
+                    error!("IntentRecognizer::cb_speech_end_detected error {:?}", err);
+                }
+            }
+        }
+    }
+
+    #[allow(non_snake_case)]
+    #[allow(unused_variables)]
+    unsafe extern "C" fn cb_canceled(
+        hreco: SPXRECOHANDLE,
+        hevent: SPXEVENTHANDLE,
+        pvContext: *mut c_void,
+    ) {
+        trace!("IntentRecognizer::cb_canceled called");
+        let callback_bag = &mut *(pvContext as *mut CallbackBag);
+        if let Some(cb) = &callback_bag.canceled_cb {
+            trace!("canceled_cb defined");
+            match IntentRecognitionCanceledEvent::from_handle(hevent) {
+                Ok(event) => {
+                    trace!("calling cb with event {:?}", event);
+                    cb(event);
+                }
+                Err(err) => {
+                    error!("IntentRecognizer::cb_canceled error {:?}", err);
+                }
+            }
+        }
+    }
+
+    #[allow(non_snake_case)]
+    #[allow(unused_variables)]
+    unsafe extern "C" fn cb_recognizing(
+        hreco: SPXRECOHANDLE,
+        hevent: SPXEVENTHANDLE,
+        pvContext: *mut c_void,
+    ) {
+        trace!("IntentRecognizer::cb_recognizing called");
+        let callback_bag = &mut *(pvContext as *mut CallbackBag);
+        if let Some(cb) = &callback_bag.recognizing_cb {
+            trace!("recognizing_cb defined");
+            match IntentRecognitionEvent::from_handle(hevent) {
+                Ok(event) => {
+                    trace!("calling cb with event {:?}", event);
+                    cb(event);
+                }
+                Err(err) => {
+                    error!("IntentRecognizer::cb_recognizing error {:?}", err);
+                }
+            }
+        }
+    }
+
+    #[allow(non_snake_case)]
+    #[allow(unused_variables)]
+    unsafe extern "C" fn cb_recognized(
+        hreco: SPXRECOHANDLE,
+        hevent: SPXEVENTHANDLE,
+        pvContext: *mut c_void,
+    ) {
+        trace!("IntentRecognizer::cb_recognized called");
+        let callback_bag = &mut *(pvContext as *mut CallbackBag);
+        if let Some(cb) = &callback_bag.recognized_cb {
+            trace!("recognized_cb defined");
+            match IntentRecognitionEvent::from_handle(hevent) {
+                Ok(event) => {
+                    trace!("calling cb with event {:?}", event);
+                    cb(event);
+                }
+                Err(err) => {
+                    error!("IntentRecognizer::cb_recognized error {:?}", err);
+                }
+            }
+        }
+    }
+
+    /// Starts intent recognition, and returns after a single utterance is recognized.
+    pub async fn recognize_once_async(&mut self) -> Result<IntentRecognitionResult> {
+        let handle_async = unsafe {
+            let mut handle_async: MaybeUninit<SPXASYNCHANDLE> = MaybeUninit::uninit();
+            let ret =
+                recognizer_recognize_once_async(self.handle.inner(), handle_async.as_mut_ptr());
+            convert_err(ret, "IntentRecognizer.recognize_once_async error")?;
+            SmartHandle::create(
+                "handle_async_recognize_once",
+                handle_async.assume_init(),
+                recognizer_async_handle_release,
+            )
+        };
+        wait_for_async(handle_async, |handle_async| unsafe {
+            let mut handle_result: MaybeUninit<SPXRESULTHANDLE> = MaybeUninit::uninit();
+            let ret = recognizer_recognize_once_async_wait_for(
+                handle_async,
+                u32::MAX,
+                handle_result.as_mut_ptr(),
+            );
+            convert_err(
+                ret,
+                "IntentRecognizer.recognizer_recognize_once_async_wait_for error",
+            )?;
+            IntentRecognitionResult::from_handle(handle_result.assume_init())
+        })
+        .await
+    }
+
+    /// Performs intent recognition on provided text, i.e. no audio is processed.
+    /// Only locally evaluated models (phrases and pattern matching models) are used.
+    pub fn recognize_text_once(&mut self, text: &str) -> Result<IntentRecognitionResult> {
+        unsafe {
+            let c_text = CString::new(text)?;
+            let mut handle_result: MaybeUninit<SPXRESULTHANDLE> = MaybeUninit::uninit();
+            let ret = intent_recognizer_recognize_text_once(
+                self.handle.inner(),
+                c_text.as_ptr(),
+                handle_result.as_mut_ptr(),
+            );
+            convert_err(ret, "IntentRecognizer.recognize_text_once error")?;
+            IntentRecognitionResult::from_handle(handle_result.assume_init())
+        }
+    }
+
+    /// Asynchronously initiates continuous intent recognition operation.
+    pub async fn start_continuous_recognition_async(&mut self) -> Result<()> {
+        let handle_async_start_continuous = unsafe {
+            let mut handle_async_start_continuous: MaybeUninit<SPXASYNCHANDLE> =
+                MaybeUninit::uninit();
+            let ret = recognizer_start_continuous_recognition_async(
+                self.handle.inner(),
+                handle_async_start_continuous.as_mut_ptr(),
+            );
+            convert_err(
+                ret,
+                "IntentRecognizer.recognizer_start_continuous_recognition_async error",
+            )?;
+            SmartHandle::create(
+                "handle_async_start_continuous",
+                handle_async_start_continuous.assume_init(),
+                recognizer_async_handle_release,
+            )
+        };
+        wait_for_async(handle_async_start_continuous, |handle_async| unsafe {
+            let ret =
+                recognizer_start_continuous_recognition_async_wait_for(handle_async, u32::MAX);
+            convert_err(
+                ret,
+                "IntentRecognizer.recognizer_start_continuous_recognition_async_wait_for error",
+            )
+        })
+        .await
+    }
+
+    /// Asynchronously terminates ongoing continuous intent recognition operation.
+    pub async fn stop_continuous_recognition_async(&mut self) -> Result<()> {
+        let handle_async_stop_continuous = unsafe {
+            let mut handle_async_stop_continuous: MaybeUninit<SPXASYNCHANDLE> =
+                MaybeUninit::uninit();
+            let ret = recognizer_stop_continuous_recognition_async(
+                self.handle.inner(),
+                handle_async_stop_continuous.as_mut_ptr(),
+            );
+            convert_err(
+                ret,
+                "IntentRecognizer.recognizer_stop_continuous_recognition_async error",
+            )?;
+            SmartHandle::create(
+                "handle_async_stop_continuous",
+                handle_async_stop_continuous.assume_init(),
+                recognizer_async_handle_release,
+            )
+        };
+        wait_for_async(handle_async_stop_continuous, |handle_async| unsafe {
+            let ret = recognizer_stop_continuous_recognition_async_wait_for(handle_async, u32::MAX);
+            convert_err(
+                ret,
+                "IntentRecognizer.recognizer_stop_continuous_recognition_async_wait_for error",
+            )
+        })
+        .await
+    }
+
+    pub fn get_endpoint_id(&self) -> Result<String> {
+        self.properties
+            .get_property(PropertyId::SpeechServiceConnectionEndpointId, "")
+    }
+
+    pub fn get_auth_token(&self) -> Result<String> {
+        self.properties
+            .get_property(PropertyId::SpeechServiceAuthorizationToken, "")
+    }
+
+    /// Sets the authorization token that will be used for connecting to the service.
+    /// Note: The caller needs to ensure that the authorization token is valid. Before the authorization token
+    /// expires, the caller needs to refresh it by calling this setter with a new valid token.
+    /// Otherwise, the recognizer will encounter errors during recognition.
+    pub fn set_auth_token(&mut self, token: &str) -> Result<()> {
+        self.properties
+            .set_property(PropertyId::SpeechServiceAuthorizationToken, token)
+    }
+}
+
+/// Disconnects registered callbacks before callback bag is freed, as native recognizer
+/// can outlive this struct when future of pending recognition is dropped.
+impl Drop for IntentRecognizer {
+    fn drop(&mut self) {
+        unsafe {
+            if self.callback_bag.session_started_cb.is_some() {
+                recognizer_session_started_set_callback(
+                    self.handle.inner(),
+                    None,
+                    std::ptr::null_mut(),
+                );
+            }
+            if self.callback_bag.session_stopped_cb.is_some() {
+                recognizer_session_stopped_set_callback(
+                    self.handle.inner(),
+                    None,
+                    std::ptr::null_mut(),
+                );
+            }
+            if self.callback_bag.speech_start_detected_cb.is_some() {
+                recognizer_speech_start_detected_set_callback(
+                    self.handle.inner(),
+                    None,
+                    std::ptr::null_mut(),
+                );
+            }
+            if self.callback_bag.speech_end_detected_cb.is_some() {
+                recognizer_speech_end_detected_set_callback(
+                    self.handle.inner(),
+                    None,
+                    std::ptr::null_mut(),
+                );
+            }
+            if self.callback_bag.canceled_cb.is_some() {
+                recognizer_canceled_set_callback(self.handle.inner(), None, std::ptr::null_mut());
+            }
+            if self.callback_bag.recognizing_cb.is_some() {
+                recognizer_recognizing_set_callback(
+                    self.handle.inner(),
+                    None,
+                    std::ptr::null_mut(),
+                );
+            }
+            if self.callback_bag.recognized_cb.is_some() {
+                recognizer_recognized_set_callback(self.handle.inner(), None, std::ptr::null_mut());
+            }
+        }
+    }
+}
@@ -0,0 +1,20 @@
+use crate::intent::{ConversationalLanguageUnderstandingModel, PatternMatchingModel};
+
+/// Language understanding model applied to *IntentRecognizer*.
+#[derive(Debug, Clone)]
+pub enum LanguageUnderstandingModel {
+    PatternMatching(PatternMatchingModel),
+    ConversationalLanguageUnderstanding(ConversationalLanguageUnderstandingModel),
+}
+
+impl From<PatternMatchingModel> for LanguageUnderstandingModel {
+    fn from(model: PatternMatchingModel) -> Self {
+        LanguageUnderstandingModel::PatternMatching(model)
+    }
+}
+
+impl From<ConversationalLanguageUnderstandingModel> for LanguageUnderstandingModel {
+    fn from(model: ConversationalLanguageUnderstandingModel) -> Self {
+        LanguageUnderstandingModel::ConversationalLanguageUnderstanding(model)
+    }
+}
@@ -0,0 +1,165 @@
+use crate::error::{convert_err, Result};
+use crate::ffi::{
+    pattern_matching_model_add_entity, pattern_matching_model_add_intent, AZACHR, SPXLUMODELHANDLE,
+    SPX_NOERROR,
+};
+use std::ffi::CString;
+use std::os::raw::{c_char, c_void};
+
+/// Intent recognized by *PatternMatchingModel* when any of its phrases matches.
+/// Phrases can contain entity references in curly braces,
+/// e.g. "Take me to floor {floorName}".
+#[derive(Debug, Clone)]
+pub struct PatternMatchingIntent {
+    pub id: String,
+    pub phrases: Vec<String>,
+    /// Priority of the intent, intents with higher priority win on ambiguous matches.
+    pub priority: u32,
+}
+
+impl PatternMatchingIntent {
+    pub fn new<S: Into<String>>(id: S, phrases: Vec<String>) -> Self {
+        PatternMatchingIntent {
+            id: id.into(),
+            phrases,
+            priority: 0,
+        }
+    }
+}
+
+/// Entity referenced from *PatternMatchingIntent* phrases.
+#[derive(Debug, Clone)]
+pub enum PatternMatchingEntity {
+    /// Matches any text.
+    Any { id: String },
+    /// Matches only one of the listed phrases exactly.
+    StrictList { id: String, phrases: Vec<String> },
+    /// Prefers listed phrases but matches any text.
+    FuzzyList { id: String, phrases: Vec<String> },
+    /// Matches integer, e.g. "forty two" or "42".
+    PrebuiltInteger { id: String },
+}
+
+impl PatternMatchingEntity {
+    pub fn id(&self) -> &str {
+        match self {
+            PatternMatchingEntity::Any { id }
+            | PatternMatchingEntity::StrictList { id, .. }
+            | PatternMatchingEntity::FuzzyList { id, .. }
+            | PatternMatchingEntity::PrebuiltInteger { id } => id,
+        }
+    }
+
+    /// Returns native entity type and match mode.
+    fn type_and_mode(&self) -> (i32, i32) {
+        // EntityType: Any = 0, List = 1, PrebuiltInteger = 2
+        // EntityMatchMode: Basic = 0, Strict = 1, Fuzzy = 2
+        match self {
+            PatternMatchingEntity::Any { .. } => (0, 0),
+            PatternMatchingEntity::StrictList { .. } => (1, 1),
+            PatternMatchingEntity::FuzzyList { .. } => (1, 2),
+            PatternMatchingEntity::PrebuiltInteger { .. } => (2, 0),
+        }
+    }
+
+    fn phrases(&self) -> &[String] {
+        match self {
+            PatternMatchingEntity::StrictList { phrases, .. }
+            | PatternMatchingEntity::FuzzyList { phrases, .. } => phrases,
+            _ => &[],
+        }
+    }
+}
+
+/// PatternMatchingModel is language understanding model evaluated locally
+/// by *IntentRecognizer*, i.e. without any calls to language understanding service.
+/// Apply it to recognizer with *IntentRecognizer::apply_language_models*.
+#[derive(Debug, Clone)]
+pub struct PatternMatchingModel {
+    pub model_id: String,
+    pub intents: Vec<PatternMatchingIntent>,
+    pub entities: Vec<PatternMatchingEntity>,
+}
+
+impl PatternMatchingModel {
+    pub fn new<S: Into<String>>(model_id: S) -> Self {
+        PatternMatchingModel {
+            model_id: model_id.into(),
+            intents: vec![],
+            entities: vec![],
+        }
+    }
+
+    pub fn add_intent(&mut self, intent: PatternMatchingIntent) {
+        self.intents.push(intent);
+    }
+
+    pub fn add_entity(&mut self, entity: PatternMatchingEntity) {
+        self.entities.push(entity);
+    }
+
+    /// Adds intents and entities to native model handle created by recognizer.
+    pub(crate) fn populate(&self, handle: SPXLUMODELHANDLE) -> Result<()> {
+        for intent in &self.intents {
+            let c_id = CString::new(intent.id.as_str())?;
+            let c_phrases = to_c_strings(&intent.phrases)?;
+            unsafe {
+                let ret = pattern_matching_model_add_intent(
+                    handle,
+                    c_id.as_ptr(),
+                    intent.priority,
+                    c_phrases.len(),
+                    &c_phrases as *const _ as *mut c_void,
+                    Some(phrase_from_index),
+                );
+                convert_err(ret, "PatternMatchingModel.add_intent error")?;
+            }
+        }
+        for entity in &self.entities {
+            let c_id = CString::new(entity.id())?;
+            let c_phrases = to_c_strings(entity.phrases())?;
+            let (entity_type, mode) = entity.type_and_mode();
+            unsafe {
+                let ret = pattern_matching_model_add_entity(
+                    handle,
+                    c_id.as_ptr(),
+                    entity_type,
+                    mode,
+                    c_phrases.len(),
+                    &c_phrases as *const _ as *mut c_void,
+                    Some(phrase_from_index),
+                );
+                convert_err(ret, "PatternMatchingModel.add_entity error")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+fn to_c_strings(phrases: &[String]) -> Result<Vec<CString>> {
+    let mut c_phrases = Vec::with_capacity(phrases.len());
+    for phrase in phrases {
+        c_phrases.push(CString::new(phrase.as_str())?);
+    }
+    Ok(c_phrases)
+}
+
+/// Callback used by native layer to read phrases one by one.
+/// Context is pointer to Vec<CString> living for the duration of add_intent/add_entity call.
+unsafe extern "C" fn phrase_from_index(
+    context: *mut c_void,
+    index: usize,
+    str_: *mut *const c_char,
+    size: *mut usize,
+) -> AZACHR {
+    let phrases = &*(context as *const Vec<CString>);
+    match phrases.get(index) {
+        Some(phrase) => {
+            *str_ = phrase.as_ptr();
+            *size = phrase.as_bytes().len();
+            SPX_NOERROR as AZACHR
+        }
+        // SPXERR_INVALID_ARG
+        None => 0x005,
+    }
+}
@@ -7,10 +7,14 @@
 //! For more information about Micorost Speech Service see [here](https://docs.microsoft.com/en-us/azure/cognitive-services/speech-service/speech-sdk?tabs=windows%2Cubuntu%2Cios-xcode%2Cmac-xcode%2Candroid-studio).
 pub mod audio;
 pub mod common;
+pub mod diagnostics;
 pub mod dialog;
 pub mod error;
 pub mod ffi;
+pub mod intent;
+pub mod speaker;
 pub mod speech;
+pub mod transcription;
 pub mod translation;
 
 #[cfg(test)]
@@ -0,0 +1,22 @@
+//! Package speaker provides functionality for speaker recognition, i.e. management of voice profiles
+//! (creation, enrollment, reset and deletion) and speaker identification and verification.
+mod speaker_identification_model;
+mod speaker_recognition_result;
+mod speaker_recognizer;
+mod speaker_verification_model;
+mod voice_profile;
+mod voice_profile_client;
+mod voice_profile_enrollment_result;
+mod voice_profile_result;
+mod voice_profile_type;
+
+// re-export structs directly under speaker module
+pub use self::speaker_identification_model::SpeakerIdentificationModel;
+pub use self::speaker_recognition_result::SpeakerRecognitionResult;
+pub use self::speaker_recognizer::SpeakerRecognizer;
+pub use self::speaker_verification_model::SpeakerVerificationModel;
+pub use self::voice_profile::VoiceProfile;
+pub use self::voice_profile_client::VoiceProfileClient;
+pub use self::voice_profile_enrollment_result::VoiceProfileEnrollmentResult;
+pub use self::voice_profile_result::VoiceProfileResult;
+pub use self::voice_profile_type::VoiceProfileType;
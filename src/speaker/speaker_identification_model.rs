@@ -0,0 +1,46 @@
+use crate::error::{convert_err, Result};
+use crate::ffi::{
+    speaker_identification_model_add_profile, speaker_identification_model_create,
+    speaker_identification_model_release_handle, SharedHandle, SmartHandle, SPXSIMODELHANDLE,
+};
+use crate::speaker::VoiceProfile;
+use std::mem::MaybeUninit;
+
+/// SpeakerIdentificationModel is a collection of voice profiles
+/// against which speaker identification is performed.
+#[derive(Debug)]
+pub struct SpeakerIdentificationModel {
+    pub handle: SharedHandle<SPXSIMODELHANDLE>,
+}
+
+impl SpeakerIdentificationModel {
+    /// Creates speaker identification model from voice profiles.
+    /// Profiles must be of type *VoiceProfileType::TextIndependentIdentification*.
+    pub fn from_profiles(profiles: &[VoiceProfile]) -> Result<SpeakerIdentificationModel> {
+        unsafe {
+            let mut handle: MaybeUninit<SPXSIMODELHANDLE> = MaybeUninit::uninit();
+            let ret = speaker_identification_model_create(handle.as_mut_ptr());
+            convert_err(ret, "SpeakerIdentificationModel::from_profiles error")?;
+            let model = SpeakerIdentificationModel {
+                // SAFETY: profiles are added only while model is being created,
+                // afterwards native calls only read it
+                handle: SharedHandle::new(SmartHandle::create(
+                    "SpeakerIdentificationModel",
+                    handle.assume_init(),
+                    speaker_identification_model_release_handle,
+                )),
+            };
+            for profile in profiles {
+                let ret = speaker_identification_model_add_profile(
+                    model.handle.inner(),
+                    profile.handle.inner(),
+                );
+                convert_err(
+                    ret,
+                    "SpeakerIdentificationModel::from_profiles(add_profile) error",
+                )?;
+            }
+            Ok(model)
+        }
+    }
+}
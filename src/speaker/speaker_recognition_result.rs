@@ -0,0 +1,88 @@
+use crate::common::{PropertyCollection, PropertyId, ResultReason};
+use crate::error::{convert_err, Result};
+use crate::ffi::{
+    recognizer_result_handle_release, result_get_property_bag, result_get_reason,
+    result_get_result_id, SmartHandle, SPXPROPERTYBAGHANDLE, SPXRESULTHANDLE,
+};
+use std::ffi::CStr;
+use std::fmt;
+use std::mem::MaybeUninit;
+
+/// SpeakerRecognitionResult represents result of speaker identification or verification.
+pub struct SpeakerRecognitionResult {
+    pub handle: SmartHandle<SPXRESULTHANDLE>,
+    pub result_id: String,
+    pub reason: ResultReason,
+    /// Id of identified (or verified) voice profile.
+    pub profile_id: String,
+    /// Confidence score of identification (or verification), from 0.0 to 1.0.
+    pub score: f64,
+    pub error_details: String,
+    pub properties: PropertyCollection,
+}
+
+impl fmt::Debug for SpeakerRecognitionResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SpeakerRecognitionResult")
+            .field("result_id", &self.result_id)
+            .field("reason", &self.reason)
+            .field("profile_id", &self.profile_id)
+            .field("score", &self.score)
+            .field("error_details", &self.error_details)
+            .finish()
+    }
+}
+
+impl SpeakerRecognitionResult {
+    /// # Safety
+    /// `handle` must be a valid handle to a live speaker recognition result.
+    pub unsafe fn from_handle(handle: SPXRESULTHANDLE) -> Result<SpeakerRecognitionResult> {
+        unsafe {
+            let mut c_buf = [0; 1024];
+            let mut ret = result_get_result_id(handle, c_buf.as_mut_ptr(), c_buf.len() as u32);
+            convert_err(
+                ret,
+                "SpeakerRecognitionResult::from_handle(result_get_result_id) error",
+            )?;
+            let result_id = CStr::from_ptr(c_buf.as_ptr()).to_str()?.to_owned();
+
+            let mut reason = 0;
+            ret = result_get_reason(handle, &mut reason);
+            convert_err(
+                ret,
+                "SpeakerRecognitionResult::from_handle(result_get_reason) error",
+            )?;
+
+            let mut prop_bag_handle: MaybeUninit<SPXPROPERTYBAGHANDLE> = MaybeUninit::uninit();
+            ret = result_get_property_bag(handle, prop_bag_handle.as_mut_ptr());
+            convert_err(
+                ret,
+                "SpeakerRecognitionResult::from_handle(result_get_property_bag) error",
+            )?;
+            let properties = PropertyCollection::from_handle(prop_bag_handle.assume_init());
+
+            let error_details =
+                properties.get_property(PropertyId::CancellationDetailsReasonDetailedText, "")?;
+            let profile_id =
+                properties.get_property_by_string("speakerrecognition.profileid", "")?;
+            let score = properties
+                .get_property_by_string("speakerrecognition.score", "0.0")?
+                .parse()
+                .unwrap_or_default();
+
+            Ok(SpeakerRecognitionResult {
+                handle: SmartHandle::create(
+                    "SpeakerRecognitionResult",
+                    handle,
+                    recognizer_result_handle_release,
+                ),
+                result_id,
+                reason: reason.into(),
+                profile_id,
+                score,
+                error_details,
+                properties,
+            })
+        }
+    }
+}
@@ -0,0 +1,95 @@
+use crate::audio::AudioConfig;
+use crate::common::PropertyCollection;
+use crate::error::{convert_err, Result};
+use crate::ffi::{
+    recognizer_create_speaker_recognizer_from_config, run_blocking,
+    speaker_recognizer_get_property_bag, speaker_recognizer_identify,
+    speaker_recognizer_release_handle, speaker_recognizer_verify, SharedHandle, SmartHandle,
+    SPXPROPERTYBAGHANDLE, SPXRESULTHANDLE, SPXSPEAKERIDHANDLE,
+};
+use crate::speaker::{
+    SpeakerIdentificationModel, SpeakerRecognitionResult, SpeakerVerificationModel,
+};
+use crate::speech::SpeechConfig;
+use std::mem::MaybeUninit;
+
+/// SpeakerRecognizer struct holds functionality for speaker identification and verification.
+#[derive(Debug)]
+pub struct SpeakerRecognizer {
+    pub handle: SharedHandle<SPXSPEAKERIDHANDLE>,
+    pub properties: PropertyCollection,
+}
+
+impl SpeakerRecognizer {
+    pub fn from_config(
+        speech_config: SpeechConfig,
+        audio_config: AudioConfig,
+    ) -> Result<SpeakerRecognizer> {
+        unsafe {
+            let mut handle: MaybeUninit<SPXSPEAKERIDHANDLE> = MaybeUninit::uninit();
+            let mut ret = recognizer_create_speaker_recognizer_from_config(
+                handle.as_mut_ptr(),
+                speech_config.handle.inner(),
+                audio_config.handle.inner(),
+            );
+            convert_err(ret, "SpeakerRecognizer::from_config error")?;
+            // SAFETY: every native speaker recognizer call is independent service request
+            // which does not mutate the recognizer itself
+            let handle = SharedHandle::new(SmartHandle::create(
+                "SpeakerRecognizer",
+                handle.assume_init(),
+                speaker_recognizer_release_handle,
+            ));
+
+            let mut prop_bag_handle: MaybeUninit<SPXPROPERTYBAGHANDLE> = MaybeUninit::uninit();
+            ret = speaker_recognizer_get_property_bag(handle.inner(), prop_bag_handle.as_mut_ptr());
+            convert_err(
+                ret,
+                "SpeakerRecognizer::from_config(get_property_bag) error",
+            )?;
+            let properties = PropertyCollection::from_handle(prop_bag_handle.assume_init());
+
+            Ok(SpeakerRecognizer { handle, properties })
+        }
+    }
+
+    /// Identifies speaker among voice profiles of speaker identification model.
+    pub async fn identify_once_async(
+        &self,
+        model: &SpeakerIdentificationModel,
+    ) -> Result<SpeakerRecognitionResult> {
+        let handle = self.handle.clone();
+        let model_handle = model.handle.clone();
+        run_blocking(move || unsafe {
+            let mut result_handle: MaybeUninit<SPXRESULTHANDLE> = MaybeUninit::uninit();
+            let ret = speaker_recognizer_identify(
+                handle.inner(),
+                model_handle.inner(),
+                result_handle.as_mut_ptr(),
+            );
+            convert_err(ret, "SpeakerRecognizer.identify_once_async error")?;
+            SpeakerRecognitionResult::from_handle(result_handle.assume_init())
+        })
+        .await
+    }
+
+    /// Verifies speaker against voice profile of speaker verification model.
+    pub async fn verify_once_async(
+        &self,
+        model: &SpeakerVerificationModel,
+    ) -> Result<SpeakerRecognitionResult> {
+        let handle = self.handle.clone();
+        let model_handle = model.handle.clone();
+        run_blocking(move || unsafe {
+            let mut result_handle: MaybeUninit<SPXRESULTHANDLE> = MaybeUninit::uninit();
+            let ret = speaker_recognizer_verify(
+                handle.inner(),
+                model_handle.inner(),
+                result_handle.as_mut_ptr(),
+            );
+            convert_err(ret, "SpeakerRecognizer.verify_once_async error")?;
+            SpeakerRecognitionResult::from_handle(result_handle.assume_init())
+        })
+        .await
+    }
+}
@@ -0,0 +1,35 @@
+use crate::error::{convert_err, Result};
+use crate::ffi::{
+    speaker_verification_model_create, speaker_verification_model_release_handle, SharedHandle,
+    SmartHandle, SPXSVMODELHANDLE,
+};
+use crate::speaker::VoiceProfile;
+use std::mem::MaybeUninit;
+
+/// SpeakerVerificationModel holds voice profile against which speaker verification is performed.
+#[derive(Debug)]
+pub struct SpeakerVerificationModel {
+    pub handle: SharedHandle<SPXSVMODELHANDLE>,
+}
+
+impl SpeakerVerificationModel {
+    /// Creates speaker verification model from voice profile.
+    /// Profile must be of type *VoiceProfileType::TextDependentVerification*
+    /// or *VoiceProfileType::TextIndependentVerification*.
+    pub fn from_profile(profile: &VoiceProfile) -> Result<SpeakerVerificationModel> {
+        unsafe {
+            let mut handle: MaybeUninit<SPXSVMODELHANDLE> = MaybeUninit::uninit();
+            let ret =
+                speaker_verification_model_create(handle.as_mut_ptr(), profile.handle.inner());
+            convert_err(ret, "SpeakerVerificationModel::from_profile error")?;
+            Ok(SpeakerVerificationModel {
+                // SAFETY: model is immutable once created, native calls only read it
+                handle: SharedHandle::new(SmartHandle::create(
+                    "SpeakerVerificationModel",
+                    handle.assume_init(),
+                    speaker_verification_model_release_handle,
+                )),
+            })
+        }
+    }
+}
@@ -0,0 +1,77 @@
+use crate::common::PropertyCollection;
+use crate::error::{convert_err, Result};
+use crate::ffi::{
+    create_voice_profile_from_id_and_type, voice_profile_get_id, voice_profile_get_property_bag,
+    voice_profile_get_type, voice_profile_release_handle, SharedHandle, SmartHandle,
+    SPXPROPERTYBAGHANDLE, SPXVOICEPROFILEHANDLE,
+};
+use crate::speaker::VoiceProfileType;
+use std::ffi::{CStr, CString};
+use std::mem::MaybeUninit;
+
+/// VoiceProfile represents a speaker's uniqueness in their voice
+/// and is used for speaker identification and verification.
+#[derive(Debug)]
+pub struct VoiceProfile {
+    pub handle: SharedHandle<SPXVOICEPROFILEHANDLE>,
+    pub properties: PropertyCollection,
+}
+
+impl VoiceProfile {
+    /// # Safety
+    /// `handle` must be a valid handle to a live voice profile.
+    pub unsafe fn from_handle(handle: SPXVOICEPROFILEHANDLE) -> Result<VoiceProfile> {
+        unsafe {
+            let mut prop_bag_handle: MaybeUninit<SPXPROPERTYBAGHANDLE> = MaybeUninit::uninit();
+            let ret = voice_profile_get_property_bag(handle, prop_bag_handle.as_mut_ptr());
+            convert_err(ret, "VoiceProfile::from_handle error")?;
+            let properties = PropertyCollection::from_handle(prop_bag_handle.assume_init());
+
+            Ok(VoiceProfile {
+                // SAFETY: voice profile is immutable once created, native calls only read it
+                handle: SharedHandle::new(SmartHandle::create(
+                    "VoiceProfile",
+                    handle,
+                    voice_profile_release_handle,
+                )),
+                properties,
+            })
+        }
+    }
+
+    /// Creates voice profile from id of already existing profile and its type.
+    pub fn from_id_and_type(id: &str, profile_type: VoiceProfileType) -> Result<VoiceProfile> {
+        unsafe {
+            let c_id = CString::new(id)?;
+            let mut handle: MaybeUninit<SPXVOICEPROFILEHANDLE> = MaybeUninit::uninit();
+            let ret = create_voice_profile_from_id_and_type(
+                handle.as_mut_ptr(),
+                c_id.as_ptr(),
+                profile_type as i32,
+            );
+            convert_err(ret, "VoiceProfile::from_id_and_type error")?;
+            VoiceProfile::from_handle(handle.assume_init())
+        }
+    }
+
+    /// Returns id of the voice profile.
+    pub fn id(&self) -> Result<String> {
+        unsafe {
+            let mut c_buf = [0; 1024];
+            let mut len = c_buf.len() as u32;
+            let ret = voice_profile_get_id(self.handle.inner(), c_buf.as_mut_ptr(), &mut len);
+            convert_err(ret, "VoiceProfile.id error")?;
+            Ok(CStr::from_ptr(c_buf.as_ptr()).to_str()?.to_owned())
+        }
+    }
+
+    /// Returns type of the voice profile.
+    pub fn profile_type(&self) -> Result<VoiceProfileType> {
+        unsafe {
+            let mut profile_type = 0;
+            let ret = voice_profile_get_type(self.handle.inner(), &mut profile_type);
+            convert_err(ret, "VoiceProfile.profile_type error")?;
+            Ok(profile_type.into())
+        }
+    }
+}
@@ -0,0 +1,133 @@
+use crate::audio::AudioConfig;
+use crate::common::PropertyCollection;
+use crate::error::{convert_err, Result};
+use crate::ffi::{
+    create_voice_profile, create_voice_profile_client_from_config, delete_voice_profile,
+    enroll_voice_profile, reset_voice_profile, run_blocking, voice_profile_client_get_property_bag,
+    voice_profile_client_release_handle, SharedHandle, SmartHandle, SPXPROPERTYBAGHANDLE,
+    SPXRESULTHANDLE, SPXVOICEPROFILECLIENTHANDLE, SPXVOICEPROFILEHANDLE,
+};
+use crate::speaker::{
+    VoiceProfile, VoiceProfileEnrollmentResult, VoiceProfileResult, VoiceProfileType,
+};
+use crate::speech::SpeechConfig;
+use std::ffi::CString;
+use std::mem::MaybeUninit;
+
+/// VoiceProfileClient manages voice profiles used for speaker recognition.
+#[derive(Debug)]
+pub struct VoiceProfileClient {
+    pub handle: SharedHandle<SPXVOICEPROFILECLIENTHANDLE>,
+    pub properties: PropertyCollection,
+}
+
+impl VoiceProfileClient {
+    pub fn from_config(speech_config: SpeechConfig) -> Result<VoiceProfileClient> {
+        unsafe {
+            let mut handle: MaybeUninit<SPXVOICEPROFILECLIENTHANDLE> = MaybeUninit::uninit();
+            let mut ret = create_voice_profile_client_from_config(
+                handle.as_mut_ptr(),
+                speech_config.handle.inner(),
+            );
+            convert_err(ret, "VoiceProfileClient::from_config error")?;
+            // SAFETY: every native voice profile client call is independent service request
+            // which does not mutate the client itself
+            let handle = SharedHandle::new(SmartHandle::create(
+                "VoiceProfileClient",
+                handle.assume_init(),
+                voice_profile_client_release_handle,
+            ));
+
+            let mut prop_bag_handle: MaybeUninit<SPXPROPERTYBAGHANDLE> = MaybeUninit::uninit();
+            ret =
+                voice_profile_client_get_property_bag(handle.inner(), prop_bag_handle.as_mut_ptr());
+            convert_err(
+                ret,
+                "VoiceProfileClient::from_config(get_property_bag) error",
+            )?;
+            let properties = PropertyCollection::from_handle(prop_bag_handle.assume_init());
+
+            Ok(VoiceProfileClient { handle, properties })
+        }
+    }
+
+    /// Creates new voice profile of given type for given locale (e.g. en-us).
+    pub async fn create_profile_async(
+        &self,
+        profile_type: VoiceProfileType,
+        locale: &str,
+    ) -> Result<VoiceProfile> {
+        let handle = self.handle.clone();
+        let c_locale = CString::new(locale)?;
+        run_blocking(move || unsafe {
+            let mut profile_handle: MaybeUninit<SPXVOICEPROFILEHANDLE> = MaybeUninit::uninit();
+            let ret = create_voice_profile(
+                handle.inner(),
+                profile_type as i32,
+                c_locale.as_ptr(),
+                profile_handle.as_mut_ptr(),
+            );
+            convert_err(ret, "VoiceProfileClient.create_profile_async error")?;
+            VoiceProfile::from_handle(profile_handle.assume_init())
+        })
+        .await
+    }
+
+    /// Enrolls voice profile with audio from given audio config.
+    /// Enrollment might need to be repeated until result reason is
+    /// *ResultReason::EnrolledVoiceProfile*.
+    pub async fn enroll_profile_async(
+        &self,
+        profile: &VoiceProfile,
+        audio_config: AudioConfig,
+    ) -> Result<VoiceProfileEnrollmentResult> {
+        let handle = self.handle.clone();
+        let profile_handle = profile.handle.clone();
+        run_blocking(move || unsafe {
+            let mut result_handle: MaybeUninit<SPXRESULTHANDLE> = MaybeUninit::uninit();
+            let ret = enroll_voice_profile(
+                handle.inner(),
+                profile_handle.inner(),
+                audio_config.handle.inner(),
+                result_handle.as_mut_ptr(),
+            );
+            convert_err(ret, "VoiceProfileClient.enroll_profile_async error")?;
+            VoiceProfileEnrollmentResult::from_handle(result_handle.assume_init())
+        })
+        .await
+    }
+
+    /// Deletes voice profile.
+    pub async fn delete_profile_async(&self, profile: &VoiceProfile) -> Result<VoiceProfileResult> {
+        let handle = self.handle.clone();
+        let profile_handle = profile.handle.clone();
+        run_blocking(move || unsafe {
+            let mut result_handle: MaybeUninit<SPXRESULTHANDLE> = MaybeUninit::uninit();
+            let ret = delete_voice_profile(
+                handle.inner(),
+                profile_handle.inner(),
+                result_handle.as_mut_ptr(),
+            );
+            convert_err(ret, "VoiceProfileClient.delete_profile_async error")?;
+            VoiceProfileResult::from_handle(result_handle.assume_init())
+        })
+        .await
+    }
+
+    /// Resets voice profile, i.e. removes all its enrollments.
+    pub async fn reset_profile_async(&self, profile: &VoiceProfile) -> Result<VoiceProfileResult> {
+        let handle = self.handle.clone();
+        let profile_handle = profile.handle.clone();
+        run_blocking(move || unsafe {
+            let mut result_handle: MaybeUninit<SPXRESULTHANDLE> = MaybeUninit::uninit();
+            let ret = reset_voice_profile(
+                handle.inner(),
+                profile_handle.inner(),
+                result_handle.as_mut_ptr(),
+            );
+            convert_err(ret, "VoiceProfileClient.reset_profile_async error")?;
+            VoiceProfileResult::from_handle(result_handle.assume_init())
+        })
+        .await
+    }
+}
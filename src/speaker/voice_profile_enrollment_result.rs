@@ -0,0 +1,137 @@
+use crate::common::{PropertyCollection, PropertyId, ResultReason, Ticks};
+use crate::error::{convert_err, Result};
+use crate::ffi::{
+    recognizer_result_handle_release, result_get_property_bag, result_get_reason,
+    result_get_result_id, SmartHandle, SPXPROPERTYBAGHANDLE, SPXRESULTHANDLE,
+};
+use std::ffi::CStr;
+use std::fmt;
+use std::mem::MaybeUninit;
+use std::time::Duration;
+
+/// VoiceProfileEnrollmentResult represents result of voice profile enrollment.
+pub struct VoiceProfileEnrollmentResult {
+    pub handle: SmartHandle<SPXRESULTHANDLE>,
+    pub result_id: String,
+    pub reason: ResultReason,
+    pub profile_id: String,
+    /// Number of enrollment audios accepted for this profile.
+    pub enrollments_count: u32,
+    /// Total length of enrollment audios accepted for this profile.
+    pub enrollments_length: Duration,
+    /// Total speech length in enrollment audios accepted for this profile.
+    pub enrollments_speech_length: Duration,
+    /// Number of enrollment audios still needed to complete profile enrollment.
+    pub remaining_enrollments_count: u32,
+    /// Speech length still needed to complete profile enrollment.
+    pub remaining_enrollments_speech_length: Duration,
+    /// Length of the audio submitted in this enrollment.
+    pub audio_length: Duration,
+    /// Speech length of the audio submitted in this enrollment.
+    pub audio_speech_length: Duration,
+    pub created_time: String,
+    pub last_update_time: String,
+    pub error_details: String,
+    pub properties: PropertyCollection,
+}
+
+impl fmt::Debug for VoiceProfileEnrollmentResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("VoiceProfileEnrollmentResult")
+            .field("result_id", &self.result_id)
+            .field("reason", &self.reason)
+            .field("profile_id", &self.profile_id)
+            .field("enrollments_count", &self.enrollments_count)
+            .field("enrollments_length", &self.enrollments_length)
+            .field("enrollments_speech_length", &self.enrollments_speech_length)
+            .field(
+                "remaining_enrollments_count",
+                &self.remaining_enrollments_count,
+            )
+            .field(
+                "remaining_enrollments_speech_length",
+                &self.remaining_enrollments_speech_length,
+            )
+            .field("audio_length", &self.audio_length)
+            .field("audio_speech_length", &self.audio_speech_length)
+            .field("created_time", &self.created_time)
+            .field("last_update_time", &self.last_update_time)
+            .field("error_details", &self.error_details)
+            .finish()
+    }
+}
+
+impl VoiceProfileEnrollmentResult {
+    /// # Safety
+    /// `handle` must be a valid handle to a live voice profile enrollment result.
+    pub unsafe fn from_handle(handle: SPXRESULTHANDLE) -> Result<VoiceProfileEnrollmentResult> {
+        unsafe {
+            let mut c_buf = [0; 1024];
+            let mut ret = result_get_result_id(handle, c_buf.as_mut_ptr(), c_buf.len() as u32);
+            convert_err(
+                ret,
+                "VoiceProfileEnrollmentResult::from_handle(result_get_result_id) error",
+            )?;
+            let result_id = CStr::from_ptr(c_buf.as_ptr()).to_str()?.to_owned();
+
+            let mut reason = 0;
+            ret = result_get_reason(handle, &mut reason);
+            convert_err(
+                ret,
+                "VoiceProfileEnrollmentResult::from_handle(result_get_reason) error",
+            )?;
+
+            let mut prop_bag_handle: MaybeUninit<SPXPROPERTYBAGHANDLE> = MaybeUninit::uninit();
+            ret = result_get_property_bag(handle, prop_bag_handle.as_mut_ptr());
+            convert_err(
+                ret,
+                "VoiceProfileEnrollmentResult::from_handle(result_get_property_bag) error",
+            )?;
+            let properties = PropertyCollection::from_handle(prop_bag_handle.assume_init());
+
+            let error_details =
+                properties.get_property(PropertyId::CancellationDetailsReasonDetailedText, "")?;
+
+            let number_property = |name: &str| -> Result<u64> {
+                Ok(properties
+                    .get_property_by_string(name, "0")?
+                    .parse()
+                    .unwrap_or_default())
+            };
+            // lengths are reported in ticks despite InSec suffix of property names
+            let length_property = |name: &str| -> Result<Duration> {
+                Ok(Duration::from_ticks(number_property(name)?))
+            };
+
+            Ok(VoiceProfileEnrollmentResult {
+                result_id,
+                reason: reason.into(),
+                profile_id: properties.get_property_by_string("enrollment.profileId", "")?,
+                enrollments_count: number_property("enrollment.enrollmentsCount")? as u32,
+                enrollments_length: length_property("enrollment.enrollmentsLengthInSec")?,
+                enrollments_speech_length: length_property(
+                    "enrollment.enrollmentsSpeechLengthInSec",
+                )?,
+                remaining_enrollments_count: number_property(
+                    "enrollment.remainingEnrollmentsCount",
+                )? as u32,
+                remaining_enrollments_speech_length: length_property(
+                    "enrollment.remainingEnrollmentsSpeechLengthInSec",
+                )?,
+                audio_length: length_property("enrollment.audioLengthInSec")?,
+                audio_speech_length: length_property("enrollment.audioSpeechLengthInSec")?,
+                created_time: properties
+                    .get_property_by_string("enrollment.createdDateTime", "")?,
+                last_update_time: properties
+                    .get_property_by_string("enrollment.lastUpdatedDateTime", "")?,
+                error_details,
+                handle: SmartHandle::create(
+                    "VoiceProfileEnrollmentResult",
+                    handle,
+                    recognizer_result_handle_release,
+                ),
+                properties,
+            })
+        }
+    }
+}
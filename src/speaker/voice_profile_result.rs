@@ -0,0 +1,74 @@
+use crate::common::{PropertyCollection, PropertyId, ResultReason};
+use crate::error::{convert_err, Result};
+use crate::ffi::{
+    recognizer_result_handle_release, result_get_property_bag, result_get_reason,
+    result_get_result_id, SmartHandle, SPXPROPERTYBAGHANDLE, SPXRESULTHANDLE,
+};
+use std::ffi::CStr;
+use std::fmt;
+use std::mem::MaybeUninit;
+
+/// VoiceProfileResult represents result of voice profile operation (e.g. reset or deletion).
+pub struct VoiceProfileResult {
+    pub handle: SmartHandle<SPXRESULTHANDLE>,
+    pub result_id: String,
+    pub reason: ResultReason,
+    pub error_details: String,
+    pub properties: PropertyCollection,
+}
+
+impl fmt::Debug for VoiceProfileResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("VoiceProfileResult")
+            .field("result_id", &self.result_id)
+            .field("reason", &self.reason)
+            .field("error_details", &self.error_details)
+            .finish()
+    }
+}
+
+impl VoiceProfileResult {
+    /// # Safety
+    /// `handle` must be a valid handle to a live voice profile result.
+    pub unsafe fn from_handle(handle: SPXRESULTHANDLE) -> Result<VoiceProfileResult> {
+        unsafe {
+            let mut c_buf = [0; 1024];
+            let mut ret = result_get_result_id(handle, c_buf.as_mut_ptr(), c_buf.len() as u32);
+            convert_err(
+                ret,
+                "VoiceProfileResult::from_handle(result_get_result_id) error",
+            )?;
+            let result_id = CStr::from_ptr(c_buf.as_ptr()).to_str()?.to_owned();
+
+            let mut reason = 0;
+            ret = result_get_reason(handle, &mut reason);
+            convert_err(
+                ret,
+                "VoiceProfileResult::from_handle(result_get_reason) error",
+            )?;
+
+            let mut prop_bag_handle: MaybeUninit<SPXPROPERTYBAGHANDLE> = MaybeUninit::uninit();
+            ret = result_get_property_bag(handle, prop_bag_handle.as_mut_ptr());
+            convert_err(
+                ret,
+                "VoiceProfileResult::from_handle(result_get_property_bag) error",
+            )?;
+            let properties = PropertyCollection::from_handle(prop_bag_handle.assume_init());
+
+            let error_details =
+                properties.get_property(PropertyId::CancellationDetailsReasonDetailedText, "")?;
+
+            Ok(VoiceProfileResult {
+                handle: SmartHandle::create(
+                    "VoiceProfileResult",
+                    handle,
+                    recognizer_result_handle_release,
+                ),
+                result_id,
+                reason: reason.into(),
+                error_details,
+                properties,
+            })
+        }
+    }
+}
@@ -0,0 +1,34 @@
+/// VoiceProfileType defines the type of a voice profile.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VoiceProfileType {
+    /// TextIndependentIdentification indicates text independent speaker identification.
+    TextIndependentIdentification = 1,
+
+    /// TextDependentVerification indicates text dependent speaker verification.
+    TextDependentVerification = 2,
+
+    /// TextIndependentVerification indicates text independent speaker verification.
+    TextIndependentVerification = 3,
+}
+
+impl VoiceProfileType {
+    pub fn from_u32(profile_type: u32) -> Self {
+        match profile_type {
+            1 => VoiceProfileType::TextIndependentIdentification,
+            2 => VoiceProfileType::TextDependentVerification,
+            _ => VoiceProfileType::TextIndependentVerification,
+        }
+    }
+}
+
+impl From<u32> for VoiceProfileType {
+    fn from(value: u32) -> Self {
+        VoiceProfileType::from_u32(value)
+    }
+}
+
+impl From<i32> for VoiceProfileType {
+    fn from(value: i32) -> Self {
+        VoiceProfileType::from_u32(value as u32)
+    }
+}
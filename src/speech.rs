@@ -2,18 +2,38 @@
 mod audio_data_stream;
 mod auto_detect_source_language_config;
 mod cancellation_details;
+mod class_language_model;
+mod connection;
+mod connection_event;
+mod connection_message;
+mod connection_message_received_event;
+mod detailed_recognition_result;
 mod embedded_speech_config;
+mod grammar;
+mod grammar_list;
 mod grammar_phrase;
+mod hybrid_speech_config;
+mod keyword_recognition_canceled_event;
+mod keyword_recognition_event;
 mod keyword_recognition_model;
+mod keyword_recognition_result;
+mod keyword_recognizer;
 mod phrase_list_grammar;
+mod pronunciation_assessment_config;
+mod pronunciation_assessment_result;
 mod recognition_event;
+mod recognizer;
 mod session_event;
 mod source_language_config;
+mod source_language_recognition_event;
+mod source_language_recognition_result;
+mod source_language_recognizer;
 mod speech_config;
 mod speech_recognition_canceled_event;
 mod speech_recognition_event;
 mod speech_recognition_result;
 mod speech_recognizer;
+mod speech_recognizer_event;
 mod speech_synthesis_bookmark_event;
 mod speech_synthesis_event;
 mod speech_synthesis_request;
@@ -21,6 +41,7 @@ mod speech_synthesis_result;
 mod speech_synthesis_viseme_event;
 mod speech_synthesis_word_boundary_event;
 mod speech_synthesizer;
+mod speech_synthesizer_event;
 mod synthesis_voices_result;
 mod voice_info;
 
@@ -28,17 +49,49 @@ mod voice_info;
 pub use self::audio_data_stream::AudioDataStream;
 pub use self::auto_detect_source_language_config::AutoDetectSourceLanguageConfig;
 pub use self::cancellation_details::CancellationDetails;
-pub use self::embedded_speech_config::EmbeddedSpeechConfig;
+pub use self::class_language_model::ClassLanguageModel;
+pub use self::connection::Connection;
+pub use self::connection_event::ConnectionEvent;
+pub use self::connection_message::ConnectionMessage;
+pub use self::connection_message_received_event::ConnectionMessageReceivedEvent;
+pub use self::detailed_recognition_result::{
+    DetailedRecognitionResult, RecognitionAlternative, WordDetail,
+};
+pub use self::embedded_speech_config::{
+    EmbeddedSpeechConfig, SpeechRecognitionModel, SpeechTranslationModel,
+};
+pub use self::grammar::{Grammar, StoredGrammar};
+pub(crate) use self::grammar::SealedGrammar;
+pub use self::grammar_list::GrammarList;
+pub use self::hybrid_speech_config::HybridSpeechConfig;
+pub use self::keyword_recognition_canceled_event::KeywordRecognitionCanceledEvent;
+pub use self::keyword_recognition_event::KeywordRecognitionEvent;
 pub use self::keyword_recognition_model::KeywordRecognitionModel;
+pub use self::keyword_recognition_result::KeywordRecognitionResult;
+pub use self::keyword_recognizer::KeywordRecognizer;
 pub use self::phrase_list_grammar::PhraseListGrammar;
+pub use self::pronunciation_assessment_config::PronunciationAssessmentConfig;
+pub use self::pronunciation_assessment_result::{
+    BreakFeedback, ContentAssessmentResult, IntonationFeedback, NBestPhoneme,
+    PronunciationAssessmentPhonemeResult, PronunciationAssessmentResult,
+    PronunciationAssessmentSyllableResult, PronunciationAssessmentWordResult, ProsodyFeedback,
+};
 pub use self::recognition_event::RecognitionEvent;
+pub use self::recognizer::Recognizer;
+pub(crate) use self::recognizer::SealedRecognizer;
 pub use self::session_event::SessionEvent;
 pub use self::source_language_config::SourceLanguageConfig;
+pub use self::source_language_recognition_event::SourceLanguageRecognitionEvent;
+pub use self::source_language_recognition_result::{
+    SourceLanguageConfidence, SourceLanguageRecognitionResult,
+};
+pub use self::source_language_recognizer::SourceLanguageRecognizer;
 pub use self::speech_config::SpeechConfig;
 pub use self::speech_recognition_canceled_event::SpeechRecognitionCanceledEvent;
 pub use self::speech_recognition_event::SpeechRecognitionEvent;
 pub use self::speech_recognition_result::SpeechRecognitionResult;
 pub use self::speech_recognizer::SpeechRecognizer;
+pub use self::speech_recognizer_event::SpeechRecognizerEvent;
 pub use self::speech_synthesis_bookmark_event::SpeechSynthesisBookmarkEvent;
 pub use self::speech_synthesis_event::SpeechSynthesisEvent;
 pub use self::speech_synthesis_request::SpeechSynthesisRequest;
@@ -46,5 +99,6 @@ pub use self::speech_synthesis_result::SpeechSynthesisResult;
 pub use self::speech_synthesis_viseme_event::SpeechSynthesisVisemeEvent;
 pub use self::speech_synthesis_word_boundary_event::SpeechSynthesisWordBoundaryEvent;
 pub use self::speech_synthesizer::SpeechSynthesizer;
+pub use self::speech_synthesizer_event::SpeechSynthesizerEvent;
 pub use self::synthesis_voices_result::SynthesisVoicesResult;
 pub use self::voice_info::VoiceInfo;
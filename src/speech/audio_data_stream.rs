@@ -1,16 +1,25 @@
-use crate::common::{PropertyCollection, StreamStatus};
+use crate::audio::BlockingReader;
+use crate::common::{CancellationErrorCode, CancellationReason, PropertyCollection, StreamStatus};
 use crate::error::{convert_err, Error, ErrorRootCause, Result};
 use crate::ffi::{
     audio_data_stream_can_read_data, audio_data_stream_can_read_data_from_position,
-    audio_data_stream_create_from_file, audio_data_stream_create_from_result,
+    audio_data_stream_create_from_file, audio_data_stream_create_from_keyword_result,
+    audio_data_stream_create_from_result, audio_data_stream_detach_input,
+    audio_data_stream_get_available_size, audio_data_stream_get_canceled_error_code,
     audio_data_stream_get_position, audio_data_stream_get_property_bag,
-    audio_data_stream_get_status, audio_data_stream_read, audio_data_stream_read_from_position,
-    audio_data_stream_save_to_wave_file, audio_data_stream_set_position, audio_stream_release,
-    SmartHandle, SPXAUDIOSTREAMHANDLE, SPXPROPERTYBAGHANDLE,
+    audio_data_stream_get_reason_canceled, audio_data_stream_get_status, audio_data_stream_read,
+    audio_data_stream_read_from_position, audio_data_stream_release,
+    audio_data_stream_save_to_wave_file, audio_data_stream_set_position, SharedHandle, SmartHandle,
+    SPXAUDIOSTREAMHANDLE, SPXPROPERTYBAGHANDLE,
 };
-use crate::speech::SpeechSynthesisResult;
+use crate::speech::{KeywordRecognitionResult, SpeechSynthesisResult};
+use std::convert::TryFrom;
 use std::ffi::CString;
+use std::io::{self, SeekFrom};
 use std::mem::MaybeUninit;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use tokio::io::{AsyncRead, ReadBuf};
 
 /// AudioDataStream represents audio data retrieved either from file
 /// or result of speech synthesis. Represents convenient option for
@@ -18,8 +27,9 @@ use std::mem::MaybeUninit;
 /// Added in version 1.17.0
 #[derive(Debug)]
 pub struct AudioDataStream {
-    pub handle: SmartHandle<SPXAUDIOSTREAMHANDLE>,
+    handle: SharedHandle<SPXAUDIOSTREAMHANDLE>,
     pub properties: PropertyCollection,
+    reader: BlockingReader,
 }
 
 impl AudioDataStream {
@@ -33,8 +43,16 @@ impl AudioDataStream {
 
             let property_bag = PropertyCollection::from_handle(prop_bag_handle.assume_init());
 
+            // SAFETY: native audio data stream is synchronized, it can be filled
+            // by synthesizer thread while being read by application
+            let handle = SharedHandle::new(SmartHandle::create(
+                "AudioDataStream",
+                handle,
+                audio_data_stream_release,
+            ));
             Ok(AudioDataStream {
-                handle: SmartHandle::create("AudioDataStream", handle, audio_stream_release),
+                reader: BlockingReader::new(handle.clone(), AudioDataStream::read_chunk),
+                handle,
                 properties: property_bag,
             })
         }
@@ -64,6 +82,26 @@ impl AudioDataStream {
         }
     }
 
+    /// Creates audio data stream containing audio of keyword recognition result.
+    /// Stream starts with the recognized keyword and continues with audio
+    /// captured after the keyword for as long as the stream is being read.
+    pub fn from_keyword_result(keyword_result: &KeywordRecognitionResult) -> Result<Self> {
+        unsafe {
+            let mut handle: MaybeUninit<SPXAUDIOSTREAMHANDLE> = MaybeUninit::uninit();
+            let ret = audio_data_stream_create_from_keyword_result(
+                handle.as_mut_ptr(),
+                keyword_result.base.handle.inner(),
+            );
+            convert_err(ret, "AudioDataStream::from_keyword_result error")?;
+            AudioDataStream::from_handle(handle.assume_init())
+        }
+    }
+
+    /// Returns underlying native handle.
+    pub fn get_handle(&self) -> SPXAUDIOSTREAMHANDLE {
+        self.handle.inner()
+    }
+
     pub fn get_status(&self) -> Result<StreamStatus> {
         unsafe {
             let mut status = 0;
@@ -74,6 +112,46 @@ impl AudioDataStream {
         }
     }
 
+    /// Returns the reason why the stream was canceled.
+    /// Applicable only when stream status is StreamStatusCanceled.
+    pub fn get_cancellation_reason(&self) -> Result<CancellationReason> {
+        unsafe {
+            let mut reason = 0;
+            let ret = audio_data_stream_get_reason_canceled(self.handle.inner(), &mut reason);
+            convert_err(ret, "AudioDataStream.get_cancellation_reason error")?;
+
+            Ok(reason.into())
+        }
+    }
+
+    /// Returns the error code in case stream was canceled because of an error.
+    pub fn get_canceled_error_code(&self) -> Result<CancellationErrorCode> {
+        unsafe {
+            let mut error_code = 0;
+            let ret =
+                audio_data_stream_get_canceled_error_code(self.handle.inner(), &mut error_code);
+            convert_err(ret, "AudioDataStream.get_canceled_error_code error")?;
+
+            Ok(error_code.into())
+        }
+    }
+
+    /// Returns size of audio data (in bytes) which can be currently
+    /// read from the stream without blocking.
+    pub fn get_available_size(&self) -> u32 {
+        unsafe { audio_data_stream_get_available_size(self.handle.inner()) }
+    }
+
+    /// Detaches the stream from its input (e.g. keyword recognizer)
+    /// so that no more audio data is appended to the stream.
+    pub fn detach_input(&self) -> Result<()> {
+        unsafe {
+            let ret = audio_data_stream_detach_input(self.handle.inner());
+            convert_err(ret, "AudioDataStream.detach_input error")?;
+            Ok(())
+        }
+    }
+
     pub fn can_read_data(&self, requested_size: u32) -> bool {
         unsafe { audio_data_stream_can_read_data(self.handle.inner(), requested_size) }
     }
@@ -91,18 +169,18 @@ impl AudioDataStream {
     /// Read reads a chunk of the audio data stream and fill it to given buffer.
     /// It returns size of data filled to the buffer and any write error encountered.
     pub fn read(&self, buffer: &mut [u8]) -> Result<u32> {
+        AudioDataStream::read_from_handle(self.handle.inner(), buffer)
+    }
+
+    fn read_from_handle(handle: SPXAUDIOSTREAMHANDLE, buffer: &mut [u8]) -> Result<u32> {
+        if buffer.is_empty() {
+            return Err(empty_buffer_error("AudioDataStream.read error"));
+        }
         unsafe {
-            #[allow(clippy::len_zero)]
-            if buffer.len() == 0 {
-                let rootc = ErrorRootCause::ApiError(0x005);
-                return Err(Error::new(Error::api_error_desc(&rootc).unwrap(), rootc));
-            }
             let mut filled_size: u32 = 0;
-            // let c_buffer: *mut u8 = buffer as *const _ as *mut u8;
-            let c_buffer = buffer.as_mut_ptr();
             let ret = audio_data_stream_read(
-                self.handle.inner(),
-                c_buffer,
+                handle,
+                buffer.as_mut_ptr(),
                 buffer.len() as u32,
                 &mut filled_size,
             );
@@ -111,17 +189,25 @@ impl AudioDataStream {
         }
     }
 
+    /// Reads chunk of at most *size* bytes, used by *AsyncRead* implementation.
+    fn read_chunk(handle: SPXAUDIOSTREAMHANDLE, size: usize) -> Result<Vec<u8>> {
+        let mut data = vec![0u8; size];
+        let filled = AudioDataStream::read_from_handle(handle, &mut data)?;
+        if filled == 0 {
+            check_not_canceled(handle)?;
+        }
+        data.truncate(filled as usize);
+        Ok(data)
+    }
+
     /// ReadAt reads a chunk of the audio data stream and fill it to given buffer, at specified offset.
     /// It returns size of data filled to the buffer and any write error encountered.
     pub fn read_at(&self, buffer: &mut [u8], offset: u32) -> Result<u32> {
+        if buffer.is_empty() {
+            return Err(empty_buffer_error("AudioDataStream.read_at error"));
+        }
         unsafe {
-            #[allow(clippy::len_zero)]
-            if buffer.len() == 0 {
-                let rootc = ErrorRootCause::ApiError(0x005);
-                return Err(Error::new(Error::api_error_desc(&rootc).unwrap(), rootc));
-            }
             let mut filled_size: u32 = 0;
-            // let c_buffer: *mut u8 = buffer as *const _ as *mut u8;
             let c_buffer = buffer.as_mut_ptr();
             let ret = audio_data_stream_read_from_position(
                 self.handle.inner(),
@@ -161,3 +247,121 @@ impl AudioDataStream {
         }
     }
 }
+
+/// Error returned when read is called with empty buffer.
+fn empty_buffer_error(err_msg: &str) -> Error {
+    // SPXERR_INVALID_ARG
+    let root_cause = ErrorRootCause::ApiError(0x005);
+    let desc = Error::api_error_desc(&root_cause).unwrap_or_default();
+    Error::new(format!("{}: {}", err_msg, desc), root_cause)
+}
+
+/// Returns error describing cancellation when stream was canceled
+/// (e.g. synthesis failed) instead of finishing with all data.
+fn check_not_canceled(handle: SPXAUDIOSTREAMHANDLE) -> Result<()> {
+    unsafe {
+        let mut status = 0;
+        let ret = audio_data_stream_get_status(handle, &mut status);
+        convert_err(ret, "AudioDataStream.get_status error")?;
+        if let StreamStatus::StreamStatusCanceled = status.into() {
+            let mut error_code = 0;
+            let ret = audio_data_stream_get_canceled_error_code(handle, &mut error_code);
+            convert_err(ret, "AudioDataStream.get_canceled_error_code error")?;
+            return Err(Error::new(
+                format!(
+                    "AudioDataStream canceled: {:?}",
+                    CancellationErrorCode::from(error_code)
+                ),
+                // SPXERR_CANCELED
+                ErrorRootCause::ApiError(0x032),
+            ));
+        }
+        Ok(())
+    }
+}
+
+fn to_io_error(err: Error) -> io::Error {
+    io::Error::other(err)
+}
+
+/// Sync read and seek would race with native read executed by *AsyncRead*.
+fn check_no_async_read(reader: &BlockingReader) -> io::Result<()> {
+    if reader.is_reading() {
+        return Err(io::Error::other(
+            "AudioDataStream async read is still in progress",
+        ));
+    }
+    Ok(())
+}
+
+/// Reads audio data from current position of the stream.
+/// Blocks until data is available, returns 0 once whole
+/// synthesized audio has been read and error when synthesis was canceled.
+impl io::Read for AudioDataStream {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+        check_no_async_read(&self.reader)?;
+        // data already read by AsyncRead precede current position of native stream
+        let buffered = self.reader.take_buffered(buf);
+        if buffered > 0 {
+            return Ok(buffered);
+        }
+        let len = buf.len().min(u32::MAX as usize);
+        let filled = AudioDataStream::read(self, &mut buf[..len]).map_err(to_io_error)?;
+        if filled == 0 {
+            check_not_canceled(self.handle.inner()).map_err(to_io_error)?;
+        }
+        Ok(filled as usize)
+    }
+}
+
+/// Moves current position of the stream. Seeking relative to the end
+/// of the stream is not supported since total size of the audio
+/// is not known until synthesis is completed.
+/// Seeking is rejected while *AsyncRead* is waiting for data.
+impl io::Seek for AudioDataStream {
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        check_no_async_read(&self.reader)?;
+        let new_offset = match pos {
+            SeekFrom::Start(offset) => i64::try_from(offset).ok(),
+            SeekFrom::Current(delta) => {
+                let offset = self.get_offset().map_err(to_io_error)?;
+                // native position is already past data buffered by AsyncRead
+                let buffered = i64::try_from(self.reader.buffered_len()).ok();
+                buffered.and_then(|buffered| (i64::from(offset) - buffered).checked_add(delta))
+            }
+            SeekFrom::End(_) => {
+                return Err(io::Error::new(
+                    io::ErrorKind::Unsupported,
+                    "AudioDataStream does not support seeking from end",
+                ))
+            }
+        };
+        let new_offset = new_offset
+            .and_then(|offset| u32::try_from(offset).ok())
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "invalid AudioDataStream offset",
+                )
+            })?;
+        self.set_offset(new_offset).map_err(to_io_error)?;
+        self.reader.discard_buffered();
+        Ok(new_offset as u64)
+    }
+}
+
+/// Reads audio data without blocking the executor. Since native read blocks
+/// until data are available it is executed on tokio blocking thread pool.
+/// Returns 0 (EOF) once whole synthesized audio has been read.
+impl AsyncRead for AudioDataStream {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        self.reader.poll_read(cx, buf)
+    }
+}
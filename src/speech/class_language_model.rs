@@ -0,0 +1,58 @@
+use std::ffi::CString;
+use std::mem::MaybeUninit;
+
+use crate::error::{convert_err, Result};
+use crate::ffi::{
+    class_language_model_assign_class, class_language_model_from_storage_id,
+    grammar_handle_release, SmartHandle, SPXGRAMMARHANDLE,
+};
+use crate::speech::{Grammar, SealedGrammar};
+
+/// Represents class language model previously uploaded to the speech service.
+/// Classes referenced by the model are filled in with grammars via *assign_class*.
+#[derive(Debug)]
+pub struct ClassLanguageModel {
+    handle: SmartHandle<SPXGRAMMARHANDLE>,
+}
+
+impl SealedGrammar for ClassLanguageModel {}
+
+impl Grammar for ClassLanguageModel {
+    fn get_handle(&self) -> SPXGRAMMARHANDLE {
+        self.handle.inner()
+    }
+}
+
+impl ClassLanguageModel {
+    /// Creates class language model from the specified storage id.
+    pub fn from_storage_id(storage_id: &str) -> Result<ClassLanguageModel> {
+        unsafe {
+            let c_storage_id = CString::new(storage_id)?;
+            let mut handle: MaybeUninit<SPXGRAMMARHANDLE> = MaybeUninit::uninit();
+            let ret =
+                class_language_model_from_storage_id(handle.as_mut_ptr(), c_storage_id.as_ptr());
+            convert_err(ret, "ClassLanguageModel::from_storage_id error")?;
+            Ok(ClassLanguageModel {
+                handle: SmartHandle::create(
+                    "ClassLanguageModel",
+                    handle.assume_init(),
+                    grammar_handle_release,
+                ),
+            })
+        }
+    }
+
+    /// Assigns grammar to the class of the specified name.
+    pub fn assign_class(&self, class_name: &str, grammar: &dyn Grammar) -> Result<()> {
+        let c_class_name = CString::new(class_name)?;
+        let ret = unsafe {
+            class_language_model_assign_class(
+                self.handle.inner(),
+                c_class_name.as_ptr(),
+                grammar.get_handle(),
+            )
+        };
+        convert_err(ret, "ClassLanguageModel::assign_class error")?;
+        Ok(())
+    }
+}
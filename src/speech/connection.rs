@@ -0,0 +1,309 @@
+use crate::common::PropertyCollection;
+use crate::dialog::DialogServiceConnector;
+use crate::error::{convert_err, Result};
+use crate::ffi::{
+    connection_async_handle_release, connection_close, connection_connected_set_callback,
+    connection_disconnected_set_callback, connection_from_dialog_service_connector,
+    connection_from_recognizer, connection_from_speech_synthesizer, connection_get_property_bag,
+    connection_handle_release, connection_message_received_set_callback, connection_open,
+    connection_send_message_async, connection_send_message_wait_for,
+    connection_set_message_property, wait_for_async, SmartHandle, SPXASYNCHANDLE,
+    SPXCONNECTIONHANDLE, SPXEVENTHANDLE, SPXPROPERTYBAGHANDLE,
+};
+use crate::speech::{
+    ConnectionEvent, ConnectionMessageReceivedEvent, SpeechRecognizer, SpeechSynthesizer,
+};
+use crate::translation::TranslationRecognizer;
+use log::*;
+use std::boxed::Box;
+use std::ffi::CString;
+use std::fmt;
+use std::mem::MaybeUninit;
+use std::os::raw::c_void;
+
+/// A separate internal struct to hold all the callback closures for the connection events.
+/// Boxing it keeps the callbacks at a fixed memory address on the heap
+/// while Connection itself can be moved freely.
+struct CallbackBag {
+    connected_cb: Option<Box<dyn Fn(ConnectionEvent) + Send>>,
+    disconnected_cb: Option<Box<dyn Fn(ConnectionEvent) + Send>>,
+    message_received_cb: Option<Box<dyn Fn(ConnectionMessageReceivedEvent) + Send>>,
+}
+
+/// Connection is a proxy class for managing connection to the speech service
+/// of the specified recognizer, synthesizer or dialog service connector.
+/// By default recognizer autonomously manages connection to service when needed.
+/// Connection provides additional methods for users to explicitly open or close
+/// a connection and to subscribe to connection status changes.
+pub struct Connection {
+    pub handle: SmartHandle<SPXCONNECTIONHANDLE>,
+    pub properties: PropertyCollection,
+    callback_bag: Box<CallbackBag>,
+}
+
+impl fmt::Debug for Connection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Connection")
+            .field("handle", &self.handle)
+            .field("properties", &self.properties)
+            .finish()
+    }
+}
+
+impl Connection {
+    /// # Safety
+    /// `handle` must be a valid handle to a live connection.
+    unsafe fn from_handle(handle: SPXCONNECTIONHANDLE) -> Result<Connection> {
+        let handle = SmartHandle::create("Connection", handle, connection_handle_release);
+        unsafe {
+            let mut prop_bag_handle: MaybeUninit<SPXPROPERTYBAGHANDLE> = MaybeUninit::uninit();
+            let ret = connection_get_property_bag(handle.inner(), prop_bag_handle.as_mut_ptr());
+            convert_err(ret, "Connection::from_handle error")?;
+            let property_bag = PropertyCollection::from_handle(prop_bag_handle.assume_init());
+
+            Ok(Connection {
+                handle,
+                properties: property_bag,
+                callback_bag: Box::new(CallbackBag {
+                    connected_cb: None,
+                    disconnected_cb: None,
+                    message_received_cb: None,
+                }),
+            })
+        }
+    }
+
+    /// Gets the Connection instance from the specified speech recognizer.
+    pub fn from_recognizer(recognizer: &SpeechRecognizer) -> Result<Connection> {
+        unsafe {
+            let mut handle: MaybeUninit<SPXCONNECTIONHANDLE> = MaybeUninit::uninit();
+            let ret = connection_from_recognizer(recognizer.handle.inner(), handle.as_mut_ptr());
+            convert_err(ret, "Connection::from_recognizer error")?;
+            Connection::from_handle(handle.assume_init())
+        }
+    }
+
+    /// Gets the Connection instance from the specified translation recognizer.
+    pub fn from_translation_recognizer(recognizer: &TranslationRecognizer) -> Result<Connection> {
+        unsafe {
+            let mut handle: MaybeUninit<SPXCONNECTIONHANDLE> = MaybeUninit::uninit();
+            let ret = connection_from_recognizer(recognizer.handle.inner(), handle.as_mut_ptr());
+            convert_err(ret, "Connection::from_translation_recognizer error")?;
+            Connection::from_handle(handle.assume_init())
+        }
+    }
+
+    /// Gets the Connection instance from the specified speech synthesizer.
+    pub fn from_speech_synthesizer(synthesizer: &SpeechSynthesizer) -> Result<Connection> {
+        unsafe {
+            let mut handle: MaybeUninit<SPXCONNECTIONHANDLE> = MaybeUninit::uninit();
+            let ret =
+                connection_from_speech_synthesizer(synthesizer.handle.inner(), handle.as_mut_ptr());
+            convert_err(ret, "Connection::from_speech_synthesizer error")?;
+            Connection::from_handle(handle.assume_init())
+        }
+    }
+
+    /// Gets the Connection instance from the specified dialog service connector.
+    pub fn from_dialog_service_connector(connector: &DialogServiceConnector) -> Result<Connection> {
+        unsafe {
+            let mut handle: MaybeUninit<SPXCONNECTIONHANDLE> = MaybeUninit::uninit();
+            let ret = connection_from_dialog_service_connector(
+                connector.handle.inner(),
+                handle.as_mut_ptr(),
+            );
+            convert_err(ret, "Connection::from_dialog_service_connector error")?;
+            Connection::from_handle(handle.assume_init())
+        }
+    }
+
+    /// Starts to set up connection to the service.
+    /// Users can optionally call open() to manually set up a connection in advance
+    /// before starting recognition/synthesis, e.g. to pre-connect before the user speaks.
+    /// *for_continuous_recognition* indicates whether the connection is used
+    /// for continuous recognition or single-shot recognition.
+    pub fn open(&self, for_continuous_recognition: bool) -> Result<()> {
+        unsafe {
+            let ret = connection_open(self.handle.inner(), for_continuous_recognition);
+            convert_err(ret, "Connection.open error")
+        }
+    }
+
+    /// Closes the connection to the service.
+    /// If any recognition is ongoing, it will be terminated.
+    pub fn close(&self) -> Result<()> {
+        unsafe {
+            let ret = connection_close(self.handle.inner());
+            convert_err(ret, "Connection.close error")
+        }
+    }
+
+    /// Appends a parameter in a message to service,
+    /// e.g. set_message_property("speech.context", "phraseDetection", "{...}").
+    /// This method doesn't work for the connection of SpeechSynthesizer.
+    pub fn set_message_property(&self, path: &str, name: &str, value: &str) -> Result<()> {
+        unsafe {
+            let c_path = CString::new(path)?;
+            let c_name = CString::new(name)?;
+            let c_value = CString::new(value)?;
+            let ret = connection_set_message_property(
+                self.handle.inner(),
+                c_path.as_ptr(),
+                c_name.as_ptr(),
+                c_value.as_ptr(),
+            );
+            convert_err(ret, "Connection.set_message_property error")
+        }
+    }
+
+    /// Sends a message to the speech service.
+    /// *path* is the message path and *payload* is the message body (usually JSON).
+    pub async fn send_message_async(&self, path: &str, payload: &str) -> Result<()> {
+        let handle_async = unsafe {
+            let c_path = CString::new(path)?;
+            let c_payload = CString::new(payload)?;
+            let mut handle_async: MaybeUninit<SPXASYNCHANDLE> = MaybeUninit::uninit();
+            let ret = connection_send_message_async(
+                self.handle.inner(),
+                c_path.as_ptr(),
+                c_payload.as_ptr(),
+                handle_async.as_mut_ptr(),
+            );
+            convert_err(ret, "Connection.send_message_async error")?;
+            SmartHandle::create(
+                "handle_async_send_message",
+                handle_async.assume_init(),
+                connection_async_handle_release,
+            )
+        };
+        wait_for_async(handle_async, |handle_async| unsafe {
+            let ret = connection_send_message_wait_for(handle_async, u32::MAX);
+            convert_err(ret, "Connection.send_message_wait_for error")
+        })
+        .await
+    }
+
+    pub fn set_connected_cb<F>(&mut self, f: F) -> Result<()>
+    where
+        F: Fn(ConnectionEvent) + 'static + Send,
+    {
+        self.callback_bag.connected_cb = Some(Box::new(f));
+        unsafe {
+            let ret = connection_connected_set_callback(
+                self.handle.inner(),
+                Some(Self::cb_connected),
+                &*self.callback_bag as *const _ as *mut c_void,
+            );
+            convert_err(ret, "Connection.set_connected_cb error")
+        }
+    }
+
+    pub fn set_disconnected_cb<F>(&mut self, f: F) -> Result<()>
+    where
+        F: Fn(ConnectionEvent) + 'static + Send,
+    {
+        self.callback_bag.disconnected_cb = Some(Box::new(f));
+        unsafe {
+            let ret = connection_disconnected_set_callback(
+                self.handle.inner(),
+                Some(Self::cb_disconnected),
+                &*self.callback_bag as *const _ as *mut c_void,
+            );
+            convert_err(ret, "Connection.set_disconnected_cb error")
+        }
+    }
+
+    /// Sets callback receiving all messages coming from the speech service,
+    /// e.g. *speech.hypothesis*, *speech.phrase* or *turn.end*.
+    pub fn set_message_received_cb<F>(&mut self, f: F) -> Result<()>
+    where
+        F: Fn(ConnectionMessageReceivedEvent) + 'static + Send,
+    {
+        self.callback_bag.message_received_cb = Some(Box::new(f));
+        unsafe {
+            let ret = connection_message_received_set_callback(
+                self.handle.inner(),
+                Some(Self::cb_message_received),
+                &*self.callback_bag as *const _ as *mut c_void,
+            );
+            convert_err(ret, "Connection.set_message_received_cb error")
+        }
+    }
+
+    #[allow(non_snake_case)]
+    unsafe extern "C" fn cb_connected(hevent: SPXEVENTHANDLE, pvContext: *mut c_void) {
+        trace!("Connection::cb_connected called");
+        let callback_bag = &mut *(pvContext as *mut CallbackBag);
+        if let Some(cb) = &callback_bag.connected_cb {
+            match ConnectionEvent::from_handle(hevent) {
+                Ok(event) => {
+                    trace!("calling cb with event {:?}", event);
+                    cb(event);
+                }
+                Err(err) => {
+                    error!("Connection::cb_connected error {:?}", err);
+                }
+            }
+        }
+    }
+
+    #[allow(non_snake_case)]
+    unsafe extern "C" fn cb_disconnected(hevent: SPXEVENTHANDLE, pvContext: *mut c_void) {
+        trace!("Connection::cb_disconnected called");
+        let callback_bag = &mut *(pvContext as *mut CallbackBag);
+        if let Some(cb) = &callback_bag.disconnected_cb {
+            match ConnectionEvent::from_handle(hevent) {
+                Ok(event) => {
+                    trace!("calling cb with event {:?}", event);
+                    cb(event);
+                }
+                Err(err) => {
+                    error!("Connection::cb_disconnected error {:?}", err);
+                }
+            }
+        }
+    }
+
+    #[allow(non_snake_case)]
+    unsafe extern "C" fn cb_message_received(hevent: SPXEVENTHANDLE, pvContext: *mut c_void) {
+        trace!("Connection::cb_message_received called");
+        let callback_bag = &mut *(pvContext as *mut CallbackBag);
+        if let Some(cb) = &callback_bag.message_received_cb {
+            match ConnectionMessageReceivedEvent::from_handle(hevent) {
+                Ok(event) => {
+                    trace!("calling cb with event {:?}", event);
+                    cb(event);
+                }
+                Err(err) => {
+                    error!("Connection::cb_message_received error {:?}", err);
+                }
+            }
+        }
+    }
+}
+
+/// Native connection object is owned by recognizer/synthesizer and can outlive
+/// this proxy, so registered callbacks are disconnected before callback bag is freed.
+impl Drop for Connection {
+    fn drop(&mut self) {
+        unsafe {
+            if self.callback_bag.connected_cb.is_some() {
+                connection_connected_set_callback(self.handle.inner(), None, std::ptr::null_mut());
+            }
+            if self.callback_bag.disconnected_cb.is_some() {
+                connection_disconnected_set_callback(
+                    self.handle.inner(),
+                    None,
+                    std::ptr::null_mut(),
+                );
+            }
+            if self.callback_bag.message_received_cb.is_some() {
+                connection_message_received_set_callback(
+                    self.handle.inner(),
+                    None,
+                    std::ptr::null_mut(),
+                );
+            }
+        }
+    }
+}
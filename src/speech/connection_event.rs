@@ -0,0 +1,45 @@
+use crate::error::{convert_err, Result};
+use crate::ffi::{
+    recognizer_event_handle_release, recognizer_session_event_get_session_id, SmartHandle,
+    SPXEVENTHANDLE,
+};
+use std::ffi::CStr;
+use std::fmt;
+
+/// Event passed into *Connection* callbacks *set_connected_cb* and *set_disconnected_cb*.
+pub struct ConnectionEvent {
+    pub session_id: String,
+    pub handle: SmartHandle<SPXEVENTHANDLE>,
+}
+
+impl fmt::Debug for ConnectionEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ConnectionEvent")
+            .field("session_id", &self.session_id)
+            .finish()
+    }
+}
+
+impl ConnectionEvent {
+    /// # Safety
+    /// `handle` must be a valid reference to a live connection event.
+    pub unsafe fn from_handle(handle: SPXEVENTHANDLE) -> Result<ConnectionEvent> {
+        let handle = SmartHandle::create("ConnectionEvent", handle, recognizer_event_handle_release);
+        let mut c_buf = [0; 37];
+
+        unsafe {
+            let ret = recognizer_session_event_get_session_id(
+                handle.inner(),
+                c_buf.as_mut_ptr(),
+                c_buf.len() as u32,
+            );
+            convert_err(ret, "ConnectionEvent::from_handle error")?;
+            let session_id = CStr::from_ptr(c_buf.as_ptr()).to_str()?.to_owned();
+
+            Ok(ConnectionEvent {
+                session_id,
+                handle,
+            })
+        }
+    }
+}
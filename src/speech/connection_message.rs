@@ -0,0 +1,84 @@
+use crate::common::PropertyCollection;
+use crate::error::{convert_err, Result};
+use crate::ffi::{
+    connection_message_get_data, connection_message_get_data_size,
+    connection_message_get_property_bag, connection_message_handle_release, SmartHandle,
+    SPXCONNECTIONMESSAGEHANDLE, SPXPROPERTYBAGHANDLE,
+};
+use std::fmt;
+use std::mem::MaybeUninit;
+
+/// ConnectionMessage represents implementation specific messages
+/// sent to and received from the speech service.
+pub struct ConnectionMessage {
+    /// Message path, e.g. *speech.hypothesis* or *turn.start*.
+    pub path: String,
+    /// true if message is text message, false for binary messages.
+    pub is_text_message: bool,
+    /// Body of text message, empty for binary messages.
+    pub text_message: String,
+    /// Raw body of the message.
+    pub binary_message: Vec<u8>,
+    pub properties: PropertyCollection,
+    pub handle: SmartHandle<SPXCONNECTIONMESSAGEHANDLE>,
+}
+
+impl fmt::Debug for ConnectionMessage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ConnectionMessage")
+            .field("path", &self.path)
+            .field("is_text_message", &self.is_text_message)
+            .field("text_message", &self.text_message)
+            .field("binary_message.len()", &self.binary_message.len())
+            .finish()
+    }
+}
+
+impl ConnectionMessage {
+    /// # Safety
+    /// `handle` must be a valid handle to a live connection message.
+    pub unsafe fn from_handle(handle: SPXCONNECTIONMESSAGEHANDLE) -> Result<ConnectionMessage> {
+        // wrap handle first so that it is released on early error return
+        let handle = SmartHandle::create(
+            "ConnectionMessage",
+            handle,
+            connection_message_handle_release,
+        );
+        unsafe {
+            let mut prop_bag_handle: MaybeUninit<SPXPROPERTYBAGHANDLE> = MaybeUninit::uninit();
+            let ret =
+                connection_message_get_property_bag(handle.inner(), prop_bag_handle.as_mut_ptr());
+            convert_err(ret, "ConnectionMessage::from_handle(prop_bag) error")?;
+            let properties = PropertyCollection::from_handle(prop_bag_handle.assume_init());
+
+            let data_size = connection_message_get_data_size(handle.inner());
+            let mut binary_message = vec![0u8; data_size as usize];
+            if data_size > 0 {
+                let ret = connection_message_get_data(
+                    handle.inner(),
+                    binary_message.as_mut_ptr(),
+                    data_size,
+                );
+                convert_err(ret, "ConnectionMessage::from_handle(data) error")?;
+            }
+
+            let path = properties.get_property_by_string("connection.message.path", "")?;
+            let is_text_message =
+                properties.get_property_by_string("connection.message.type", "")? == "text";
+            let text_message = if is_text_message {
+                properties.get_property_by_string("connection.message.text.message", "")?
+            } else {
+                "".to_owned()
+            };
+
+            Ok(ConnectionMessage {
+                path,
+                is_text_message,
+                text_message,
+                binary_message,
+                properties,
+                handle,
+            })
+        }
+    }
+}
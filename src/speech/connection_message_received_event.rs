@@ -0,0 +1,49 @@
+use crate::error::{convert_err, Result};
+use crate::ffi::{
+    connection_message_received_event_get_message,
+    connection_message_received_event_handle_release, SmartHandle, SPXCONNECTIONMESSAGEHANDLE,
+    SPXEVENTHANDLE,
+};
+use crate::speech::ConnectionMessage;
+use std::fmt;
+use std::mem::MaybeUninit;
+
+/// Event passed into *Connection* callback *set_message_received_cb*.
+pub struct ConnectionMessageReceivedEvent {
+    pub message: ConnectionMessage,
+    pub handle: SmartHandle<SPXEVENTHANDLE>,
+}
+
+impl fmt::Debug for ConnectionMessageReceivedEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ConnectionMessageReceivedEvent")
+            .field("message", &self.message)
+            .finish()
+    }
+}
+
+impl ConnectionMessageReceivedEvent {
+    /// # Safety
+    /// `handle` must be a valid reference to a live message received event.
+    pub unsafe fn from_handle(handle: SPXEVENTHANDLE) -> Result<ConnectionMessageReceivedEvent> {
+        let handle = SmartHandle::create(
+            "ConnectionMessageReceivedEvent",
+            handle,
+            connection_message_received_event_handle_release,
+        );
+        unsafe {
+            let mut message_handle: MaybeUninit<SPXCONNECTIONMESSAGEHANDLE> = MaybeUninit::uninit();
+            let ret = connection_message_received_event_get_message(
+                handle.inner(),
+                message_handle.as_mut_ptr(),
+            );
+            convert_err(ret, "ConnectionMessageReceivedEvent::from_handle error")?;
+            let message = ConnectionMessage::from_handle(message_handle.assume_init())?;
+
+            Ok(ConnectionMessageReceivedEvent {
+                message,
+                handle,
+            })
+        }
+    }
+}
@@ -0,0 +1,164 @@
+use crate::common::{PropertyCollection, PropertyId, Ticks};
+use crate::error::Result;
+use crate::speech::SourceLanguageConfidence;
+use serde::Deserialize;
+use std::time::Duration;
+
+/// DetailedRecognitionResult contains detailed recognition output parsed from
+/// JSON result of the recognizer. Alternatives are available only when
+/// output format is set to *OutputFormat::Detailed*, word level details
+/// only when word level timestamps are requested
+/// (see *SpeechConfig::request_word_level_timestamps*).
+#[derive(Debug, Clone)]
+pub struct DetailedRecognitionResult {
+    /// Recognition status as reported by service, e.g. *Success* or *NoMatch*.
+    pub recognition_status: String,
+    pub display_text: String,
+    /// Offset of the recognized speech.
+    pub offset: Duration,
+    /// Duration of the recognized speech.
+    pub duration: Duration,
+    /// Detected language, available only when source language auto detection is enabled.
+    pub language: Option<String>,
+    pub language_confidence: SourceLanguageConfidence,
+    /// Recognition alternatives ordered from the most to the least likely one.
+    pub n_best: Vec<RecognitionAlternative>,
+}
+
+/// Single recognition alternative (NBest entry) of detailed result.
+#[derive(Debug, Clone)]
+pub struct RecognitionAlternative {
+    pub confidence: f64,
+    /// Lexical form, i.e. the actual words recognized.
+    pub lexical: String,
+    /// Inverse text normalized form, e.g. numbers converted to digits.
+    pub itn: String,
+    /// ITN form with profanity masking applied.
+    pub masked_itn: String,
+    /// Display form with punctuation and capitalization.
+    pub display: String,
+    pub words: Vec<WordDetail>,
+}
+
+/// Timing and confidence of single recognized word.
+#[derive(Debug, Clone)]
+pub struct WordDetail {
+    pub word: String,
+    /// Offset of the word.
+    pub offset: Duration,
+    /// Duration of the word.
+    pub duration: Duration,
+    /// Available only when word level confidence is reported by service.
+    pub confidence: Option<f64>,
+}
+
+impl DetailedRecognitionResult {
+    /// Parses detailed result from properties of recognition result.
+    /// Returns None if result does not contain JSON result.
+    pub(crate) fn from_properties(properties: &PropertyCollection) -> Result<Option<Self>> {
+        let json = properties.get_property(PropertyId::SpeechServiceResponseJsonResult, "")?;
+        if json.is_empty() {
+            return Ok(None);
+        }
+        let mut result = DetailedRecognitionResult::from_json(&json)?;
+        if result.language.is_none() {
+            let language = properties.get_property(
+                PropertyId::SpeechServiceConnectionAutoDetectSourceLanguageResult,
+                "",
+            )?;
+            if !language.is_empty() {
+                result.language = Some(language);
+            }
+        }
+        Ok(Some(result))
+    }
+
+    fn from_json(json: &str) -> Result<Self> {
+        let raw: RawResult = serde_json::from_str(json)?;
+        // translation results carry recognition details within SpeechPhrase
+        let raw = match raw.speech_phrase {
+            Some(speech_phrase) => *speech_phrase,
+            None => raw,
+        };
+        let (language, language_confidence) = match raw.primary_language {
+            Some(primary) => (
+                Some(primary.language),
+                SourceLanguageConfidence::parse(&primary.confidence),
+            ),
+            None => (None, SourceLanguageConfidence::Unknown),
+        };
+        Ok(DetailedRecognitionResult {
+            recognition_status: raw.recognition_status,
+            display_text: raw.display_text,
+            offset: Duration::from_ticks(raw.offset),
+            duration: Duration::from_ticks(raw.duration),
+            language,
+            language_confidence,
+            n_best: raw
+                .n_best
+                .into_iter()
+                .map(|alternative| RecognitionAlternative {
+                    confidence: alternative.confidence,
+                    lexical: alternative.lexical,
+                    itn: alternative.itn,
+                    masked_itn: alternative.masked_itn,
+                    display: alternative.display,
+                    words: alternative
+                        .words
+                        .into_iter()
+                        .map(|word| WordDetail {
+                            word: word.word,
+                            offset: Duration::from_ticks(word.offset),
+                            duration: Duration::from_ticks(word.duration),
+                            confidence: word.confidence,
+                        })
+                        .collect(),
+                })
+                .collect(),
+        })
+    }
+}
+
+// raw structures mirroring detailed JSON result returned by speech service
+
+#[derive(Deserialize, Default)]
+#[serde(rename_all = "PascalCase", default)]
+struct RawResult {
+    recognition_status: String,
+    display_text: String,
+    offset: u64,
+    duration: u64,
+    primary_language: Option<RawPrimaryLanguage>,
+    #[serde(rename = "NBest")]
+    n_best: Vec<RawNBest>,
+    speech_phrase: Option<Box<RawResult>>,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(rename_all = "PascalCase", default)]
+struct RawPrimaryLanguage {
+    language: String,
+    confidence: String,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(rename_all = "PascalCase", default)]
+struct RawNBest {
+    confidence: f64,
+    lexical: String,
+    #[serde(rename = "ITN")]
+    itn: String,
+    #[serde(rename = "MaskedITN")]
+    masked_itn: String,
+    display: String,
+    words: Vec<RawWord>,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(rename_all = "PascalCase", default)]
+struct RawWord {
+    word: String,
+    offset: u64,
+    duration: u64,
+    confidence: Option<f64>,
+}
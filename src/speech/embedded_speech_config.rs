@@ -3,10 +3,15 @@ use crate::error::{convert_err, Result};
 use crate::ffi::{
     embedded_speech_config_add_path, embedded_speech_config_create,
     embedded_speech_config_get_num_speech_reco_models,
-    embedded_speech_config_get_speech_reco_model, property_bag_free_string,
+    embedded_speech_config_get_num_speech_translation_models,
+    embedded_speech_config_get_speech_reco_model,
+    embedded_speech_config_get_speech_translation_model, property_bag_free_string,
     speech_recognition_model_get_locales, speech_recognition_model_get_name,
     speech_recognition_model_get_path, speech_recognition_model_get_version,
-    speech_recognition_model_handle_release, SmartHandle, SPXSPEECHCONFIGHANDLE,
+    speech_recognition_model_handle_release, speech_translation_model_get_name,
+    speech_translation_model_get_path, speech_translation_model_get_source_languages,
+    speech_translation_model_get_target_languages, speech_translation_model_get_version,
+    speech_translation_model_handle_release, SmartHandle, SPXSPEECHCONFIGHANDLE,
     SPXSPEECHRECOMODELHANDLE,
 };
 use crate::speech::SpeechConfig;
@@ -115,6 +120,101 @@ impl EmbeddedSpeechConfig {
             .get_property(PropertyId::SpeechServiceConnectionRecoModelName)
     }
 
+    /// Gets a list of available speech translation models.
+    pub fn get_speech_translation_models(&self) -> Result<Vec<SpeechTranslationModel>> {
+        unsafe {
+            let mut count = 0u32;
+
+            convert_err(
+                embedded_speech_config_get_num_speech_translation_models(
+                    self.config.handle.inner(),
+                    &mut count,
+                ),
+                "EmbeddedSpeechConfig::get_num_translation_models error",
+            )?;
+
+            let mut models = Vec::with_capacity(usize::try_from(count).unwrap_or(0));
+            for i in 0..count {
+                let mut handle: MaybeUninit<SPXSPEECHRECOMODELHANDLE> = MaybeUninit::uninit();
+                convert_err(
+                    embedded_speech_config_get_speech_translation_model(
+                        self.config.handle.inner(),
+                        i,
+                        handle.as_mut_ptr(),
+                    ),
+                    "EmbeddedSpeechConfig::get_translation_model error",
+                )?;
+                models.push(SpeechTranslationModel::from_handle(handle.assume_init())?);
+            }
+            Ok(models)
+        }
+    }
+
+    /// Sets the model for speech translation.
+    /// Use *TranslationRecognizer::from_embedded_config* to translate offline.
+    /// * `model`:  The translation model
+    /// * `model_key`: The model decryption key.
+    pub fn set_speech_translation_model<S: Into<String>>(
+        &mut self,
+        model: &SpeechTranslationModel,
+        model_key: S,
+    ) -> Result<()> {
+        self.config
+            .set_property(PropertyId::SpeechTranslationModelName, model.name.clone())?;
+        self.config
+            .set_property(PropertyId::SpeechTranslationModelKey, model_key.into())
+    }
+
+    /// Gets the model name for speech translation.
+    pub fn get_speech_translation_model_name(&self) -> Result<String> {
+        self.config
+            .get_property(PropertyId::SpeechTranslationModelName)
+    }
+
+    /// Adds target language for embedded speech translation.
+    /// Language must be one of *target_languages* of the selected translation model.
+    pub fn add_target_language<S: Into<String>>(&mut self, target_lang: S) -> Result<()> {
+        let mut languages = self.get_target_languages()?;
+        languages.push(target_lang.into());
+        self.config.set_property(
+            PropertyId::SpeechServiceConnectionTranslationToLanguages,
+            languages.join(","),
+        )
+    }
+
+    /// Gets target languages for embedded speech translation.
+    pub fn get_target_languages(&self) -> Result<Vec<String>> {
+        let languages = self
+            .config
+            .get_property(PropertyId::SpeechServiceConnectionTranslationToLanguages)?;
+        Ok(languages
+            .split(',')
+            .filter(|lang| !lang.is_empty())
+            .map(ToString::to_string)
+            .collect())
+    }
+
+    /// Sets the model for keyword recognition, so that keyword verification
+    /// runs on the device without the speech service.
+    /// * `model_name`: The keyword recognition model name.
+    /// * `model_key`: The model decryption key.
+    pub fn set_keyword_recognition_model<S, K>(&mut self, model_name: S, model_key: K) -> Result<()>
+    where
+        S: Into<String>,
+        K: Into<String>,
+    {
+        self.config
+            .set_property(PropertyId::KeywordRecognitionModelName, model_name.into())?;
+        self.config
+            .set_property(PropertyId::KeywordRecognitionModelKey, model_key.into())
+    }
+
+    /// Gets the model name for keyword recognition.
+    pub fn get_keyword_recognition_model_name(&self) -> Result<String> {
+        self.config
+            .get_property(PropertyId::KeywordRecognitionModelName)
+    }
+
     /// Sets the speech recognition output format.
     /// * `format`: Speech recognition output format (simple or detailed).
     pub fn set_speech_recognition_output_format(&mut self, format: OutputFormat) -> Result<()> {
@@ -215,6 +315,64 @@ impl SpeechRecognitionModel {
     }
 }
 
+#[derive(Debug)]
+pub struct SpeechTranslationModel {
+    pub name: String,
+    pub version: String,
+    pub path: String,
+    pub source_languages: Vec<String>,
+    pub target_languages: Vec<String>,
+}
+
+impl SpeechTranslationModel {
+    /// # Safety
+    /// `handle` must be a valid handle to a live speech translation model.
+    unsafe fn from_handle(handle: SPXSPEECHRECOMODELHANDLE) -> Result<SpeechTranslationModel> {
+        let handle = SmartHandle::create(
+            "SpeechTranslationModel",
+            handle,
+            speech_translation_model_handle_release,
+        );
+
+        unsafe {
+            let name = to_owned(
+                speech_translation_model_get_name(handle.inner()),
+                "SpeechTranslationModel::from_handle(name) error",
+            )?;
+            let version = to_owned(
+                speech_translation_model_get_version(handle.inner()),
+                "SpeechTranslationModel::from_handle(version) error",
+            )?;
+            let path = to_owned(
+                speech_translation_model_get_path(handle.inner()),
+                "SpeechTranslationModel::from_handle(path) error",
+            )?;
+            let source_languages = to_owned(
+                speech_translation_model_get_source_languages(handle.inner()),
+                "SpeechTranslationModel::from_handle(source_languages) error",
+            )?
+            .split('|')
+            .map(ToString::to_string)
+            .collect();
+            let target_languages = to_owned(
+                speech_translation_model_get_target_languages(handle.inner()),
+                "SpeechTranslationModel::from_handle(target_languages) error",
+            )?
+            .split('|')
+            .map(ToString::to_string)
+            .collect();
+
+            Ok(SpeechTranslationModel {
+                name,
+                version,
+                path,
+                source_languages,
+                target_languages,
+            })
+        }
+    }
+}
+
 fn to_owned(c_text: *const ::std::os::raw::c_char, err_msg: &str) -> Result<String> {
     unsafe {
         let text = CStr::from_ptr(c_text).to_str()?.to_owned();
@@ -0,0 +1,53 @@
+use std::ffi::CString;
+use std::mem::MaybeUninit;
+
+use crate::error::{convert_err, Result};
+use crate::ffi::{
+    grammar_create_from_storage_id, grammar_handle_release, SmartHandle, SPXGRAMMARHANDLE,
+};
+
+/// Abstraction over grammars (phrase list grammars, stored grammars
+/// and class language models). Enables transparent handling of all grammars
+/// by *GrammarList* and *ClassLanguageModel*.
+/// Trait is sealed, it is implemented only by grammars of this crate.
+pub trait Grammar: SealedGrammar {
+    fn get_handle(&self) -> SPXGRAMMARHANDLE;
+}
+
+/// Supertrait of *Grammar* which cannot be named outside of this crate,
+/// as handle returned by *Grammar::get_handle* is passed unchecked into native calls.
+pub trait SealedGrammar {}
+
+/// Represents grammar previously uploaded to the speech service
+/// and referenced by its storage id.
+#[derive(Debug)]
+pub struct StoredGrammar {
+    handle: SmartHandle<SPXGRAMMARHANDLE>,
+}
+
+impl SealedGrammar for StoredGrammar {}
+
+impl Grammar for StoredGrammar {
+    fn get_handle(&self) -> SPXGRAMMARHANDLE {
+        self.handle.inner()
+    }
+}
+
+impl StoredGrammar {
+    /// Creates grammar from the specified storage id.
+    pub fn from_storage_id(storage_id: &str) -> Result<StoredGrammar> {
+        unsafe {
+            let c_storage_id = CString::new(storage_id)?;
+            let mut handle: MaybeUninit<SPXGRAMMARHANDLE> = MaybeUninit::uninit();
+            let ret = grammar_create_from_storage_id(handle.as_mut_ptr(), c_storage_id.as_ptr());
+            convert_err(ret, "StoredGrammar::from_storage_id error")?;
+            Ok(StoredGrammar {
+                handle: SmartHandle::create(
+                    "StoredGrammar",
+                    handle.assume_init(),
+                    grammar_handle_release,
+                ),
+            })
+        }
+    }
+}
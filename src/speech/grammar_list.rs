@@ -0,0 +1,51 @@
+use std::mem::MaybeUninit;
+
+use crate::common::RecognitionFactorScope;
+use crate::error::{convert_err, Result};
+use crate::ffi::{
+    grammar_handle_release, grammar_list_add_grammar, grammar_list_from_recognizer,
+    grammar_list_set_recognition_factor, SmartHandle, SPXGRAMMARHANDLE,
+};
+use crate::speech::{Grammar, Recognizer};
+
+/// Represents list of grammars (stored grammars, class language models)
+/// used by recognizer for dynamic grammar scenarios.
+#[derive(Debug)]
+pub struct GrammarList {
+    handle: SmartHandle<SPXGRAMMARHANDLE>,
+}
+
+impl GrammarList {
+    /// Creates a grammar list for the specified recognizer.
+    pub fn from_recognizer(recognizer: &dyn Recognizer) -> Result<GrammarList> {
+        unsafe {
+            let mut handle: MaybeUninit<SPXGRAMMARHANDLE> = MaybeUninit::uninit();
+            let ret = grammar_list_from_recognizer(handle.as_mut_ptr(), recognizer.get_handle());
+            convert_err(ret, "GrammarList::from_recognizer error")?;
+            Ok(GrammarList {
+                handle: SmartHandle::create(
+                    "GrammarList",
+                    handle.assume_init(),
+                    grammar_handle_release,
+                ),
+            })
+        }
+    }
+
+    /// Adds grammar to the list.
+    pub fn add(&self, grammar: &dyn Grammar) -> Result<()> {
+        let ret = unsafe { grammar_list_add_grammar(self.handle.inner(), grammar.get_handle()) };
+        convert_err(ret, "GrammarList::add error")?;
+        Ok(())
+    }
+
+    /// Sets the recognition factor applied to all grammars in the list.
+    /// Higher factor boosts grammars against the base language model.
+    pub fn set_recognition_factor(&self, factor: f64, scope: RecognitionFactorScope) -> Result<()> {
+        let ret = unsafe {
+            grammar_list_set_recognition_factor(self.handle.inner(), factor, scope.into())
+        };
+        convert_err(ret, "GrammarList::set_recognition_factor error")?;
+        Ok(())
+    }
+}
@@ -0,0 +1,66 @@
+use crate::common::OutputFormat;
+use crate::error::{convert_err, Result};
+use crate::ffi::{hybrid_speech_config_create, SPXSPEECHCONFIGHANDLE};
+use crate::speech::{EmbeddedSpeechConfig, SpeechConfig};
+use std::mem::MaybeUninit;
+
+/// Class that defines hybrid (cloud and embedded) configurations for speech recognition and speech synthesis.
+/// Recognizer or synthesizer created from hybrid config uses the speech service when it is reachable
+/// and automatically falls back to embedded models when connectivity is lost.
+/// Use *backend* method of the result to find out which backend produced it.
+#[derive(Debug)]
+pub struct HybridSpeechConfig {
+    pub config: SpeechConfig,
+}
+
+impl From<HybridSpeechConfig> for SpeechConfig {
+    fn from(hsc: HybridSpeechConfig) -> SpeechConfig {
+        hsc.config
+    }
+}
+
+impl HybridSpeechConfig {
+    /// Creates an instance of the hybrid speech config combining cloud and embedded configs.
+    /// Settings of both configs are copied, i.e. configs can be dropped afterwards.
+    pub fn from_configs(
+        cloud_speech_config: &SpeechConfig,
+        embedded_speech_config: &EmbeddedSpeechConfig,
+    ) -> Result<HybridSpeechConfig> {
+        unsafe {
+            let mut handle: MaybeUninit<SPXSPEECHCONFIGHANDLE> = MaybeUninit::uninit();
+            convert_err(
+                hybrid_speech_config_create(
+                    handle.as_mut_ptr(),
+                    cloud_speech_config.handle.inner(),
+                    embedded_speech_config.config.handle.inner(),
+                ),
+                "HybridSpeechConfig::from_configs error",
+            )?;
+            Ok(HybridSpeechConfig {
+                config: SpeechConfig::from_handle(handle.assume_init())?,
+            })
+        }
+    }
+
+    /// Sets the speech recognition output format.
+    /// * `format`: Speech recognition output format (simple or detailed).
+    pub fn set_speech_recognition_output_format(&mut self, format: OutputFormat) -> Result<()> {
+        self.config.set_get_output_format(format)
+    }
+
+    /// Gets the speech recognition output format.
+    pub fn get_speech_recognition_output_format(&self) -> Result<OutputFormat> {
+        self.config.get_output_format()
+    }
+
+    /// Sets the speech synthesis output format (e.g. Riff16Khz16BitMonoPcm).
+    pub fn set_speech_synthesis_output_format<F: Into<String>>(&mut self, format: F) -> Result<()> {
+        self.config
+            .set_get_speech_synthesis_output_format(format.into())
+    }
+
+    /// Gets the speech synthesis output format.
+    pub fn get_speech_synthesis_output_format(&self) -> Result<String> {
+        self.config.get_speech_synthesis_output_format()
+    }
+}
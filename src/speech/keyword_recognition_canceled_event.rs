@@ -0,0 +1,58 @@
+use crate::common::{CancellationErrorCode, CancellationReason, PropertyId};
+use crate::error::{convert_err, Result};
+use crate::ffi::{result_get_canceled_error_code, result_get_reason_canceled, SPXEVENTHANDLE};
+use crate::speech::KeywordRecognitionEvent;
+use log::*;
+
+/// Recognition event extending *KeywordRecognitionEvent* passed into callback *set_canceled_cb*.
+#[derive(Debug)]
+pub struct KeywordRecognitionCanceledEvent {
+    pub base: KeywordRecognitionEvent,
+    pub reason: CancellationReason,
+    pub error_code: CancellationErrorCode,
+    pub error_details: String,
+}
+
+impl KeywordRecognitionCanceledEvent {
+    /// # Safety
+    /// `handle` must be a valid handle to a live keyword recognition canceled event.
+    pub unsafe fn from_handle(handle: SPXEVENTHANDLE) -> Result<KeywordRecognitionCanceledEvent> {
+        unsafe {
+            let base = KeywordRecognitionEvent::from_handle(handle)?;
+            let result_handle = base.result.base.handle.inner();
+            let mut reason = 0;
+            let ret = result_get_reason_canceled(result_handle, &mut reason);
+            convert_err(
+                ret,
+                "KeywordRecognitionCanceledEvent::from_handle(result_get_reason_canceled) error",
+            )?;
+
+            let mut error_code = 0;
+            let ret = result_get_canceled_error_code(result_handle, &mut error_code);
+            convert_err(
+                ret,
+                "KeywordRecognitionCanceledEvent::from_handle(result_get_canceled_error_code) error",
+            )?;
+
+            let error_details = base
+                .result
+                .base
+                .properties
+                .get_property(PropertyId::SpeechServiceResponseJsonErrorDetails, "")
+                .unwrap_or_else(|err| {
+                    warn!(
+                        "Error when getting SpeechServiceResponseJsonErrorDetails {:?}",
+                        err
+                    );
+                    "".to_owned()
+                });
+
+            Ok(KeywordRecognitionCanceledEvent {
+                base,
+                reason: reason.into(),
+                error_code: error_code.into(),
+                error_details,
+            })
+        }
+    }
+}
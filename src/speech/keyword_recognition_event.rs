@@ -0,0 +1,29 @@
+use crate::error::{convert_err, Result};
+use crate::ffi::{recognizer_recognition_event_get_result, SPXEVENTHANDLE, SPXRESULTHANDLE};
+use crate::speech::{KeywordRecognitionResult, RecognitionEvent};
+use log::*;
+use std::mem::MaybeUninit;
+
+/// Recognition event extending *RecognitionEvent* passed into callback *set_recognized_cb*.
+#[derive(Debug)]
+pub struct KeywordRecognitionEvent {
+    pub base: RecognitionEvent,
+    pub result: KeywordRecognitionResult,
+}
+
+impl KeywordRecognitionEvent {
+    /// # Safety
+    /// `handle` must be a valid handle to a live keyword recognition event.
+    pub unsafe fn from_handle(handle: SPXEVENTHANDLE) -> Result<KeywordRecognitionEvent> {
+        unsafe {
+            let base = RecognitionEvent::from_handle(handle)?;
+            let mut result_handle: MaybeUninit<SPXRESULTHANDLE> = MaybeUninit::uninit();
+            trace!("calling recognizer_recognition_event_get_result");
+            let ret = recognizer_recognition_event_get_result(handle, result_handle.as_mut_ptr());
+            convert_err(ret, "KeywordRecognitionEvent::from_handle error")?;
+            trace!("called recognizer_recognition_event_get_result");
+            let result = KeywordRecognitionResult::from_handle(result_handle.assume_init())?;
+            Ok(KeywordRecognitionEvent { base, result })
+        }
+    }
+}
@@ -0,0 +1,30 @@
+use crate::error::Result;
+use crate::ffi::SPXRESULTHANDLE;
+use crate::speech::SpeechRecognitionResult;
+use std::fmt;
+
+/// Represents keyword recognition result returned by *KeywordRecognizer*
+/// or contained within callback event *KeywordRecognitionEvent*.
+/// Audio starting with the keyword can be retrieved via *AudioDataStream::from_keyword_result*.
+pub struct KeywordRecognitionResult {
+    pub base: SpeechRecognitionResult,
+}
+
+impl fmt::Debug for KeywordRecognitionResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("KeywordRecognitionResult")
+            .field("base", &self.base)
+            .finish()
+    }
+}
+
+impl KeywordRecognitionResult {
+    /// # Safety
+    /// `handle` must be a valid handle to a live keyword recognition result.
+    pub unsafe fn from_handle(handle: SPXRESULTHANDLE) -> Result<KeywordRecognitionResult> {
+        unsafe {
+            let base = SpeechRecognitionResult::from_handle(handle)?;
+            Ok(KeywordRecognitionResult { base })
+        }
+    }
+}
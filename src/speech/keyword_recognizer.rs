@@ -0,0 +1,242 @@
+use crate::audio::AudioConfig;
+use crate::common::PropertyCollection;
+use crate::error::{convert_err, Result};
+use crate::ffi::{
+    recognizer_async_handle_release, recognizer_canceled_set_callback,
+    recognizer_create_keyword_recognizer_from_audio_config, recognizer_get_property_bag,
+    recognizer_handle_release, recognizer_recognize_keyword_once_async,
+    recognizer_recognize_keyword_once_async_wait_for, recognizer_recognized_set_callback,
+    recognizer_stop_keyword_recognition_async, recognizer_stop_keyword_recognition_async_wait_for,
+    wait_for_async, SmartHandle, SPXASYNCHANDLE, SPXEVENTHANDLE, SPXPROPERTYBAGHANDLE,
+    SPXRECOHANDLE, SPXRESULTHANDLE,
+};
+use crate::speech::{
+    KeywordRecognitionCanceledEvent, KeywordRecognitionEvent, KeywordRecognitionModel,
+    KeywordRecognitionResult,
+};
+use log::*;
+use std::boxed::Box;
+use std::fmt;
+use std::mem::MaybeUninit;
+use std::os::raw::c_void;
+
+/// A separate internal struct to hold all the callback closures for the keyword recognizer events.
+/// By creating a separate struct, and then boxing this struct inside our KeywordRecognizer,
+/// we can ensure the KeywordRecognizer itself can be moved freely by end users,
+/// and the callbacks will remain at a fixed memory address on the heap.
+struct CallbackBag {
+    recognized_cb: Option<Box<dyn Fn(KeywordRecognitionEvent) + Send>>,
+    canceled_cb: Option<Box<dyn Fn(KeywordRecognitionCanceledEvent) + Send>>,
+}
+
+/// KeywordRecognizer struct holds functionality for on-device keyword (wake word) recognition.
+/// No speech service connection is needed.
+pub struct KeywordRecognizer {
+    pub(crate) handle: SmartHandle<SPXRECOHANDLE>,
+    properties: PropertyCollection,
+    callback_bag: Box<CallbackBag>,
+}
+
+impl fmt::Debug for KeywordRecognizer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("KeywordRecognizer")
+            .field("handle", &self.handle)
+            .field("properties", &self.properties)
+            .finish()
+    }
+}
+
+impl KeywordRecognizer {
+    /// # Safety
+    /// `handle` must be a valid handle to a live keyword recognizer.
+    unsafe fn from_handle(handle: SPXRECOHANDLE) -> Result<KeywordRecognizer> {
+        unsafe {
+            let mut prop_bag_handle: MaybeUninit<SPXPROPERTYBAGHANDLE> = MaybeUninit::uninit();
+            let ret = recognizer_get_property_bag(handle, prop_bag_handle.as_mut_ptr());
+            convert_err(ret, "KeywordRecognizer::from_handle error")?;
+
+            let property_bag = PropertyCollection::from_handle(prop_bag_handle.assume_init());
+
+            let result = KeywordRecognizer {
+                handle: SmartHandle::create("KeywordRecognizer", handle, recognizer_handle_release),
+                properties: property_bag,
+                callback_bag: Box::new(CallbackBag {
+                    recognized_cb: None,
+                    canceled_cb: None,
+                }),
+            };
+            Ok(result)
+        }
+    }
+
+    pub fn from_config(audio_config: AudioConfig) -> Result<KeywordRecognizer> {
+        unsafe {
+            let mut handle: MaybeUninit<SPXRECOHANDLE> = MaybeUninit::uninit();
+            convert_err(
+                recognizer_create_keyword_recognizer_from_audio_config(
+                    handle.as_mut_ptr(),
+                    audio_config.handle.inner(),
+                ),
+                "KeywordRecognizer.from_config error",
+            )?;
+            KeywordRecognizer::from_handle(handle.assume_init())
+        }
+    }
+
+    /// Recognized signals for events containing keyword recognition results.
+    pub fn set_recognized_cb<F>(&mut self, f: F) -> Result<()>
+    where
+        F: Fn(KeywordRecognitionEvent) + 'static + Send,
+    {
+        self.callback_bag.recognized_cb = Some(Box::new(f));
+        unsafe {
+            let ret = recognizer_recognized_set_callback(
+                self.handle.inner(),
+                Some(Self::cb_recognized),
+                &*self.callback_bag as *const _ as *mut c_void,
+            );
+            convert_err(ret, "KeywordRecognizer.set_recognized_cb error")?;
+            Ok(())
+        }
+    }
+
+    /// Canceled signals for events containing canceled keyword recognition results.
+    pub fn set_canceled_cb<F>(&mut self, f: F) -> Result<()>
+    where
+        F: Fn(KeywordRecognitionCanceledEvent) + 'static + Send,
+    {
+        self.callback_bag.canceled_cb = Some(Box::new(f));
+        unsafe {
+            let ret = recognizer_canceled_set_callback(
+                self.handle.inner(),
+                Some(Self::cb_canceled),
+                &*self.callback_bag as *const _ as *mut c_void,
+            );
+            convert_err(ret, "KeywordRecognizer.set_canceled_cb error")?;
+            Ok(())
+        }
+    }
+
+    #[allow(non_snake_case)]
+    #[allow(unused_variables)]
+    unsafe extern "C" fn cb_recognized(
+        hreco: SPXRECOHANDLE,
+        hevent: SPXEVENTHANDLE,
+        pvContext: *mut c_void,
+    ) {
+        trace!("KeywordRecognizer::cb_recognized called");
+        let callback_bag = &mut *(pvContext as *mut CallbackBag);
+        if let Some(cb) = &callback_bag.recognized_cb {
+            trace!("recognized_cb defined");
+            match KeywordRecognitionEvent::from_handle(hevent) {
+                Ok(event) => {
+                    trace!("calling cb with event {:?}", event);
+                    cb(event);
+                }
+                Err(err) => {
+                    error!("KeywordRecognizer::cb_recognized error {:?}", err);
+                }
+            }
+        }
+    }
+
+    #[allow(non_snake_case)]
+    #[allow(unused_variables)]
+    unsafe extern "C" fn cb_canceled(
+        hreco: SPXRECOHANDLE,
+        hevent: SPXEVENTHANDLE,
+        pvContext: *mut c_void,
+    ) {
+        trace!("KeywordRecognizer::cb_canceled called");
+        let callback_bag = &mut *(pvContext as *mut CallbackBag);
+        if let Some(cb) = &callback_bag.canceled_cb {
+            trace!("canceled_cb defined");
+            match KeywordRecognitionCanceledEvent::from_handle(hevent) {
+                Ok(event) => {
+                    trace!("calling cb with event {:?}", event);
+                    cb(event);
+                }
+                Err(err) => {
+                    error!("KeywordRecognizer::cb_canceled error {:?}", err);
+                }
+            }
+        }
+    }
+
+    /// Starts keyword recognition and returns once keyword from the model is recognized
+    /// (or recognition is stopped/canceled).
+    /// Pending recognition can be terminated by *stop_recognition_async*.
+    pub async fn recognize_once_async(
+        &self,
+        model: &KeywordRecognitionModel,
+    ) -> Result<KeywordRecognitionResult> {
+        let handle_async = unsafe {
+            let mut handle_async: MaybeUninit<SPXASYNCHANDLE> = MaybeUninit::uninit();
+            let ret = recognizer_recognize_keyword_once_async(
+                self.handle.inner(),
+                model.handle.inner(),
+                handle_async.as_mut_ptr(),
+            );
+            convert_err(ret, "KeywordRecognizer.recognize_once_async error")?;
+            SmartHandle::create(
+                "handle_async_recognize_keyword_once",
+                handle_async.assume_init(),
+                recognizer_async_handle_release,
+            )
+        };
+        wait_for_async(handle_async, |handle_async| unsafe {
+            let mut handle_result: MaybeUninit<SPXRESULTHANDLE> = MaybeUninit::uninit();
+            let ret = recognizer_recognize_keyword_once_async_wait_for(
+                handle_async,
+                u32::MAX,
+                handle_result.as_mut_ptr(),
+            );
+            convert_err(
+                ret,
+                "KeywordRecognizer.recognizer_recognize_keyword_once_async_wait_for error",
+            )?;
+            KeywordRecognitionResult::from_handle(handle_result.assume_init())
+        })
+        .await
+    }
+
+    /// Asynchronously terminates ongoing keyword recognition operation.
+    pub async fn stop_recognition_async(&self) -> Result<()> {
+        let handle_async = unsafe {
+            let mut handle_async: MaybeUninit<SPXASYNCHANDLE> = MaybeUninit::uninit();
+            let ret = recognizer_stop_keyword_recognition_async(
+                self.handle.inner(),
+                handle_async.as_mut_ptr(),
+            );
+            convert_err(ret, "KeywordRecognizer.stop_recognition_async error")?;
+            SmartHandle::create(
+                "handle_async_stop_keyword",
+                handle_async.assume_init(),
+                recognizer_async_handle_release,
+            )
+        };
+        wait_for_async(handle_async, |handle_async| unsafe {
+            let ret = recognizer_stop_keyword_recognition_async_wait_for(handle_async, u32::MAX);
+            convert_err(
+                ret,
+                "KeywordRecognizer.recognizer_stop_keyword_recognition_async_wait_for error",
+            )
+        })
+        .await
+    }
+}
+
+/// Disconnects registered callbacks before callback bag is freed, as native recognizer
+/// can outlive this struct when future of pending recognition is dropped.
+impl Drop for KeywordRecognizer {
+    fn drop(&mut self) {
+        unsafe {
+            if self.callback_bag.recognized_cb.is_some() {
+                recognizer_recognized_set_callback(self.handle.inner(), None, std::ptr::null_mut());
+            }
+            if self.callback_bag.canceled_cb.is_some() {
+                recognizer_canceled_set_callback(self.handle.inner(), None, std::ptr::null_mut());
+            }
+        }
+    }
+}
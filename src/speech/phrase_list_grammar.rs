@@ -4,10 +4,11 @@ use std::mem::MaybeUninit;
 use crate::error::{convert_err, Result};
 use crate::ffi::{
     grammar_handle_release, phrase_list_grammar_add_phrase, phrase_list_grammar_clear,
-    phrase_list_grammar_from_recognizer_by_name, SmartHandle, SPXGRAMMARHANDLE,
+    phrase_list_grammar_from_recognizer_by_name, phrase_list_grammar_set_weight, SmartHandle,
+    SPXGRAMMARHANDLE,
 };
 use crate::speech::grammar_phrase::GrammarPhrase;
-use crate::speech::SpeechRecognizer;
+use crate::speech::{Grammar, Recognizer, SealedGrammar};
 
 /// Represents a phrase list grammar for dynamic grammar scenarios. \
 /// Added in version 1.5.0.
@@ -16,15 +17,23 @@ pub struct PhraseListGrammar {
     handle: SmartHandle<SPXGRAMMARHANDLE>,
 }
 
+impl SealedGrammar for PhraseListGrammar {}
+
+impl Grammar for PhraseListGrammar {
+    fn get_handle(&self) -> SPXGRAMMARHANDLE {
+        self.handle.inner()
+    }
+}
+
 impl PhraseListGrammar {
     /// Creates a phrase list grammar for the specified recognizer.
-    pub fn from_recognizer(recognizer: &SpeechRecognizer) -> Result<PhraseListGrammar> {
+    pub fn from_recognizer(recognizer: &dyn Recognizer) -> Result<PhraseListGrammar> {
         unsafe {
             let c_name = CString::new("")?;
             let mut handle: MaybeUninit<SPXGRAMMARHANDLE> = MaybeUninit::uninit();
             let ret = phrase_list_grammar_from_recognizer_by_name(
                 handle.as_mut_ptr(),
-                recognizer.handle.inner(),
+                recognizer.get_handle(),
                 c_name.as_ptr(),
             );
             convert_err(ret, "PhraseListGrammar::from_recognizer error")?;
@@ -48,6 +57,15 @@ impl PhraseListGrammar {
         Ok(())
     }
 
+    /// Sets the weight of the phrases in the phrase list grammar.
+    /// Weight ranges from 0.0 to 2.0, default is 1.0. Higher weight
+    /// makes recognizer more likely to recognize the phrases.
+    pub fn set_weight(&self, weight: f64) -> Result<()> {
+        let ret = unsafe { phrase_list_grammar_set_weight(self.handle.inner(), weight) };
+        convert_err(ret, "PhraseListGrammar::set_weight error")?;
+        Ok(())
+    }
+
     /// Clears all phrases from the phrase list grammar.
     pub fn clear(&self) -> Result<()> {
         let ret = unsafe { phrase_list_grammar_clear(self.handle.inner()) };
@@ -0,0 +1,163 @@
+use crate::common::{
+    PronunciationAssessmentGradingSystem, PronunciationAssessmentGranularity, PropertyCollection,
+    PropertyId,
+};
+use crate::error::{convert_err, Result};
+use crate::ffi::{
+    create_pronunciation_assessment_config, create_pronunciation_assessment_config_from_json,
+    pronunciation_assessment_config_apply_to_recognizer,
+    pronunciation_assessment_config_get_property_bag, pronunciation_assessment_config_release,
+    pronunciation_assessment_config_to_json, SmartHandle, NULL_C_STR_PTR,
+    SPXPRONUNCIATIONASSESSMENTCONFIGHANDLE, SPXPROPERTYBAGHANDLE,
+};
+use crate::speech::SpeechRecognizer;
+use std::ffi::{CStr, CString};
+use std::mem::MaybeUninit;
+
+/// PronunciationAssessmentConfig defines pronunciation assessment configuration.
+/// Create it with *new* or *from_json*, adjust it with setters
+/// and apply it to recognizer with *apply_to_recognizer*.
+/// Assessment is then available via *PronunciationAssessmentResult::from_result*.
+#[derive(Debug)]
+pub struct PronunciationAssessmentConfig {
+    pub handle: SmartHandle<SPXPRONUNCIATIONASSESSMENTCONFIGHANDLE>,
+    properties: PropertyCollection,
+}
+
+impl PronunciationAssessmentConfig {
+    /// # Safety
+    /// `handle` must be a valid handle to a live pronunciation assessment config.
+    unsafe fn from_handle(
+        handle: SPXPRONUNCIATIONASSESSMENTCONFIGHANDLE,
+    ) -> Result<PronunciationAssessmentConfig> {
+        unsafe {
+            let mut prop_bag_handle: MaybeUninit<SPXPROPERTYBAGHANDLE> = MaybeUninit::uninit();
+            let ret = pronunciation_assessment_config_get_property_bag(
+                handle,
+                prop_bag_handle.as_mut_ptr(),
+            );
+            convert_err(ret, "PronunciationAssessmentConfig::from_handle error")?;
+            let property_bag = PropertyCollection::from_handle(prop_bag_handle.assume_init());
+
+            Ok(PronunciationAssessmentConfig {
+                handle: SmartHandle::create(
+                    "PronunciationAssessmentConfig",
+                    handle,
+                    pronunciation_assessment_config_release,
+                ),
+                properties: property_bag,
+            })
+        }
+    }
+
+    /// Creates an instance of the PronunciationAssessmentConfig.
+    /// *reference_text* is the text that the pronunciation will be evaluated against,
+    /// it can be left empty for unscripted assessment.
+    /// *enable_miscue* enables detection of omitted and inserted words
+    /// by comparing recognized text with reference text.
+    pub fn new(
+        reference_text: &str,
+        grading_system: PronunciationAssessmentGradingSystem,
+        granularity: PronunciationAssessmentGranularity,
+        enable_miscue: bool,
+    ) -> Result<PronunciationAssessmentConfig> {
+        unsafe {
+            let c_reference_text = CString::new(reference_text)?;
+            let mut handle: MaybeUninit<SPXPRONUNCIATIONASSESSMENTCONFIGHANDLE> =
+                MaybeUninit::uninit();
+            let ret = create_pronunciation_assessment_config(
+                handle.as_mut_ptr(),
+                c_reference_text.as_ptr(),
+                grading_system.into(),
+                granularity.into(),
+                enable_miscue,
+            );
+            convert_err(ret, "PronunciationAssessmentConfig::new error")?;
+            PronunciationAssessmentConfig::from_handle(handle.assume_init())
+        }
+    }
+
+    /// Creates an instance of the PronunciationAssessmentConfig from json string, e.g.
+    /// {"referenceText":"good morning","gradingSystem":"HundredMark","granularity":"Phoneme"}
+    pub fn from_json(json: &str) -> Result<PronunciationAssessmentConfig> {
+        unsafe {
+            let c_json = CString::new(json)?;
+            let mut handle: MaybeUninit<SPXPRONUNCIATIONASSESSMENTCONFIGHANDLE> =
+                MaybeUninit::uninit();
+            let ret = create_pronunciation_assessment_config_from_json(
+                handle.as_mut_ptr(),
+                c_json.as_ptr(),
+            );
+            convert_err(ret, "PronunciationAssessmentConfig::from_json error")?;
+            PronunciationAssessmentConfig::from_handle(handle.assume_init())
+        }
+    }
+
+    /// Gets json string representation of pronunciation assessment parameters.
+    pub fn to_json(&self) -> Result<String> {
+        unsafe {
+            let ret = pronunciation_assessment_config_to_json(self.handle.inner());
+            if ret == NULL_C_STR_PTR {
+                Ok("".to_owned())
+            } else {
+                Ok(CStr::from_ptr(ret).to_str()?.to_owned())
+            }
+        }
+    }
+
+    pub fn get_reference_text(&self) -> Result<String> {
+        self.properties
+            .get_property(PropertyId::PronunciationAssessmentReferenceText, "")
+    }
+
+    pub fn set_reference_text(&mut self, reference_text: &str) -> Result<()> {
+        self.properties.set_property(
+            PropertyId::PronunciationAssessmentReferenceText,
+            reference_text,
+        )
+    }
+
+    /// Sets the phoneme alphabet. Valid values are *SAPI* (default) and *IPA*.
+    pub fn set_phoneme_alphabet(&mut self, phoneme_alphabet: &str) -> Result<()> {
+        self.properties.set_property(
+            PropertyId::PronunciationAssessmentPhonemeAlphabet,
+            phoneme_alphabet,
+        )
+    }
+
+    /// Sets the number of nbest phonemes returned for each phoneme.
+    pub fn set_nbest_phoneme_count(&mut self, count: u32) -> Result<()> {
+        self.properties.set_property(
+            PropertyId::PronunciationAssessmentNBestPhonemeCount,
+            count.to_string(),
+        )
+    }
+
+    /// Enables prosody assessment. Prosody scores are then included in the results.
+    pub fn enable_prosody_assessment(&mut self) -> Result<()> {
+        self.properties.set_property(
+            PropertyId::PronunciationAssessmentEnableProsodyAssessment,
+            "true",
+        )
+    }
+
+    /// Enables content assessment for the given topic (unscripted assessment).
+    pub fn enable_content_assessment_with_topic(&mut self, topic: &str) -> Result<()> {
+        self.properties
+            .set_property(PropertyId::PronunciationAssessmentContentTopic, topic)
+    }
+
+    /// Applies the settings in this config to the specified recognizer.
+    pub fn apply_to_recognizer(&self, recognizer: &SpeechRecognizer) -> Result<()> {
+        unsafe {
+            let ret = pronunciation_assessment_config_apply_to_recognizer(
+                self.handle.inner(),
+                recognizer.handle.inner(),
+            );
+            convert_err(
+                ret,
+                "PronunciationAssessmentConfig.apply_to_recognizer error",
+            )
+        }
+    }
+}
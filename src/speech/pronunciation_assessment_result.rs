@@ -0,0 +1,346 @@
+use crate::common::{PropertyId, Ticks};
+use crate::error::Result;
+use crate::speech::SpeechRecognitionResult;
+use serde::Deserialize;
+use std::time::Duration;
+
+/// PronunciationAssessmentResult contains pronunciation assessment scores
+/// parsed from detailed JSON result of the recognizer
+/// (see *PronunciationAssessmentConfig::apply_to_recognizer*).
+#[derive(Debug, Clone)]
+pub struct PronunciationAssessmentResult {
+    /// Pronunciation accuracy of the speech, i.e. how closely the phonemes match native speaker's pronunciation.
+    pub accuracy_score: f64,
+    /// Overall score indicating the pronunciation quality of the given speech.
+    pub pronunciation_score: f64,
+    /// Completeness of the speech, calculated by the ratio of pronounced words to reference text input.
+    pub completeness_score: f64,
+    /// Fluency of the given speech, i.e. how closely the speech matches native speaker's use of silent breaks.
+    pub fluency_score: f64,
+    /// Prosody of the given speech (stress, intonation, speaking speed and rhythm).
+    /// Available only when prosody assessment is enabled.
+    pub prosody_score: Option<f64>,
+    /// Content assessment scores, available only when content assessment is enabled.
+    pub content_assessment: Option<ContentAssessmentResult>,
+    /// Word level assessment.
+    pub words: Vec<PronunciationAssessmentWordResult>,
+}
+
+/// Content assessment scores of unscripted speech.
+#[derive(Debug, Clone)]
+pub struct ContentAssessmentResult {
+    pub grammar_score: f64,
+    pub vocabulary_score: f64,
+    pub topic_score: f64,
+}
+
+/// Pronunciation assessment of single word.
+#[derive(Debug, Clone)]
+pub struct PronunciationAssessmentWordResult {
+    pub word: String,
+    /// Offset of the word.
+    pub offset: Duration,
+    /// Duration of the word.
+    pub duration: Duration,
+    pub accuracy_score: f64,
+    /// Error type of the word, e.g. *None*, *Omission*, *Insertion*, *Mispronunciation*.
+    pub error_type: String,
+    /// Prosody feedback of the word (breaks and intonation).
+    /// Available only when prosody assessment is enabled.
+    pub prosody_feedback: Option<ProsodyFeedback>,
+    pub syllables: Vec<PronunciationAssessmentSyllableResult>,
+    pub phonemes: Vec<PronunciationAssessmentPhonemeResult>,
+}
+
+/// Prosody feedback of single word.
+#[derive(Debug, Clone)]
+pub struct ProsodyFeedback {
+    pub break_feedback: BreakFeedback,
+    pub intonation: IntonationFeedback,
+}
+
+/// Feedback on pauses before the word.
+#[derive(Debug, Clone)]
+pub struct BreakFeedback {
+    /// Break error types, e.g. *None*, *UnexpectedBreak*, *MissingBreak*.
+    pub error_types: Vec<String>,
+    /// Confidence that there is unexpected break before the word.
+    pub unexpected_break_confidence: Option<f64>,
+    /// Confidence that there is missing break before the word.
+    pub missing_break_confidence: Option<f64>,
+    /// Length of the break before the word.
+    pub break_length: Duration,
+}
+
+/// Feedback on intonation of the word.
+#[derive(Debug, Clone)]
+pub struct IntonationFeedback {
+    /// Intonation error types, e.g. *Monotone*.
+    pub error_types: Vec<String>,
+    /// Confidence that the pitch of syllables is monotone.
+    pub monotone_confidence: Option<f64>,
+}
+
+/// Pronunciation assessment of single syllable.
+#[derive(Debug, Clone)]
+pub struct PronunciationAssessmentSyllableResult {
+    pub syllable: String,
+    pub grapheme: Option<String>,
+    pub offset: Duration,
+    pub duration: Duration,
+    pub accuracy_score: f64,
+}
+
+/// Pronunciation assessment of single phoneme.
+#[derive(Debug, Clone)]
+pub struct PronunciationAssessmentPhonemeResult {
+    pub phoneme: String,
+    pub offset: Duration,
+    pub duration: Duration,
+    pub accuracy_score: f64,
+    /// Most likely spoken phonemes, available when nbest phoneme count is set.
+    pub nbest_phonemes: Vec<NBestPhoneme>,
+}
+
+/// Candidate of spoken phoneme with its score.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct NBestPhoneme {
+    pub phoneme: String,
+    pub score: f64,
+}
+
+impl PronunciationAssessmentResult {
+    /// Extracts pronunciation assessment from recognition result.
+    /// Returns None if result does not contain pronunciation assessment.
+    pub fn from_result(result: &SpeechRecognitionResult) -> Result<Option<Self>> {
+        let json = result
+            .properties
+            .get_property(PropertyId::SpeechServiceResponseJsonResult, "")?;
+        if json.is_empty() {
+            return Ok(None);
+        }
+        PronunciationAssessmentResult::from_json(&json)
+    }
+
+    fn from_json(json: &str) -> Result<Option<Self>> {
+        let raw: RawResult = serde_json::from_str(json)?;
+        let best = match raw.n_best.into_iter().next() {
+            Some(best) => best,
+            None => return Ok(None),
+        };
+        let scores = match best.pronunciation_assessment {
+            Some(scores) => scores,
+            None => return Ok(None),
+        };
+        Ok(Some(PronunciationAssessmentResult {
+            accuracy_score: scores.accuracy_score,
+            pronunciation_score: scores.pron_score,
+            completeness_score: scores.completeness_score,
+            fluency_score: scores.fluency_score,
+            prosody_score: scores.prosody_score,
+            content_assessment: best
+                .content_assessment
+                .map(|content| ContentAssessmentResult {
+                    grammar_score: content.grammar_score,
+                    vocabulary_score: content.vocabulary_score,
+                    topic_score: content.topic_score,
+                }),
+            words: best.words.into_iter().map(Into::into).collect(),
+        }))
+    }
+}
+
+// raw structures mirroring detailed JSON result returned by speech service
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct RawResult {
+    #[serde(default, rename = "NBest")]
+    n_best: Vec<RawNBest>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct RawNBest {
+    pronunciation_assessment: Option<RawScores>,
+    content_assessment: Option<RawContentAssessment>,
+    #[serde(default)]
+    words: Vec<RawWord>,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(rename_all = "PascalCase", default)]
+struct RawScores {
+    accuracy_score: f64,
+    fluency_score: f64,
+    completeness_score: f64,
+    pron_score: f64,
+    prosody_score: Option<f64>,
+    error_type: Option<String>,
+    #[serde(rename = "NBestPhonemes")]
+    n_best_phonemes: Vec<NBestPhoneme>,
+    feedback: Option<RawFeedback>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct RawFeedback {
+    prosody: Option<RawProsody>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct RawProsody {
+    #[serde(default, rename = "Break")]
+    break_feedback: RawBreak,
+    #[serde(default)]
+    intonation: RawIntonation,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(rename_all = "PascalCase", default)]
+struct RawBreak {
+    error_types: Vec<String>,
+    unexpected_break: Option<RawConfidence>,
+    missing_break: Option<RawConfidence>,
+    break_length: u64,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct RawConfidence {
+    confidence: f64,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(rename_all = "PascalCase", default)]
+struct RawIntonation {
+    error_types: Vec<String>,
+    monotone: Option<RawMonotone>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct RawMonotone {
+    syllable_pitch_delta_confidence: f64,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(rename_all = "PascalCase", default)]
+struct RawContentAssessment {
+    grammar_score: f64,
+    vocabulary_score: f64,
+    topic_score: f64,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct RawWord {
+    word: String,
+    #[serde(default)]
+    offset: u64,
+    #[serde(default)]
+    duration: u64,
+    #[serde(default)]
+    pronunciation_assessment: RawScores,
+    #[serde(default)]
+    syllables: Vec<RawSyllable>,
+    #[serde(default)]
+    phonemes: Vec<RawPhoneme>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct RawSyllable {
+    syllable: String,
+    grapheme: Option<String>,
+    #[serde(default)]
+    offset: u64,
+    #[serde(default)]
+    duration: u64,
+    #[serde(default)]
+    pronunciation_assessment: RawScores,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct RawPhoneme {
+    phoneme: String,
+    #[serde(default)]
+    offset: u64,
+    #[serde(default)]
+    duration: u64,
+    #[serde(default)]
+    pronunciation_assessment: RawScores,
+}
+
+impl From<RawProsody> for ProsodyFeedback {
+    fn from(raw: RawProsody) -> Self {
+        ProsodyFeedback {
+            break_feedback: BreakFeedback {
+                error_types: raw.break_feedback.error_types,
+                unexpected_break_confidence: raw
+                    .break_feedback
+                    .unexpected_break
+                    .map(|b| b.confidence),
+                missing_break_confidence: raw.break_feedback.missing_break.map(|b| b.confidence),
+                break_length: Duration::from_ticks(raw.break_feedback.break_length),
+            },
+            intonation: IntonationFeedback {
+                error_types: raw.intonation.error_types,
+                monotone_confidence: raw
+                    .intonation
+                    .monotone
+                    .map(|m| m.syllable_pitch_delta_confidence),
+            },
+        }
+    }
+}
+
+impl From<RawWord> for PronunciationAssessmentWordResult {
+    fn from(raw: RawWord) -> Self {
+        let prosody_feedback = raw
+            .pronunciation_assessment
+            .feedback
+            .and_then(|feedback| feedback.prosody)
+            .map(Into::into);
+        PronunciationAssessmentWordResult {
+            word: raw.word,
+            offset: Duration::from_ticks(raw.offset),
+            duration: Duration::from_ticks(raw.duration),
+            accuracy_score: raw.pronunciation_assessment.accuracy_score,
+            error_type: raw
+                .pronunciation_assessment
+                .error_type
+                .unwrap_or_else(|| "None".to_owned()),
+            prosody_feedback,
+            syllables: raw.syllables.into_iter().map(Into::into).collect(),
+            phonemes: raw.phonemes.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<RawSyllable> for PronunciationAssessmentSyllableResult {
+    fn from(raw: RawSyllable) -> Self {
+        PronunciationAssessmentSyllableResult {
+            syllable: raw.syllable,
+            grapheme: raw.grapheme,
+            offset: Duration::from_ticks(raw.offset),
+            duration: Duration::from_ticks(raw.duration),
+            accuracy_score: raw.pronunciation_assessment.accuracy_score,
+        }
+    }
+}
+
+impl From<RawPhoneme> for PronunciationAssessmentPhonemeResult {
+    fn from(raw: RawPhoneme) -> Self {
+        PronunciationAssessmentPhonemeResult {
+            phoneme: raw.phoneme,
+            offset: Duration::from_ticks(raw.offset),
+            duration: Duration::from_ticks(raw.duration),
+            accuracy_score: raw.pronunciation_assessment.accuracy_score,
+            nbest_phonemes: raw.pronunciation_assessment.n_best_phonemes,
+        }
+    }
+}
@@ -1,13 +1,15 @@
+use crate::common::Ticks;
 use crate::error::{convert_err, Result};
 use crate::ffi::{recognizer_recognition_event_get_offset, SPXEVENTHANDLE};
 use crate::speech::SessionEvent;
 use log::*;
+use std::time::Duration;
 
 /// Recognition event extending *SessionEvent* passed into callbacks *set_speech_start_detected_cb* and *set_speech_end_detected_cb*.
 #[derive(Debug)]
 pub struct RecognitionEvent {
     pub base: SessionEvent,
-    pub offset: u64,
+    pub offset: Duration,
 }
 
 impl RecognitionEvent {
@@ -22,7 +24,10 @@ impl RecognitionEvent {
             let ret = recognizer_recognition_event_get_offset(handle, &mut offset);
             convert_err(ret, "RecognitionEvent::from_handle error")?;
             trace!("recognizer_recognition_event_get_offset offset: {}", offset);
-            Ok(RecognitionEvent { base, offset })
+            Ok(RecognitionEvent {
+                base,
+                offset: Duration::from_ticks(offset),
+            })
         }
     }
 }
@@ -0,0 +1,15 @@
+use crate::ffi::SPXRECOHANDLE;
+
+/// Abstraction over recognizers (speech, translation, intent,
+/// dialog service connector and transcribers).
+/// Enables transparent handling of all recognizers by functions
+/// customizing recognition, e.g. *PhraseListGrammar* or *GrammarList*.
+/// Trait is sealed, it is implemented only by recognizers of this crate.
+pub trait Recognizer: SealedRecognizer {
+    fn get_handle(&self) -> SPXRECOHANDLE;
+}
+
+/// Supertrait of *Recognizer* which cannot be named outside of this crate.
+/// Handle returned by *Recognizer::get_handle* is passed unchecked into native
+/// calls, so only recognizers of this crate (owning valid handles) may implement it.
+pub trait SealedRecognizer {}
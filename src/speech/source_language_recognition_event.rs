@@ -0,0 +1,30 @@
+use crate::error::{convert_err, Result};
+use crate::ffi::{recognizer_recognition_event_get_result, SPXEVENTHANDLE, SPXRESULTHANDLE};
+use crate::speech::{RecognitionEvent, SourceLanguageRecognitionResult};
+use log::*;
+use std::mem::MaybeUninit;
+
+/// Language identification event extending *RecognitionEvent* passed into callback *set_recognized_cb*
+/// of *SourceLanguageRecognizer*.
+#[derive(Debug)]
+pub struct SourceLanguageRecognitionEvent {
+    pub base: RecognitionEvent,
+    pub result: SourceLanguageRecognitionResult,
+}
+
+impl SourceLanguageRecognitionEvent {
+    /// # Safety
+    /// `handle` must be a valid handle to a live source language recognition event.
+    pub unsafe fn from_handle(handle: SPXEVENTHANDLE) -> Result<SourceLanguageRecognitionEvent> {
+        unsafe {
+            let base = RecognitionEvent::from_handle(handle)?;
+            let mut result_handle: MaybeUninit<SPXRESULTHANDLE> = MaybeUninit::uninit();
+            trace!("calling recognizer_recognition_event_get_result");
+            let ret = recognizer_recognition_event_get_result(handle, result_handle.as_mut_ptr());
+            convert_err(ret, "SourceLanguageRecognitionEvent::from_handle error")?;
+            trace!("called recognizer_recognition_event_get_result");
+            let result = SourceLanguageRecognitionResult::from_handle(result_handle.assume_init())?;
+            Ok(SourceLanguageRecognitionEvent { base, result })
+        }
+    }
+}
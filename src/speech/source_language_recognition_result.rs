@@ -0,0 +1,91 @@
+use crate::common::PropertyId;
+use crate::error::Result;
+use crate::ffi::SPXRESULTHANDLE;
+use crate::speech::SpeechRecognitionResult;
+use serde::Deserialize;
+use std::fmt;
+
+/// Confidence of the spoken language identification as reported by the speech service.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SourceLanguageConfidence {
+    High,
+    Medium,
+    Low,
+    /// Confidence was not reported, e.g. no language was detected.
+    Unknown,
+}
+
+impl SourceLanguageConfidence {
+    pub(crate) fn parse(confidence: &str) -> Self {
+        match confidence.to_ascii_lowercase().as_str() {
+            "high" => SourceLanguageConfidence::High,
+            "medium" => SourceLanguageConfidence::Medium,
+            "low" => SourceLanguageConfidence::Low,
+            _ => SourceLanguageConfidence::Unknown,
+        }
+    }
+}
+
+/// Represents language identification result returned by *SourceLanguageRecognizer*
+/// or contained within callback event *SourceLanguageRecognitionEvent*.
+pub struct SourceLanguageRecognitionResult {
+    pub base: SpeechRecognitionResult,
+    /// Detected language, e.g. en-US. Empty when no language was detected.
+    pub language: String,
+    pub confidence: SourceLanguageConfidence,
+}
+
+impl fmt::Debug for SourceLanguageRecognitionResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SourceLanguageRecognitionResult")
+            .field("base", &self.base)
+            .field("language", &self.language)
+            .field("confidence", &self.confidence)
+            .finish()
+    }
+}
+
+impl SourceLanguageRecognitionResult {
+    /// # Safety
+    /// `handle` must be a valid handle to a live source language recognition result.
+    pub unsafe fn from_handle(handle: SPXRESULTHANDLE) -> Result<SourceLanguageRecognitionResult> {
+        unsafe {
+            let base = SpeechRecognitionResult::from_handle(handle)?;
+            let language = base.properties.get_property(
+                PropertyId::SpeechServiceConnectionAutoDetectSourceLanguageResult,
+                "",
+            )?;
+            let json = base
+                .properties
+                .get_property(PropertyId::SpeechServiceResponseJsonResult, "")?;
+            let confidence = if json.is_empty() {
+                SourceLanguageConfidence::Unknown
+            } else {
+                let raw: RawResult = serde_json::from_str(&json)?;
+                raw.primary_language
+                    .map(|primary| SourceLanguageConfidence::parse(&primary.confidence))
+                    .unwrap_or(SourceLanguageConfidence::Unknown)
+            };
+            Ok(SourceLanguageRecognitionResult {
+                base,
+                language,
+                confidence,
+            })
+        }
+    }
+}
+
+// raw structures mirroring JSON result returned by speech service
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct RawResult {
+    primary_language: Option<RawPrimaryLanguage>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct RawPrimaryLanguage {
+    #[serde(default)]
+    confidence: String,
+}
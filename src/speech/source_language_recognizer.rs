@@ -0,0 +1,518 @@
+use crate::audio::AudioConfig;
+use crate::common::{PropertyCollection, PropertyId};
+use crate::error::{convert_err, Result};
+use crate::ffi::{
+    recognizer_async_handle_release, recognizer_canceled_set_callback,
+    recognizer_create_source_language_recognizer_from_auto_detect_source_lang_config,
+    recognizer_get_property_bag, recognizer_handle_release, recognizer_recognize_once_async,
+    recognizer_recognize_once_async_wait_for, recognizer_recognized_set_callback,
+    recognizer_session_started_set_callback, recognizer_session_stopped_set_callback,
+    recognizer_speech_end_detected_set_callback, recognizer_speech_start_detected_set_callback,
+    recognizer_start_continuous_recognition_async,
+    recognizer_start_continuous_recognition_async_wait_for,
+    recognizer_stop_continuous_recognition_async,
+    recognizer_stop_continuous_recognition_async_wait_for, wait_for_async, SmartHandle,
+    SPXASYNCHANDLE, SPXEVENTHANDLE, SPXPROPERTYBAGHANDLE, SPXRECOHANDLE, SPXRESULTHANDLE,
+};
+use crate::speech::{
+    AutoDetectSourceLanguageConfig, RecognitionEvent, SessionEvent, SourceLanguageRecognitionEvent,
+    SourceLanguageRecognitionResult, SpeechConfig, SpeechRecognitionCanceledEvent,
+};
+use log::*;
+use std::boxed::Box;
+use std::fmt;
+use std::mem::MaybeUninit;
+use std::os::raw::c_void;
+
+/// A separate internal struct to hold all the callback closures for the source language recognizer events.
+/// By creating a separate struct, and then boxing this struct inside our SourceLanguageRecognizer,
+/// we can ensure the SourceLanguageRecognizer itself can be moved freely by end users,
+/// and the callbacks will remain at a fixed memory address on the heap.
+struct CallbackBag {
+    session_started_cb: Option<Box<dyn Fn(SessionEvent) + Send>>,
+    session_stopped_cb: Option<Box<dyn Fn(SessionEvent) + Send>>,
+    speech_start_detected_cb: Option<Box<dyn Fn(RecognitionEvent) + Send>>,
+    speech_end_detected_cb: Option<Box<dyn Fn(RecognitionEvent) + Send>>,
+    canceled_cb: Option<Box<dyn Fn(SpeechRecognitionCanceledEvent) + Send>>,
+    recognized_cb: Option<Box<dyn Fn(SourceLanguageRecognitionEvent) + Send>>,
+}
+
+/// SourceLanguageRecognizer struct holds functionality for spoken language identification only,
+/// i.e. it detects language of the speech (out of candidates of *AutoDetectSourceLanguageConfig*)
+/// without transcribing it.
+pub struct SourceLanguageRecognizer {
+    pub(crate) handle: SmartHandle<SPXRECOHANDLE>,
+    properties: PropertyCollection,
+    callback_bag: Box<CallbackBag>,
+}
+
+impl fmt::Debug for SourceLanguageRecognizer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SourceLanguageRecognizer")
+            .field("handle", &self.handle)
+            .field("properties", &self.properties)
+            .finish()
+    }
+}
+
+impl SourceLanguageRecognizer {
+    /// # Safety
+    /// `handle` must be a valid handle to a live source language recognizer.
+    unsafe fn from_handle(handle: SPXRECOHANDLE) -> Result<SourceLanguageRecognizer> {
+        unsafe {
+            let mut prop_bag_handle: MaybeUninit<SPXPROPERTYBAGHANDLE> = MaybeUninit::uninit();
+            let ret = recognizer_get_property_bag(handle, prop_bag_handle.as_mut_ptr());
+            convert_err(ret, "SourceLanguageRecognizer::from_handle error")?;
+
+            let property_bag = PropertyCollection::from_handle(prop_bag_handle.assume_init());
+
+            let result = SourceLanguageRecognizer {
+                handle: SmartHandle::create(
+                    "SourceLanguageRecognizer",
+                    handle,
+                    recognizer_handle_release,
+                ),
+                properties: property_bag,
+                // Here we return a boxed instance of the CallbackBag,
+                // ensure that the pointer we provide to the C library
+                // points to a stable, heap-allocated location that holds the callbacks.
+                callback_bag: Box::new(CallbackBag {
+                    session_started_cb: None,
+                    session_stopped_cb: None,
+                    speech_start_detected_cb: None,
+                    speech_end_detected_cb: None,
+                    canceled_cb: None,
+                    recognized_cb: None,
+                }),
+            };
+            Ok(result)
+        }
+    }
+
+    pub fn from_auto_detect_source_lang_config(
+        speech_config: SpeechConfig,
+        audio_config: AudioConfig,
+        lang_config: AutoDetectSourceLanguageConfig,
+    ) -> Result<SourceLanguageRecognizer> {
+        unsafe {
+            let mut handle: MaybeUninit<SPXRECOHANDLE> = MaybeUninit::uninit();
+            convert_err(
+                recognizer_create_source_language_recognizer_from_auto_detect_source_lang_config(
+                    handle.as_mut_ptr(),
+                    speech_config.handle.inner(),
+                    lang_config.handle.inner(),
+                    audio_config.handle.inner(),
+                ),
+                "SourceLanguageRecognizer.from_auto_detect_source_lang_config error",
+            )?;
+            SourceLanguageRecognizer::from_handle(handle.assume_init())
+        }
+    }
+
+    pub fn set_session_started_cb<F>(&mut self, f: F) -> Result<()>
+    where
+        F: Fn(SessionEvent) + 'static + Send,
+    {
+        self.callback_bag.session_started_cb = Some(Box::new(f));
+        unsafe {
+            let ret = recognizer_session_started_set_callback(
+                self.handle.inner(),
+                Some(Self::cb_session_started),
+                &*self.callback_bag as *const _ as *mut c_void,
+            );
+            convert_err(ret, "SourceLanguageRecognizer.set_session_started_cb error")?;
+            Ok(())
+        }
+    }
+
+    pub fn set_session_stopped_cb<F>(&mut self, f: F) -> Result<()>
+    where
+        F: Fn(SessionEvent) + 'static + Send,
+    {
+        self.callback_bag.session_stopped_cb = Some(Box::new(f));
+        unsafe {
+            let ret = recognizer_session_stopped_set_callback(
+                self.handle.inner(),
+                Some(Self::cb_session_stopped),
+                &*self.callback_bag as *const _ as *mut c_void,
+            );
+            convert_err(ret, "SourceLanguageRecognizer.set_session_stopped_cb error")?;
+            Ok(())
+        }
+    }
+
+    pub fn set_speech_start_detected_cb<F>(&mut self, f: F) -> Result<()>
+    where
+        F: Fn(RecognitionEvent) + 'static + Send,
+    {
+        self.callback_bag.speech_start_detected_cb = Some(Box::new(f));
+        unsafe {
+            let ret = recognizer_speech_start_detected_set_callback(
+                self.handle.inner(),
+                Some(Self::cb_speech_start_detected),
+                &*self.callback_bag as *const _ as *mut c_void,
+            );
+            convert_err(
+                ret,
+                "SourceLanguageRecognizer.set_speech_start_detected_cb error",
+            )?;
+            Ok(())
+        }
+    }
+
+    pub fn set_speech_end_detected_cb<F>(&mut self, f: F) -> Result<()>
+    where
+        F: Fn(RecognitionEvent) + 'static + Send,
+    {
+        self.callback_bag.speech_end_detected_cb = Some(Box::new(f));
+        unsafe {
+            let ret = recognizer_speech_end_detected_set_callback(
+                self.handle.inner(),
+                Some(Self::cb_speech_end_detected),
+                &*self.callback_bag as *const _ as *mut c_void,
+            );
+            convert_err(
+                ret,
+                "SourceLanguageRecognizer.set_speech_end_detected_cb error",
+            )?;
+            Ok(())
+        }
+    }
+
+    /// Canceled signals for events containing canceled recognition results
+    /// (indicating a recognition attempt that was canceled as a result or a direct cancellation request
+    /// or, alternatively, a transport or protocol failure).
+    pub fn set_canceled_cb<F>(&mut self, f: F) -> Result<()>
+    where
+        F: Fn(SpeechRecognitionCanceledEvent) + 'static + Send,
+    {
+        self.callback_bag.canceled_cb = Some(Box::new(f));
+        unsafe {
+            let ret = recognizer_canceled_set_callback(
+                self.handle.inner(),
+                Some(Self::cb_canceled),
+                &*self.callback_bag as *const _ as *mut c_void,
+            );
+            convert_err(ret, "SourceLanguageRecognizer.set_canceled_cb error")?;
+            Ok(())
+        }
+    }
+
+    pub fn set_recognized_cb<F>(&mut self, f: F) -> Result<()>
+    where
+        F: Fn(SourceLanguageRecognitionEvent) + 'static + Send,
+    {
+        self.callback_bag.recognized_cb = Some(Box::new(f));
+        unsafe {
+            trace!("calling recognizer_recognized_set_callback");
+            let ret = recognizer_recognized_set_callback(
+                self.handle.inner(),
+                Some(Self::cb_recognized),
+                &*self.callback_bag as *const _ as *mut c_void,
+            );
+            convert_err(ret, "SourceLanguageRecognizer.set_recognized_cb error")?;
+            trace!("called recognizer_recognized_set_callback");
+            Ok(())
+        }
+    }
+
+    #[allow(non_snake_case)]
+    #[allow(unused_variables)]
+    unsafe extern "C" fn cb_session_started(
+        hreco: SPXRECOHANDLE,
+        hevent: SPXEVENTHANDLE,
+        pvContext: *mut c_void,
+    ) {
+        trace!("SourceLanguageRecognizer::cb_session_started called");
+        let callback_bag = &mut *(pvContext as *mut CallbackBag);
+        if let Some(cb) = &callback_bag.session_started_cb {
+            trace!("session_started_cb defined");
+            match SessionEvent::from_handle(hevent) {
+                Ok(event) => {
+                    trace!("calling cb with event {:?}", event);
+                    cb(event);
+                }
+                Err(err) => {
+                    error!(
+                        "SourceLanguageRecognizer::cb_session_started error {:?}",
+                        err
+                    );
+                }
+            }
+        }
+    }
+
+    #[allow(non_snake_case)]
+    #[allow(unused_variables)]
+    unsafe extern "C" fn cb_session_stopped(
+        hreco: SPXRECOHANDLE,
+        hevent: SPXEVENTHANDLE,
+        pvContext: *mut c_void,
+    ) {
+        trace!("SourceLanguageRecognizer::cb_session_stopped called");
+        let callback_bag = &mut *(pvContext as *mut CallbackBag);
+        if let Some(cb) = &callback_bag.session_stopped_cb {
+            trace!("cb_session_stopped defined");
+            match SessionEvent::from_handle(hevent) {
+                Ok(event) => {
+                    trace!("calling cb with event {:?}", event);
+                    cb(event);
+                }
+                Err(err) => {
+                    error!(
+                        "SourceLanguageRecognizer::cb_session_stopped error {:?}",
+                        err
+                    );
+                }
+            }
+        }
+    }
+
+    #[allow(non_snake_case)]
+    #[allow(unused_variables)]
+    unsafe extern "C" fn cb_speech_start_detected(
+        hreco: SPXRECOHANDLE,
+        hevent: SPXEVENTHANDLE,
+        pvContext: *mut c_void,
+    ) {
+        trace!("SourceLanguageRecognizer::cb_speech_start_detected called");
+        let callback_bag = &mut *(pvContext as *mut CallbackBag);
+        if let Some(cb) = &callback_bag.speech_start_detected_cb {
+            trace!("speech_start_detected_cb defined");
+            match RecognitionEvent::from_handle(hevent) {
+                Ok(event) => {
+                    trace!("calling cb with event {:?}", event);
+                    cb(event);
+                }
+                Err(err) => {
+                    error!(
+                        "SourceLanguageRecognizer::cb_speech_start_detected error {:?}",
+                        err
+                    );
+                }
+            }
+        }
+    }
+
+    #[allow(non_snake_case)]
+    #[allow(unused_variables)]
+    unsafe extern "C" fn cb_speech_end_detected(
+        hreco: SPXRECOHANDLE,
+        hevent: SPXEVENTHANDLE,
+        pvContext: *mut c_void,
+    ) {
+        trace!("SourceLanguageRecognizer::cb_speech_end_detected called");
+        let callback_bag = &mut *(pvContext as *mut CallbackBag);
+        if let Some(cb) = &callback_bag.speech_end_detected_cb {
+            trace!("speech_end_detected_cb defined");
+            match RecognitionEvent::from_handle(hevent) {
+                Ok(event) => {
+                    trace!("calling cb with event {:?}", event);
+                    cb(event);
+                }
+                Err(err) => {
+                    error!(
+                        "SourceLanguageRecognizer::cb_speech_end_detected error {:?}",
+                        err
+                    );
+                }
+            }
+        }
+    }
+
+    #[allow(non_snake_case)]
+    #[allow(unused_variables)]
+    unsafe extern "C" fn cb_canceled(
+        hreco: SPXRECOHANDLE,
+        hevent: SPXEVENTHANDLE,
+        pvContext: *mut c_void,
+    ) {
+        trace!("SourceLanguageRecognizer::cb_canceled called");
+        let callback_bag = &mut *(pvContext as *mut CallbackBag);
+        if let Some(cb) = &callback_bag.canceled_cb {
+            trace!("canceled_cb defined");
+            match SpeechRecognitionCanceledEvent::from_handle(hevent) {
+                Ok(event) => {
+                    trace!("calling cb with event {:?}", event);
+                    cb(event);
+                }
+                Err(err) => {
+                    error!("SourceLanguageRecognizer::cb_canceled error {:?}", err);
+                }
+            }
+        }
+    }
+
+    #[allow(non_snake_case)]
+    #[allow(unused_variables)]
+    unsafe extern "C" fn cb_recognized(
+        hreco: SPXRECOHANDLE,
+        hevent: SPXEVENTHANDLE,
+        pvContext: *mut c_void,
+    ) {
+        trace!("SourceLanguageRecognizer::cb_recognized called");
+        let callback_bag = &mut *(pvContext as *mut CallbackBag);
+        if let Some(cb) = &callback_bag.recognized_cb {
+            trace!("recognized_cb defined");
+            match SourceLanguageRecognitionEvent::from_handle(hevent) {
+                Ok(event) => {
+                    trace!("calling cb with event {:?}", event);
+                    cb(event);
+                }
+                Err(err) => {
+                    error!("SourceLanguageRecognizer::cb_recognized error {:?}", err);
+                }
+            }
+        }
+    }
+
+    /// Starts language identification, and returns after the language of a single utterance is detected.
+    /// The end of a single utterance is determined by listening for silence at the end or until a maximum
+    /// of 15 seconds of audio is processed. The task returns detected language as result.
+    /// For language identification of long-running multi-utterance audio,
+    /// use start_continuous_recognition_async() instead.
+    pub async fn recognize_once_async(&mut self) -> Result<SourceLanguageRecognitionResult> {
+        let handle_async = unsafe {
+            let mut handle_async: MaybeUninit<SPXASYNCHANDLE> = MaybeUninit::uninit();
+            let ret =
+                recognizer_recognize_once_async(self.handle.inner(), handle_async.as_mut_ptr());
+            convert_err(ret, "SourceLanguageRecognizer.recognize_once_async error")?;
+            SmartHandle::create(
+                "handle_async_recognize_once",
+                handle_async.assume_init(),
+                recognizer_async_handle_release,
+            )
+        };
+        wait_for_async(handle_async, |handle_async| unsafe {
+            let mut handle_result: MaybeUninit<SPXRESULTHANDLE> = MaybeUninit::uninit();
+            let ret = recognizer_recognize_once_async_wait_for(
+                handle_async,
+                u32::MAX,
+                handle_result.as_mut_ptr(),
+            );
+            convert_err(
+                ret,
+                "SourceLanguageRecognizer.recognizer_recognize_once_async_wait_for error",
+            )?;
+            SourceLanguageRecognitionResult::from_handle(handle_result.assume_init())
+        })
+        .await
+    }
+
+    /// Asynchronously initiates continuous language identification operation.
+    /// Detected languages are signaled via *set_recognized_cb* callback.
+    pub async fn start_continuous_recognition_async(&mut self) -> Result<()> {
+        let handle_async_start_continuous = unsafe {
+            let mut handle_async_start_continuous: MaybeUninit<SPXASYNCHANDLE> =
+                MaybeUninit::uninit();
+            let ret = recognizer_start_continuous_recognition_async(
+                self.handle.inner(),
+                handle_async_start_continuous.as_mut_ptr(),
+            );
+            convert_err(
+                ret,
+                "SourceLanguageRecognizer.recognizer_start_continuous_recognition_async error",
+            )?;
+            SmartHandle::create(
+                "handle_async_start_continuous",
+                handle_async_start_continuous.assume_init(),
+                recognizer_async_handle_release,
+            )
+        };
+        wait_for_async(handle_async_start_continuous, |handle_async| unsafe {
+            let ret =
+                recognizer_start_continuous_recognition_async_wait_for(handle_async, u32::MAX);
+            convert_err(
+                ret,
+                "SourceLanguageRecognizer.recognizer_start_continuous_recognition_async_wait_for error",
+            )
+        })
+        .await
+    }
+
+    /// Asynchronously terminates ongoing continuous language identification operation.
+    pub async fn stop_continuous_recognition_async(&mut self) -> Result<()> {
+        let handle_async_stop_continuous = unsafe {
+            let mut handle_async_stop_continuous: MaybeUninit<SPXASYNCHANDLE> =
+                MaybeUninit::uninit();
+            let ret = recognizer_stop_continuous_recognition_async(
+                self.handle.inner(),
+                handle_async_stop_continuous.as_mut_ptr(),
+            );
+            convert_err(
+                ret,
+                "SourceLanguageRecognizer.recognizer_stop_continuous_recognition_async error",
+            )?;
+            SmartHandle::create(
+                "handle_async_stop_continuous",
+                handle_async_stop_continuous.assume_init(),
+                recognizer_async_handle_release,
+            )
+        };
+        wait_for_async(handle_async_stop_continuous, |handle_async| unsafe {
+            let ret = recognizer_stop_continuous_recognition_async_wait_for(handle_async, u32::MAX);
+            convert_err(
+                ret,
+                "SourceLanguageRecognizer.recognizer_stop_continuous_recognition_async_wait_for error",
+            )
+        })
+        .await
+    }
+
+    pub fn get_auth_token(&self) -> Result<String> {
+        self.properties
+            .get_property(PropertyId::SpeechServiceAuthorizationToken, "")
+    }
+
+    /// Sets the authorization token that will be used for connecting to the service.
+    /// Note: The caller needs to ensure that the authorization token is valid. Before the authorization token
+    /// expires, the caller needs to refresh it by calling this setter with a new valid token.
+    /// Otherwise, the recognizer will encounter errors during recognition.
+    pub fn set_auth_token(&mut self, token: &str) -> Result<()> {
+        self.properties
+            .set_property(PropertyId::SpeechServiceAuthorizationToken, token)
+    }
+}
+
+/// Disconnects registered callbacks before callback bag is freed, as native recognizer
+/// can outlive this struct when future of pending recognition is dropped.
+impl Drop for SourceLanguageRecognizer {
+    fn drop(&mut self) {
+        unsafe {
+            if self.callback_bag.session_started_cb.is_some() {
+                recognizer_session_started_set_callback(
+                    self.handle.inner(),
+                    None,
+                    std::ptr::null_mut(),
+                );
+            }
+            if self.callback_bag.session_stopped_cb.is_some() {
+                recognizer_session_stopped_set_callback(
+                    self.handle.inner(),
+                    None,
+                    std::ptr::null_mut(),
+                );
+            }
+            if self.callback_bag.speech_start_detected_cb.is_some() {
+                recognizer_speech_start_detected_set_callback(
+                    self.handle.inner(),
+                    None,
+                    std::ptr::null_mut(),
+                );
+            }
+            if self.callback_bag.speech_end_detected_cb.is_some() {
+                recognizer_speech_end_detected_set_callback(
+                    self.handle.inner(),
+                    None,
+                    std::ptr::null_mut(),
+                );
+            }
+            if self.callback_bag.canceled_cb.is_some() {
+                recognizer_canceled_set_callback(self.handle.inner(), None, std::ptr::null_mut());
+            }
+            if self.callback_bag.recognized_cb.is_some() {
+                recognizer_recognized_set_callback(self.handle.inner(), None, std::ptr::null_mut());
+            }
+        }
+    }
+}
@@ -1,13 +1,15 @@
-use crate::common::{PropertyCollection, ResultReason};
+use crate::common::{PropertyCollection, PropertyId, ResultReason, SpeechBackend, Ticks};
 use crate::error::{convert_err, Result};
 use crate::ffi::{
     recognizer_result_handle_release, result_get_duration, result_get_offset,
     result_get_property_bag, result_get_reason, result_get_result_id, result_get_text, SmartHandle,
     SPXPROPERTYBAGHANDLE, SPXRESULTHANDLE,
 };
+use crate::speech::DetailedRecognitionResult;
 use std::ffi::CStr;
 use std::fmt;
 use std::mem::MaybeUninit;
+use std::time::Duration;
 
 /// Represents speech recognition result contained within callback event *SpeechRecognitionEvent*.
 pub struct SpeechRecognitionResult {
@@ -15,8 +17,8 @@ pub struct SpeechRecognitionResult {
     pub result_id: String,
     pub reason: ResultReason,
     pub text: String,
-    pub duration: String, //TBD: change to duration
-    pub offset: String,   // TBD: change to duration
+    pub duration: Duration,
+    pub offset: Duration,
     pub properties: PropertyCollection,
 }
 
@@ -91,10 +93,24 @@ impl SpeechRecognitionResult {
                 result_id,
                 reason: reason.into(),
                 text: result_text,
-                duration: (duration).to_string(),
-                offset: (offset).to_string(),
+                duration: Duration::from_ticks(duration),
+                offset: Duration::from_ticks(offset),
                 properties,
             })
         }
     }
+
+    /// Returns backend (cloud or embedded) which produced the result.
+    pub fn backend(&self) -> Result<SpeechBackend> {
+        let value = self
+            .properties
+            .get_property(PropertyId::SpeechServiceResponseRecognitionBackend, "")?;
+        Ok(SpeechBackend::from_property(&value))
+    }
+
+    /// Returns detailed result (alternatives, word timings, detected language)
+    /// parsed from JSON result. Returns None if JSON result is not available.
+    pub fn detailed_result(&self) -> Result<Option<DetailedRecognitionResult>> {
+        DetailedRecognitionResult::from_properties(&self.properties)
+    }
 }
@@ -1,12 +1,16 @@
 use crate::audio::AudioConfig;
-use crate::common::{PropertyCollection, PropertyId};
+use crate::common::{
+    apply_token, event_channel, EventBuffering, EventStream, PropertyCollection, PropertyId,
+    TokenProvider,
+};
 use crate::error::{convert_err, Result};
 use crate::ffi::{
     recognizer_async_handle_release, recognizer_canceled_set_callback,
     recognizer_create_speech_recognizer_from_auto_detect_source_lang_config,
     recognizer_create_speech_recognizer_from_config,
-    recognizer_create_speech_recognizer_from_source_lang_config, recognizer_get_property_bag,
-    recognizer_handle_release, recognizer_recognize_once, recognizer_recognized_set_callback,
+    recognizer_create_speech_recognizer_from_source_lang_config, recognizer_event_handle_release,
+    recognizer_get_property_bag, recognizer_handle_release, recognizer_recognize_once_async,
+    recognizer_recognize_once_async_wait_for, recognizer_recognized_set_callback,
     recognizer_recognizing_set_callback, recognizer_session_started_set_callback,
     recognizer_session_stopped_set_callback, recognizer_speech_end_detected_set_callback,
     recognizer_speech_start_detected_set_callback, recognizer_start_continuous_recognition_async,
@@ -16,13 +20,14 @@ use crate::ffi::{
     recognizer_stop_continuous_recognition_async,
     recognizer_stop_continuous_recognition_async_wait_for,
     recognizer_stop_keyword_recognition_async, recognizer_stop_keyword_recognition_async_wait_for,
-    SmartHandle, SPXASYNCHANDLE, SPXEVENTHANDLE, SPXPROPERTYBAGHANDLE, SPXRECOHANDLE,
-    SPXRESULTHANDLE,
+    recognizer_token_requested_set_callback, wait_for_async, SmartHandle, SPXASYNCHANDLE,
+    SPXEVENTHANDLE, SPXPROPERTYBAGHANDLE, SPXRECOHANDLE, SPXRESULTHANDLE,
 };
 use crate::speech::{
-    AutoDetectSourceLanguageConfig, EmbeddedSpeechConfig, KeywordRecognitionModel,
-    RecognitionEvent, SessionEvent, SourceLanguageConfig, SpeechConfig,
-    SpeechRecognitionCanceledEvent, SpeechRecognitionEvent, SpeechRecognitionResult,
+    AutoDetectSourceLanguageConfig, EmbeddedSpeechConfig, HybridSpeechConfig,
+    KeywordRecognitionModel, RecognitionEvent, Recognizer, SealedRecognizer, SessionEvent,
+    SourceLanguageConfig, SpeechConfig, SpeechRecognitionCanceledEvent, SpeechRecognitionEvent,
+    SpeechRecognitionResult, SpeechRecognizerEvent,
 };
 use log::*;
 use std::boxed::Box;
@@ -42,16 +47,13 @@ struct CallbackBag {
     canceled_cb: Option<Box<dyn Fn(SpeechRecognitionCanceledEvent) + Send>>,
     recognizing_cb: Option<Box<dyn Fn(SpeechRecognitionEvent) + Send>>,
     recognized_cb: Option<Box<dyn Fn(SpeechRecognitionEvent) + Send>>,
+    token_provider: Option<Box<dyn TokenProvider>>,
 }
 
 /// SpeechRecognizer struct holds functionality for speech-to-text recognition.
 pub struct SpeechRecognizer {
     pub(crate) handle: SmartHandle<SPXRECOHANDLE>,
     properties: PropertyCollection,
-    handle_async_start_continuous: Option<SmartHandle<SPXASYNCHANDLE>>,
-    handle_async_stop_continuous: Option<SmartHandle<SPXASYNCHANDLE>>,
-    handle_async_start_keyword: Option<SmartHandle<SPXASYNCHANDLE>>,
-    handle_async_stop_keyword: Option<SmartHandle<SPXASYNCHANDLE>>,
     callback_bag: Box<CallbackBag>,
 }
 
@@ -64,6 +66,14 @@ impl fmt::Debug for SpeechRecognizer {
     }
 }
 
+impl SealedRecognizer for SpeechRecognizer {}
+
+impl Recognizer for SpeechRecognizer {
+    fn get_handle(&self) -> SPXRECOHANDLE {
+        self.handle.inner()
+    }
+}
+
 impl SpeechRecognizer {
     /// # Safety
     /// `handle` must be a valid handle to a live speech recognizer.
@@ -78,10 +88,6 @@ impl SpeechRecognizer {
             let result = SpeechRecognizer {
                 handle: SmartHandle::create("SpeechRecognizer", handle, recognizer_handle_release),
                 properties: property_bag,
-                handle_async_start_continuous: None,
-                handle_async_stop_continuous: None,
-                handle_async_start_keyword: None,
-                handle_async_stop_keyword: None,
                 // Here we return a boxed instance of the CallbackBag,
                 // ensure that the pointer we provide to the C library
                 // points to a stable, heap-allocated location that holds the callbacks.
@@ -93,6 +99,7 @@ impl SpeechRecognizer {
                     canceled_cb: None,
                     recognizing_cb: None,
                     recognized_cb: None,
+                    token_provider: None,
                 }),
             };
             Ok(result)
@@ -124,6 +131,15 @@ impl SpeechRecognizer {
         SpeechRecognizer::from_config(speech_config.into(), audio_config)
     }
 
+    /// Creates speech recognizer which uses the speech service when it is reachable
+    /// and falls back to embedded models otherwise.
+    pub fn from_hybrid_config(
+        speech_config: HybridSpeechConfig,
+        audio_config: AudioConfig,
+    ) -> Result<SpeechRecognizer> {
+        SpeechRecognizer::from_config(speech_config.into(), audio_config)
+    }
+
     pub fn from_auto_detect_source_lang_config(
         speech_config: SpeechConfig,
         audio_config: AudioConfig,
@@ -173,6 +189,40 @@ impl SpeechRecognizer {
         SpeechRecognizer::from_source_lang_config(speech_config, audio_config, source_lang_config)
     }
 
+    /// Returns stream of all recognizer events so that they can be consumed
+    /// from async code instead of registering individual callbacks.
+    /// Replaces callbacks previously set via *set_*_cb* methods.
+    /// Stream ends when the recognizer is dropped.
+    pub fn events(
+        &mut self,
+        buffering: EventBuffering,
+    ) -> Result<EventStream<SpeechRecognizerEvent>> {
+        let (sender, stream) = event_channel(buffering);
+        let tx = sender.clone();
+        self.set_session_started_cb(move |event| {
+            tx.send(SpeechRecognizerEvent::SessionStarted(event))
+        })?;
+        let tx = sender.clone();
+        self.set_session_stopped_cb(move |event| {
+            tx.send(SpeechRecognizerEvent::SessionStopped(event))
+        })?;
+        let tx = sender.clone();
+        self.set_speech_start_detected_cb(move |event| {
+            tx.send(SpeechRecognizerEvent::SpeechStartDetected(event))
+        })?;
+        let tx = sender.clone();
+        self.set_speech_end_detected_cb(move |event| {
+            tx.send(SpeechRecognizerEvent::SpeechEndDetected(event))
+        })?;
+        let tx = sender.clone();
+        self.set_recognizing_cb(move |event| tx.send(SpeechRecognizerEvent::Recognizing(event)))?;
+        let tx = sender.clone();
+        self.set_recognized_cb(move |event| tx.send(SpeechRecognizerEvent::Recognized(event)))?;
+        let tx = sender;
+        self.set_canceled_cb(move |event| tx.send(SpeechRecognizerEvent::Canceled(event)))?;
+        Ok(stream)
+    }
+
     pub fn set_session_started_cb<F>(&mut self, f: F) -> Result<()>
     where
         F: Fn(SessionEvent) + 'static + Send,
@@ -460,20 +510,39 @@ impl SpeechRecognizer {
     /// shot recognition like command or query.
     /// For long-running multi-utterance recognition, use StartContinuousRecognitionAsync() instead.
     pub async fn recognize_once_async(&mut self) -> Result<SpeechRecognitionResult> {
-        unsafe {
-            let mut handle_result: MaybeUninit<SPXRESULTHANDLE> = MaybeUninit::uninit();
-            let ret = recognizer_recognize_once(self.handle.inner(), handle_result.as_mut_ptr());
+        let handle_async = unsafe {
+            let mut handle_async: MaybeUninit<SPXASYNCHANDLE> = MaybeUninit::uninit();
+            let ret =
+                recognizer_recognize_once_async(self.handle.inner(), handle_async.as_mut_ptr());
             convert_err(ret, "SpeechRecognizer.recognize_once_async error")?;
+            SmartHandle::create(
+                "handle_async_recognize_once",
+                handle_async.assume_init(),
+                recognizer_async_handle_release,
+            )
+        };
+        wait_for_async(handle_async, |handle_async| unsafe {
+            let mut handle_result: MaybeUninit<SPXRESULTHANDLE> = MaybeUninit::uninit();
+            let ret = recognizer_recognize_once_async_wait_for(
+                handle_async,
+                u32::MAX,
+                handle_result.as_mut_ptr(),
+            );
+            convert_err(
+                ret,
+                "SpeechRecognizer.recognizer_recognize_once_async_wait_for error",
+            )?;
             SpeechRecognitionResult::from_handle(handle_result.assume_init())
-        }
+        })
+        .await
     }
 
     /// Asynchronously initiates continuous speech recognition operation.
     pub async fn start_continuous_recognition_async(&mut self) -> Result<()> {
-        unsafe {
+        let handle_async_start_continuous = unsafe {
             let mut handle_async_start_continuous: MaybeUninit<SPXASYNCHANDLE> =
                 MaybeUninit::uninit();
-            let mut ret = recognizer_start_continuous_recognition_async(
+            let ret = recognizer_start_continuous_recognition_async(
                 self.handle.inner(),
                 handle_async_start_continuous.as_mut_ptr(),
             );
@@ -481,30 +550,29 @@ impl SpeechRecognizer {
                 ret,
                 "SpeechRecognizer.recognizer_start_continuous_recognition_async error",
             )?;
-            self.handle_async_start_continuous = Some(SmartHandle::create(
+            SmartHandle::create(
                 "handle_async_start_continuous",
                 handle_async_start_continuous.assume_init(),
                 recognizer_async_handle_release,
-            ));
-
-            ret = recognizer_start_continuous_recognition_async_wait_for(
-                handle_async_start_continuous.assume_init(),
-                u32::MAX,
-            );
+            )
+        };
+        wait_for_async(handle_async_start_continuous, |handle_async| unsafe {
+            let ret =
+                recognizer_start_continuous_recognition_async_wait_for(handle_async, u32::MAX);
             convert_err(
                 ret,
                 "SpeechRecognizer.recognizer_start_continuous_recognition_async_wait_for error",
-            )?;
-        }
-        Ok(())
+            )
+        })
+        .await
     }
 
     /// Asynchronously terminates ongoing continuous speech recognition operation.
     pub async fn stop_continuous_recognition_async(&mut self) -> Result<()> {
-        unsafe {
+        let handle_async_stop_continuous = unsafe {
             let mut handle_async_stop_continuous: MaybeUninit<SPXASYNCHANDLE> =
                 MaybeUninit::uninit();
-            let mut ret = recognizer_stop_continuous_recognition_async(
+            let ret = recognizer_stop_continuous_recognition_async(
                 self.handle.inner(),
                 handle_async_stop_continuous.as_mut_ptr(),
             );
@@ -512,22 +580,20 @@ impl SpeechRecognizer {
                 ret,
                 "SpeechRecognizer.recognizer_stop_continuous_recognition_async error",
             )?;
-            self.handle_async_stop_continuous = Some(SmartHandle::create(
+            SmartHandle::create(
                 "handle_async_stop_continuous",
                 handle_async_stop_continuous.assume_init(),
                 recognizer_async_handle_release,
-            ));
-
-            ret = recognizer_stop_continuous_recognition_async_wait_for(
-                handle_async_stop_continuous.assume_init(),
-                u32::MAX,
-            );
+            )
+        };
+        wait_for_async(handle_async_stop_continuous, |handle_async| unsafe {
+            let ret = recognizer_stop_continuous_recognition_async_wait_for(handle_async, u32::MAX);
             convert_err(
                 ret,
                 "SpeechRecognizer.recognizer_stop_continuous_recognition_async_wait_for error",
-            )?;
-        }
-        Ok(())
+            )
+        })
+        .await
     }
 
     /// Asynchronously initiates keyword recognition operation.
@@ -535,9 +601,9 @@ impl SpeechRecognizer {
         &mut self,
         model: KeywordRecognitionModel,
     ) -> Result<()> {
-        unsafe {
+        let handle_async_start_keyword = unsafe {
             let mut handle_async_start_keyword: MaybeUninit<SPXASYNCHANDLE> = MaybeUninit::uninit();
-            let mut ret = recognizer_start_keyword_recognition_async(
+            let ret = recognizer_start_keyword_recognition_async(
                 self.handle.inner(),
                 model.handle.inner(),
                 handle_async_start_keyword.as_mut_ptr(),
@@ -546,30 +612,27 @@ impl SpeechRecognizer {
                 ret,
                 "SpeechRecognizer.recognizer_start_keyword_recognition_async error",
             )?;
-
-            self.handle_async_start_keyword = Some(SmartHandle::create(
+            SmartHandle::create(
                 "handle_async_start_keyword",
                 handle_async_start_keyword.assume_init(),
                 recognizer_async_handle_release,
-            ));
-
-            ret = recognizer_start_keyword_recognition_async_wait_for(
-                handle_async_start_keyword.assume_init(),
-                u32::MAX,
-            );
+            )
+        };
+        wait_for_async(handle_async_start_keyword, |handle_async| unsafe {
+            let ret = recognizer_start_keyword_recognition_async_wait_for(handle_async, u32::MAX);
             convert_err(
                 ret,
                 "SpeechRecognizer.recognizer_start_keyword_recognition_async_wait_for error",
-            )?;
-        }
-        Ok(())
+            )
+        })
+        .await
     }
 
     /// Asynchronously terminates keyword recognition operation.
     pub async fn stop_keyword_recognition_async(&mut self) -> Result<()> {
-        unsafe {
+        let handle_async_stop_keyword = unsafe {
             let mut handle_async_stop_keyword: MaybeUninit<SPXASYNCHANDLE> = MaybeUninit::uninit();
-            let mut ret = recognizer_stop_keyword_recognition_async(
+            let ret = recognizer_stop_keyword_recognition_async(
                 self.handle.inner(),
                 handle_async_stop_keyword.as_mut_ptr(),
             );
@@ -577,23 +640,20 @@ impl SpeechRecognizer {
                 ret,
                 "SpeechRecognizer.recognizer_stop_keyword_recognition_async error",
             )?;
-
-            self.handle_async_stop_keyword = Some(SmartHandle::create(
+            SmartHandle::create(
                 "handle_async_stop_keyword",
                 handle_async_stop_keyword.assume_init(),
                 recognizer_async_handle_release,
-            ));
-
-            ret = recognizer_stop_keyword_recognition_async_wait_for(
-                handle_async_stop_keyword.assume_init(),
-                u32::MAX,
-            );
+            )
+        };
+        wait_for_async(handle_async_stop_keyword, |handle_async| unsafe {
+            let ret = recognizer_stop_keyword_recognition_async_wait_for(handle_async, u32::MAX);
             convert_err(
                 ret,
                 "SpeechRecognizer.recognizer_stop_keyword_recognition_async_wait_for error",
-            )?;
-        }
-        Ok(())
+            )
+        })
+        .await
     }
 
     pub fn get_endpoint_id(&self) -> Result<String> {
@@ -614,4 +674,112 @@ impl SpeechRecognizer {
         self.properties
             .set_property(PropertyId::SpeechServiceAuthorizationToken, token)
     }
+
+    /// Sets authorization token provider. Token is fetched from the provider right away
+    /// and then whenever the native layer requests a new one, so that long running
+    /// continuous recognition survives token rotation without calling *set_auth_token* periodically.
+    pub fn set_token_provider<P>(&mut self, provider: P) -> Result<()>
+    where
+        P: TokenProvider + 'static,
+    {
+        apply_token(&provider, &mut self.properties)?;
+        self.callback_bag.token_provider = Some(Box::new(provider));
+        unsafe {
+            let ret = recognizer_token_requested_set_callback(
+                self.handle.inner(),
+                Some(Self::cb_token_requested),
+                &*self.callback_bag as *const _ as *mut c_void,
+            );
+            convert_err(ret, "SpeechRecognizer.set_token_provider error")?;
+            Ok(())
+        }
+    }
+
+    #[allow(non_snake_case)]
+    #[allow(unused_variables)]
+    unsafe extern "C" fn cb_token_requested(
+        hreco: SPXRECOHANDLE,
+        hevent: SPXEVENTHANDLE,
+        pvContext: *mut c_void,
+    ) {
+        trace!("SpeechRecognizer::cb_token_requested called");
+        // event carries no data, just make sure it gets released
+        let _event = SmartHandle::create(
+            "TokenRequestedEvent",
+            hevent,
+            recognizer_event_handle_release,
+        );
+        let callback_bag = &mut *(pvContext as *mut CallbackBag);
+        if let Some(provider) = &callback_bag.token_provider {
+            let mut prop_bag_handle: MaybeUninit<SPXPROPERTYBAGHANDLE> = MaybeUninit::uninit();
+            let ret = recognizer_get_property_bag(hreco, prop_bag_handle.as_mut_ptr());
+            let result =
+                convert_err(ret, "SpeechRecognizer::cb_token_requested error").and_then(|_| {
+                    let mut properties =
+                        PropertyCollection::from_handle(prop_bag_handle.assume_init());
+                    apply_token(provider.as_ref(), &mut properties)
+                });
+            if let Err(err) = result {
+                error!("SpeechRecognizer::cb_token_requested error {:?}", err);
+            }
+        }
+    }
+}
+
+/// Native recognizer can outlive this struct, e.g. when future of pending
+/// recognition is dropped and its blocking wait is still in progress,
+/// so registered callbacks are disconnected before callback bag is freed.
+impl Drop for SpeechRecognizer {
+    fn drop(&mut self) {
+        unsafe {
+            if self.callback_bag.session_started_cb.is_some() {
+                recognizer_session_started_set_callback(
+                    self.handle.inner(),
+                    None,
+                    std::ptr::null_mut(),
+                );
+            }
+            if self.callback_bag.session_stopped_cb.is_some() {
+                recognizer_session_stopped_set_callback(
+                    self.handle.inner(),
+                    None,
+                    std::ptr::null_mut(),
+                );
+            }
+            if self.callback_bag.speech_start_detected_cb.is_some() {
+                recognizer_speech_start_detected_set_callback(
+                    self.handle.inner(),
+                    None,
+                    std::ptr::null_mut(),
+                );
+            }
+            if self.callback_bag.speech_end_detected_cb.is_some() {
+                recognizer_speech_end_detected_set_callback(
+                    self.handle.inner(),
+                    None,
+                    std::ptr::null_mut(),
+                );
+            }
+            if self.callback_bag.canceled_cb.is_some() {
+                recognizer_canceled_set_callback(self.handle.inner(), None, std::ptr::null_mut());
+            }
+            if self.callback_bag.recognizing_cb.is_some() {
+                recognizer_recognizing_set_callback(
+                    self.handle.inner(),
+                    None,
+                    std::ptr::null_mut(),
+                );
+            }
+            if self.callback_bag.recognized_cb.is_some() {
+                recognizer_recognized_set_callback(self.handle.inner(), None, std::ptr::null_mut());
+            }
+            if self.callback_bag.token_provider.is_some() {
+                recognizer_token_requested_set_callback(
+                    self.handle.inner(),
+                    None,
+                    std::ptr::null_mut(),
+                );
+            }
+        }
+    }
 }
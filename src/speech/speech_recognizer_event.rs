@@ -0,0 +1,16 @@
+use crate::speech::{
+    RecognitionEvent, SessionEvent, SpeechRecognitionCanceledEvent, SpeechRecognitionEvent,
+};
+
+/// Event yielded by stream returned from *SpeechRecognizer::events*.
+/// Each variant corresponds to one of the *SpeechRecognizer::set_*_cb* callbacks.
+#[derive(Debug)]
+pub enum SpeechRecognizerEvent {
+    SessionStarted(SessionEvent),
+    SessionStopped(SessionEvent),
+    SpeechStartDetected(RecognitionEvent),
+    SpeechEndDetected(RecognitionEvent),
+    Recognizing(SpeechRecognitionEvent),
+    Recognized(SpeechRecognitionEvent),
+    Canceled(SpeechRecognitionCanceledEvent),
+}
@@ -1,15 +1,17 @@
+use crate::common::Ticks;
 use crate::error::{convert_err, Result};
 use crate::ffi::{
     property_bag_free_string, synthesizer_bookmark_event_get_values, synthesizer_event_get_text,
     synthesizer_event_handle_release, SmartHandle, SPXEVENTHANDLE,
 };
 use std::ffi::CStr;
+use std::time::Duration;
 
 /// Event passed into speech synthetizer's callback set_synthesizer_bookmark_cb.
 #[derive(Debug)]
 pub struct SpeechSynthesisBookmarkEvent {
     pub handle: SmartHandle<SPXEVENTHANDLE>,
-    pub audio_offset: u64,
+    pub audio_offset: Duration,
     pub text: String,
 }
 
@@ -37,7 +39,7 @@ impl SpeechSynthesisBookmarkEvent {
                     handle,
                     synthesizer_event_handle_release,
                 ),
-                audio_offset,
+                audio_offset: Duration::from_ticks(audio_offset),
                 text,
             })
         }
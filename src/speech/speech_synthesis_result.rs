@@ -1,4 +1,4 @@
-use crate::common::{PropertyCollection, ResultReason};
+use crate::common::{PropertyCollection, PropertyId, ResultReason, SpeechBackend};
 use crate::error::{convert_err, Result};
 use crate::ffi::{
     synth_result_get_audio_data, synth_result_get_audio_length_duration,
@@ -10,6 +10,7 @@ use std::ffi::CStr;
 use std::fmt;
 use std::mem::MaybeUninit;
 use std::os::raw::c_char;
+use std::time::Duration;
 
 /// Represents speech synthetis result contained in SpeechSynthesisEvent callback event.
 pub struct SpeechSynthesisResult {
@@ -17,7 +18,7 @@ pub struct SpeechSynthesisResult {
     pub result_id: String,
     pub reason: ResultReason,
     pub audio_data: Vec<u8>,
-    pub audio_duration_ms: u64,
+    pub audio_duration: Duration,
     pub properties: PropertyCollection,
 }
 
@@ -108,10 +109,18 @@ impl SpeechSynthesisResult {
                 result_id,
                 reason: reason.into(),
                 audio_data: slice_buffer.to_vec(),
-                audio_duration_ms: audio_duration,
+                audio_duration: Duration::from_millis(audio_duration),
                 properties,
             };
             Ok(speech_synthesis_result)
         }
     }
+
+    /// Returns backend (cloud or embedded) which produced the result.
+    pub fn backend(&self) -> Result<SpeechBackend> {
+        let value = self
+            .properties
+            .get_property(PropertyId::SpeechServiceResponseSynthesisBackend, "")?;
+        Ok(SpeechBackend::from_property(&value))
+    }
 }
@@ -1,3 +1,4 @@
+use crate::common::Ticks;
 use crate::error::{convert_err, Result};
 use crate::ffi::{
     property_bag_free_string, synthesizer_event_handle_release,
@@ -5,12 +6,13 @@ use crate::ffi::{
     SPXEVENTHANDLE,
 };
 use std::ffi::CStr;
+use std::time::Duration;
 
 /// Event passed into speech synthetizer's callback set_synthesizer_viseme_cb.
 #[derive(Debug)]
 pub struct SpeechSynthesisVisemeEvent {
     pub handle: SmartHandle<SPXEVENTHANDLE>,
-    pub audio_offset: u64,
+    pub audio_offset: Duration,
     pub viseme_id: u32,
     pub animation: String,
 }
@@ -41,7 +43,7 @@ impl SpeechSynthesisVisemeEvent {
                     handle,
                     synthesizer_event_handle_release,
                 ),
-                audio_offset,
+                audio_offset: Duration::from_ticks(audio_offset),
                 viseme_id,
                 animation,
             })
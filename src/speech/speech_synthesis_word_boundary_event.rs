@@ -1,4 +1,4 @@
-use crate::common::SpeechSynthesisBoundaryType;
+use crate::common::{SpeechSynthesisBoundaryType, Ticks};
 use crate::error::{convert_err, Result};
 use crate::ffi::{
     property_bag_free_string, synthesizer_event_get_text, synthesizer_event_handle_release,
@@ -6,13 +6,14 @@ use crate::ffi::{
     SPXEVENTHANDLE,
 };
 use std::ffi::CStr;
+use std::time::Duration;
 
 /// Event passed into speech synthetizer's callback set_synthesizer_word_boundary_cb.
 #[derive(Debug)]
 pub struct SpeechSynthesisWordBoundaryEvent {
     pub handle: SmartHandle<SPXEVENTHANDLE>,
-    pub audio_offset: u64,
-    pub duration_ms: u64,
+    pub audio_offset: Duration,
+    pub duration: Duration,
     pub text_offset: u32,
     pub word_length: u32,
     pub boundary_type: SpeechSynthesisBoundaryType,
@@ -25,14 +26,14 @@ impl SpeechSynthesisWordBoundaryEvent {
     pub unsafe fn from_handle(handle: SPXEVENTHANDLE) -> Result<Self> {
         unsafe {
             let mut audio_offset: u64 = 0;
-            let mut duration_ms: u64 = 0;
+            let mut duration: u64 = 0;
             let mut text_offset: u32 = 0;
             let mut word_length: u32 = 0;
             let mut boundary_type: SpeechSynthesis_BoundaryType = 0;
             let ret = synthesizer_word_boundary_event_get_values(
                 handle,
                 &mut audio_offset,
-                &mut duration_ms,
+                &mut duration,
                 &mut text_offset,
                 &mut word_length,
                 &mut boundary_type,
@@ -53,8 +54,8 @@ impl SpeechSynthesisWordBoundaryEvent {
                     handle,
                     synthesizer_event_handle_release,
                 ),
-                audio_offset,
-                duration_ms,
+                audio_offset: Duration::from_ticks(audio_offset),
+                duration: Duration::from_ticks(duration),
                 text_offset,
                 word_length,
                 boundary_type: boundary_type.into(),
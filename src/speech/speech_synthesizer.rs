@@ -1,22 +1,30 @@
 use crate::audio::AudioConfig;
-use crate::common::{PropertyCollection, PropertyId};
+use crate::common::{
+    apply_token, event_channel, EventBuffering, EventStream, PropertyCollection, PropertyId,
+    TokenProvider,
+};
 use crate::error::{convert_err, Result};
 use crate::ffi::{
-    synthesizer_bookmark_reached_set_callback, synthesizer_canceled_set_callback,
-    synthesizer_completed_set_callback,
+    synthesizer_async_handle_release, synthesizer_bookmark_reached_set_callback,
+    synthesizer_canceled_set_callback, synthesizer_completed_set_callback,
     synthesizer_create_speech_synthesizer_from_auto_detect_source_lang_config,
-    synthesizer_create_speech_synthesizer_from_config, synthesizer_get_property_bag,
-    synthesizer_get_voices_list, synthesizer_handle_release, synthesizer_speak_ssml,
-    synthesizer_speak_text, synthesizer_start_speaking_request, synthesizer_start_speaking_ssml,
-    synthesizer_start_speaking_text, synthesizer_started_set_callback, synthesizer_stop_speaking,
-    synthesizer_synthesizing_set_callback, synthesizer_viseme_received_set_callback,
-    synthesizer_word_boundary_set_callback, SmartHandle, SPXEVENTHANDLE, SPXPROPERTYBAGHANDLE,
+    synthesizer_create_speech_synthesizer_from_config, synthesizer_event_handle_release,
+    synthesizer_get_property_bag, synthesizer_get_voices_list_async,
+    synthesizer_get_voices_list_async_wait_for, synthesizer_handle_release,
+    synthesizer_speak_async_wait_for, synthesizer_speak_ssml_async, synthesizer_speak_text_async,
+    synthesizer_start_speaking_request, synthesizer_start_speaking_ssml_async,
+    synthesizer_start_speaking_text_async, synthesizer_started_set_callback,
+    synthesizer_stop_speaking_async, synthesizer_stop_speaking_async_wait_for,
+    synthesizer_synthesizing_set_callback, synthesizer_token_request_set_callback,
+    synthesizer_viseme_received_set_callback, synthesizer_word_boundary_set_callback,
+    wait_for_async, SmartHandle, SPXASYNCHANDLE, SPXEVENTHANDLE, SPXPROPERTYBAGHANDLE,
     SPXRESULTHANDLE, SPXSYNTHHANDLE,
 };
 use crate::speech::{
-    AutoDetectSourceLanguageConfig, SpeechConfig, SpeechSynthesisBookmarkEvent,
+    AutoDetectSourceLanguageConfig, HybridSpeechConfig, SpeechConfig, SpeechSynthesisBookmarkEvent,
     SpeechSynthesisEvent, SpeechSynthesisRequest, SpeechSynthesisResult,
-    SpeechSynthesisVisemeEvent, SpeechSynthesisWordBoundaryEvent, SynthesisVoicesResult,
+    SpeechSynthesisVisemeEvent, SpeechSynthesisWordBoundaryEvent, SpeechSynthesizerEvent,
+    SynthesisVoicesResult,
 };
 use log::*;
 use std::boxed::Box;
@@ -37,11 +45,12 @@ struct CallbackBag {
     synthesizer_word_boundary_cb: Option<Box<dyn Fn(SpeechSynthesisWordBoundaryEvent) + Send>>,
     synthesizer_viseme_cb: Option<Box<dyn Fn(SpeechSynthesisVisemeEvent) + Send>>,
     synthesizer_bookmark_cb: Option<Box<dyn Fn(SpeechSynthesisBookmarkEvent) + Send>>,
+    token_provider: Option<Box<dyn TokenProvider>>,
 }
 
 /// SpeechSynthesizer struct holds functionality for text-to-speech synthesis.
 pub struct SpeechSynthesizer {
-    handle: SmartHandle<SPXSYNTHHANDLE>,
+    pub(crate) handle: SmartHandle<SPXSYNTHHANDLE>,
     properties: PropertyCollection,
     callback_bag: Box<CallbackBag>,
 }
@@ -90,6 +99,7 @@ impl SpeechSynthesizer {
                     synthesizer_word_boundary_cb: None,
                     synthesizer_viseme_cb: None,
                     synthesizer_bookmark_cb: None,
+                    token_provider: None,
                 }),
             })
         }
@@ -110,6 +120,15 @@ impl SpeechSynthesizer {
         }
     }
 
+    /// Creates speech synthesizer which uses the speech service when it is reachable
+    /// and falls back to embedded voices otherwise.
+    pub fn from_hybrid_config(
+        speech_config: HybridSpeechConfig,
+        audio_config: AudioConfig,
+    ) -> Result<Self> {
+        SpeechSynthesizer::from_config(speech_config.into(), audio_config)
+    }
+
     pub fn from_optional_audio_config(
         speech_config: SpeechConfig,
         audio_config: Option<AudioConfig>,
@@ -150,72 +169,110 @@ impl SpeechSynthesizer {
 
     /// Executes the speech synthesis on plain text, asynchronously.
     pub async fn speak_text_async(&self, text: &str) -> Result<SpeechSynthesisResult> {
-        unsafe {
+        let handle_async = unsafe {
             let c_text = CString::new(text)?;
             let text_len = c_text.as_bytes().len();
-            let mut result_handle: MaybeUninit<SPXRESULTHANDLE> = MaybeUninit::uninit();
-            let ret = synthesizer_speak_text(
+            let mut handle_async: MaybeUninit<SPXASYNCHANDLE> = MaybeUninit::uninit();
+            let ret = synthesizer_speak_text_async(
                 self.handle.inner(),
                 c_text.as_ptr(),
                 text_len as u32,
-                result_handle.as_mut_ptr(),
+                handle_async.as_mut_ptr(),
             );
             convert_err(ret, "SpeechSynthesizer.speak_text_async error")?;
-            SpeechSynthesisResult::from_handle(result_handle.assume_init())
-        }
+            SmartHandle::create(
+                "handle_async_speak_text",
+                handle_async.assume_init(),
+                synthesizer_async_handle_release,
+            )
+        };
+        wait_for_async(handle_async, Self::speak_async_wait_for).await
     }
 
     /// Executes the speech synthesis on SSML, asynchronously.
     pub async fn speak_ssml_async(&self, ssml: &str) -> Result<SpeechSynthesisResult> {
-        unsafe {
+        let handle_async = unsafe {
             let c_ssml = CString::new(ssml)?;
             let ssml_len = c_ssml.as_bytes().len();
-            let mut result_handle: MaybeUninit<SPXRESULTHANDLE> = MaybeUninit::uninit();
-            let ret = synthesizer_speak_ssml(
+            let mut handle_async: MaybeUninit<SPXASYNCHANDLE> = MaybeUninit::uninit();
+            let ret = synthesizer_speak_ssml_async(
                 self.handle.inner(),
                 c_ssml.as_ptr(),
                 ssml_len as u32,
-                result_handle.as_mut_ptr(),
+                handle_async.as_mut_ptr(),
             );
             convert_err(ret, "SpeechSynthesizer.speak_ssml_async error")?;
-            SpeechSynthesisResult::from_handle(result_handle.assume_init())
-        }
+            SmartHandle::create(
+                "handle_async_speak_ssml",
+                handle_async.assume_init(),
+                synthesizer_async_handle_release,
+            )
+        };
+        wait_for_async(handle_async, Self::speak_async_wait_for).await
     }
 
     /// Starts the speech synthesis on plain text, asynchronously.
     /// It returns when the synthesis request is started to process
     /// (the result reason is SynthesizingAudioStarted).
     pub async fn start_speaking_text_async(&self, text: &str) -> Result<SpeechSynthesisResult> {
-        unsafe {
+        let handle_async = unsafe {
             let c_text = CString::new(text)?;
             let text_len = c_text.as_bytes().len();
-            let mut result_handle: MaybeUninit<SPXRESULTHANDLE> = MaybeUninit::uninit();
-            let ret = synthesizer_start_speaking_text(
+            let mut handle_async: MaybeUninit<SPXASYNCHANDLE> = MaybeUninit::uninit();
+            let ret = synthesizer_start_speaking_text_async(
                 self.handle.inner(),
                 c_text.as_ptr(),
                 text_len as u32,
-                result_handle.as_mut_ptr(),
+                handle_async.as_mut_ptr(),
             );
             convert_err(ret, "SpeechSynthesizer.start_speaking_text_async error")?;
-            SpeechSynthesisResult::from_handle(result_handle.assume_init())
-        }
+            SmartHandle::create(
+                "handle_async_start_speaking_text",
+                handle_async.assume_init(),
+                synthesizer_async_handle_release,
+            )
+        };
+        wait_for_async(handle_async, Self::speak_async_wait_for).await
     }
 
     /// Starts the speech synthesis on SSML, asynchronously.
     /// It returns when the synthesis request is started to process
     ///(the result reason is SynthesizingAudioStarted).
     pub async fn start_speaking_ssml_async(&self, ssml: &str) -> Result<SpeechSynthesisResult> {
-        unsafe {
+        let handle_async = unsafe {
             let c_ssml = CString::new(ssml)?;
             let ssml_len = c_ssml.as_bytes().len();
-            let mut result_handle: MaybeUninit<SPXRESULTHANDLE> = MaybeUninit::uninit();
-            let ret = synthesizer_start_speaking_ssml(
+            let mut handle_async: MaybeUninit<SPXASYNCHANDLE> = MaybeUninit::uninit();
+            let ret = synthesizer_start_speaking_ssml_async(
                 self.handle.inner(),
                 c_ssml.as_ptr(),
                 ssml_len as u32,
-                result_handle.as_mut_ptr(),
+                handle_async.as_mut_ptr(),
             );
             convert_err(ret, "SpeechSynthesizer.start_speaking_ssml_async error")?;
+            SmartHandle::create(
+                "handle_async_start_speaking_ssml",
+                handle_async.assume_init(),
+                synthesizer_async_handle_release,
+            )
+        };
+        wait_for_async(handle_async, Self::speak_async_wait_for).await
+    }
+
+    /// Waits (blocking) for speak/start speaking async operation
+    /// and converts native result handle into SpeechSynthesisResult.
+    fn speak_async_wait_for(handle_async: SPXASYNCHANDLE) -> Result<SpeechSynthesisResult> {
+        unsafe {
+            let mut result_handle: MaybeUninit<SPXRESULTHANDLE> = MaybeUninit::uninit();
+            let ret = synthesizer_speak_async_wait_for(
+                handle_async,
+                u32::MAX,
+                result_handle.as_mut_ptr(),
+            );
+            convert_err(
+                ret,
+                "SpeechSynthesizer.synthesizer_speak_async_wait_for error",
+            )?;
             SpeechSynthesisResult::from_handle(result_handle.assume_init())
         }
     }
@@ -243,27 +300,59 @@ impl SpeechSynthesizer {
     /// Stops the speech synthesis, asynchronously.
     /// It stops audio speech synthesis and discards any unread data in audio.PullAudioOutputStream.
     pub async fn stop_speaking_async(&self) -> Result<()> {
-        unsafe {
-            let ret = synthesizer_stop_speaking(self.handle.inner());
+        let handle_async = unsafe {
+            let mut handle_async: MaybeUninit<SPXASYNCHANDLE> = MaybeUninit::uninit();
+            let ret =
+                synthesizer_stop_speaking_async(self.handle.inner(), handle_async.as_mut_ptr());
             convert_err(ret, "SpeechSynthesizer.stop_speaking_async error")?;
-            Ok(())
-        }
+            SmartHandle::create(
+                "handle_async_stop_speaking",
+                handle_async.assume_init(),
+                synthesizer_async_handle_release,
+            )
+        };
+        wait_for_async(handle_async, |handle_async| unsafe {
+            let ret = synthesizer_stop_speaking_async_wait_for(handle_async, u32::MAX);
+            convert_err(
+                ret,
+                "SpeechSynthesizer.synthesizer_stop_speaking_async_wait_for error",
+            )
+        })
+        .await
     }
 
     /// Gets the available voices, asynchronously.
     /// The parameter locale specifies the locale of voices, in BCP-47 format; or leave it empty to get all available voices.
     pub async fn get_voices_async(&self, locale: &str) -> Result<SynthesisVoicesResult> {
-        unsafe {
+        let handle_async = unsafe {
             let c_locale_str = CString::new(locale)?;
-            let mut v_result: MaybeUninit<SPXRESULTHANDLE> = MaybeUninit::uninit();
-            let ret = synthesizer_get_voices_list(
+            let mut handle_async: MaybeUninit<SPXASYNCHANDLE> = MaybeUninit::uninit();
+            let ret = synthesizer_get_voices_list_async(
                 self.handle.inner(),
                 c_locale_str.as_ptr(),
-                v_result.as_mut_ptr(),
+                handle_async.as_mut_ptr(),
             );
             convert_err(ret, "SpeechSynthesizer.get_voices_async error")?;
+            SmartHandle::create(
+                "handle_async_get_voices",
+                handle_async.assume_init(),
+                synthesizer_async_handle_release,
+            )
+        };
+        wait_for_async(handle_async, |handle_async| unsafe {
+            let mut v_result: MaybeUninit<SPXRESULTHANDLE> = MaybeUninit::uninit();
+            let ret = synthesizer_get_voices_list_async_wait_for(
+                handle_async,
+                u32::MAX,
+                v_result.as_mut_ptr(),
+            );
+            convert_err(
+                ret,
+                "SpeechSynthesizer.synthesizer_get_voices_list_async_wait_for error",
+            )?;
             SynthesisVoicesResult::from_handle(v_result.assume_init())
-        }
+        })
+        .await
     }
 
     pub fn get_auth_token(&self) -> Result<String> {
@@ -280,6 +369,96 @@ impl SpeechSynthesizer {
             .set_property(PropertyId::SpeechServiceAuthorizationToken, token)
     }
 
+    /// Sets authorization token provider. Token is fetched from the provider right away
+    /// and then whenever the native layer requests a new one, so that long running
+    /// synthesizer survives token rotation without calling *set_auth_token* periodically.
+    pub fn set_token_provider<P>(&mut self, provider: P) -> Result<()>
+    where
+        P: TokenProvider + 'static,
+    {
+        apply_token(&provider, &mut self.properties)?;
+        self.callback_bag.token_provider = Some(Box::new(provider));
+        unsafe {
+            let ret = synthesizer_token_request_set_callback(
+                self.handle.inner(),
+                Some(Self::cb_token_requested),
+                &*self.callback_bag as *const _ as *mut c_void,
+            );
+            convert_err(ret, "SpeechSynthesizer.set_token_provider error")?;
+            Ok(())
+        }
+    }
+
+    #[allow(non_snake_case)]
+    #[allow(unused_variables)]
+    unsafe extern "C" fn cb_token_requested(
+        hsynth: SPXSYNTHHANDLE,
+        hevent: SPXEVENTHANDLE,
+        pvContext: *mut c_void,
+    ) {
+        trace!("SpeechSynthesizer::cb_token_requested called");
+        // event carries no data, just make sure it gets released
+        let _event = SmartHandle::create(
+            "TokenRequestedEvent",
+            hevent,
+            synthesizer_event_handle_release,
+        );
+        let callback_bag = &mut *(pvContext as *mut CallbackBag);
+        if let Some(provider) = &callback_bag.token_provider {
+            let mut prop_bag_handle: MaybeUninit<SPXPROPERTYBAGHANDLE> = MaybeUninit::uninit();
+            let ret = synthesizer_get_property_bag(hsynth, prop_bag_handle.as_mut_ptr());
+            let result =
+                convert_err(ret, "SpeechSynthesizer::cb_token_requested error").and_then(|_| {
+                    let mut properties =
+                        PropertyCollection::from_handle(prop_bag_handle.assume_init());
+                    apply_token(provider.as_ref(), &mut properties)
+                });
+            if let Err(err) = result {
+                error!("SpeechSynthesizer::cb_token_requested error {:?}", err);
+            }
+        }
+    }
+
+    /// Returns stream of all synthesizer events so that they can be consumed
+    /// from async code instead of registering individual callbacks.
+    /// Replaces callbacks previously set via *set_*_cb* methods.
+    /// Stream ends when the synthesizer is dropped.
+    pub fn events(
+        &mut self,
+        buffering: EventBuffering,
+    ) -> Result<EventStream<SpeechSynthesizerEvent>> {
+        let (sender, stream) = event_channel(buffering);
+        let tx = sender.clone();
+        self.set_synthesizer_started_cb(move |event| {
+            tx.send(SpeechSynthesizerEvent::SynthesisStarted(event))
+        })?;
+        let tx = sender.clone();
+        self.set_synthesizer_synthesizing_cb(move |event| {
+            tx.send(SpeechSynthesizerEvent::Synthesizing(event))
+        })?;
+        let tx = sender.clone();
+        self.set_synthesizer_completed_cb(move |event| {
+            tx.send(SpeechSynthesizerEvent::SynthesisCompleted(event))
+        })?;
+        let tx = sender.clone();
+        self.set_synthesizer_canceled_cb(move |event| {
+            tx.send(SpeechSynthesizerEvent::SynthesisCanceled(event))
+        })?;
+        let tx = sender.clone();
+        self.set_synthesizer_word_boundary_cb(move |event| {
+            tx.send(SpeechSynthesizerEvent::WordBoundary(event))
+        })?;
+        let tx = sender.clone();
+        self.set_synthesizer_viseme_cb(move |event| {
+            tx.send(SpeechSynthesizerEvent::Viseme(event))
+        })?;
+        let tx = sender;
+        self.set_synthesizer_bookmark_cb(move |event| {
+            tx.send(SpeechSynthesizerEvent::Bookmark(event))
+        })?;
+        Ok(stream)
+    }
+
     pub fn set_synthesizer_started_cb<F>(&mut self, f: F) -> Result<()>
     where
         F: Fn(SpeechSynthesisEvent) + 'static + Send,
@@ -580,3 +759,56 @@ impl SpeechSynthesizer {
         }
     }
 }
+
+/// Native synthesizer can outlive this struct when future of pending synthesis
+/// is dropped, so registered callbacks are disconnected before callback bag is freed.
+impl Drop for SpeechSynthesizer {
+    fn drop(&mut self) {
+        unsafe {
+            if self.callback_bag.token_provider.is_some() {
+                synthesizer_token_request_set_callback(
+                    self.handle.inner(),
+                    None,
+                    std::ptr::null_mut(),
+                );
+            }
+            if self.callback_bag.synthesizer_started_cb.is_some() {
+                synthesizer_started_set_callback(self.handle.inner(), None, std::ptr::null_mut());
+            }
+            if self.callback_bag.synthesizer_synthesizing_cb.is_some() {
+                synthesizer_synthesizing_set_callback(
+                    self.handle.inner(),
+                    None,
+                    std::ptr::null_mut(),
+                );
+            }
+            if self.callback_bag.synthesizer_completed_cb.is_some() {
+                synthesizer_completed_set_callback(self.handle.inner(), None, std::ptr::null_mut());
+            }
+            if self.callback_bag.synthesizer_canceled_cb.is_some() {
+                synthesizer_canceled_set_callback(self.handle.inner(), None, std::ptr::null_mut());
+            }
+            if self.callback_bag.synthesizer_word_boundary_cb.is_some() {
+                synthesizer_word_boundary_set_callback(
+                    self.handle.inner(),
+                    None,
+                    std::ptr::null_mut(),
+                );
+            }
+            if self.callback_bag.synthesizer_viseme_cb.is_some() {
+                synthesizer_viseme_received_set_callback(
+                    self.handle.inner(),
+                    None,
+                    std::ptr::null_mut(),
+                );
+            }
+            if self.callback_bag.synthesizer_bookmark_cb.is_some() {
+                synthesizer_bookmark_reached_set_callback(
+                    self.handle.inner(),
+                    None,
+                    std::ptr::null_mut(),
+                );
+            }
+        }
+    }
+}
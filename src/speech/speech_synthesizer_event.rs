@@ -0,0 +1,17 @@
+use crate::speech::{
+    SpeechSynthesisBookmarkEvent, SpeechSynthesisEvent, SpeechSynthesisVisemeEvent,
+    SpeechSynthesisWordBoundaryEvent,
+};
+
+/// Event yielded by stream returned from *SpeechSynthesizer::events*.
+/// Each variant corresponds to one of the *SpeechSynthesizer::set_synthesizer_*_cb* callbacks.
+#[derive(Debug)]
+pub enum SpeechSynthesizerEvent {
+    SynthesisStarted(SpeechSynthesisEvent),
+    Synthesizing(SpeechSynthesisEvent),
+    SynthesisCompleted(SpeechSynthesisEvent),
+    SynthesisCanceled(SpeechSynthesisEvent),
+    WordBoundary(SpeechSynthesisWordBoundaryEvent),
+    Viseme(SpeechSynthesisVisemeEvent),
+    Bookmark(SpeechSynthesisBookmarkEvent),
+}
@@ -0,0 +1,27 @@
+//! Package transcription provides functionality for conversation transcription
+//! (speech-to-text with speaker diarization) and meeting transcription
+//! (speech-to-text attributed to meeting participants) along with related result and event objects.
+mod conversation_transcriber;
+mod conversation_transcription_canceled_event;
+mod conversation_transcription_event;
+mod conversation_transcription_result;
+mod meeting;
+mod meeting_transcriber;
+mod meeting_transcription_canceled_event;
+mod meeting_transcription_event;
+mod meeting_transcription_result;
+mod participant;
+mod user;
+
+// re-export structs directly under transcription module
+pub use self::conversation_transcriber::ConversationTranscriber;
+pub use self::conversation_transcription_canceled_event::ConversationTranscriptionCanceledEvent;
+pub use self::conversation_transcription_event::ConversationTranscriptionEvent;
+pub use self::conversation_transcription_result::ConversationTranscriptionResult;
+pub use self::meeting::Meeting;
+pub use self::meeting_transcriber::MeetingTranscriber;
+pub use self::meeting_transcription_canceled_event::MeetingTranscriptionCanceledEvent;
+pub use self::meeting_transcription_event::MeetingTranscriptionEvent;
+pub use self::meeting_transcription_result::MeetingTranscriptionResult;
+pub use self::participant::Participant;
+pub use self::user::User;
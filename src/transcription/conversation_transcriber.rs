@@ -0,0 +1,596 @@
+use crate::audio::AudioConfig;
+use crate::common::{PropertyCollection, PropertyId};
+use crate::error::{convert_err, Result};
+use crate::ffi::{
+    recognizer_async_handle_release, recognizer_canceled_set_callback,
+    recognizer_create_conversation_transcriber_from_auto_detect_source_lang_config,
+    recognizer_create_conversation_transcriber_from_config,
+    recognizer_create_conversation_transcriber_from_source_lang_config,
+    recognizer_get_property_bag, recognizer_handle_release, recognizer_recognized_set_callback,
+    recognizer_recognizing_set_callback, recognizer_session_started_set_callback,
+    recognizer_session_stopped_set_callback, recognizer_speech_end_detected_set_callback,
+    recognizer_speech_start_detected_set_callback, recognizer_start_continuous_recognition_async,
+    recognizer_start_continuous_recognition_async_wait_for,
+    recognizer_stop_continuous_recognition_async,
+    recognizer_stop_continuous_recognition_async_wait_for, wait_for_async, SmartHandle,
+    SPXASYNCHANDLE, SPXEVENTHANDLE, SPXPROPERTYBAGHANDLE, SPXRECOHANDLE,
+};
+use crate::speech::{
+    AutoDetectSourceLanguageConfig, RecognitionEvent, Recognizer, SealedRecognizer, SessionEvent,
+    SourceLanguageConfig, SpeechConfig,
+};
+use crate::transcription::{
+    ConversationTranscriptionCanceledEvent, ConversationTranscriptionEvent,
+};
+use log::*;
+use std::boxed::Box;
+use std::fmt;
+use std::mem::MaybeUninit;
+use std::os::raw::c_void;
+
+/// A separate internal struct to hold all the callback closures for the conversation transcriber events.
+/// By creating a separate struct, and then boxing this struct inside our ConversationTranscriber,
+/// we can ensure the ConversationTranscriber itself can be moved freely by end users,
+/// and the callbacks will remain at a fixed memory address on the heap.
+struct CallbackBag {
+    session_started_cb: Option<Box<dyn Fn(SessionEvent) + Send>>,
+    session_stopped_cb: Option<Box<dyn Fn(SessionEvent) + Send>>,
+    speech_start_detected_cb: Option<Box<dyn Fn(RecognitionEvent) + Send>>,
+    speech_end_detected_cb: Option<Box<dyn Fn(RecognitionEvent) + Send>>,
+    canceled_cb: Option<Box<dyn Fn(ConversationTranscriptionCanceledEvent) + Send>>,
+    transcribing_cb: Option<Box<dyn Fn(ConversationTranscriptionEvent) + Send>>,
+    transcribed_cb: Option<Box<dyn Fn(ConversationTranscriptionEvent) + Send>>,
+}
+
+/// ConversationTranscriber struct holds functionality for real-time conversation transcription
+/// with speaker diarization, i.e. each transcribed phrase is attributed to a speaker.
+pub struct ConversationTranscriber {
+    pub(crate) handle: SmartHandle<SPXRECOHANDLE>,
+    properties: PropertyCollection,
+    callback_bag: Box<CallbackBag>,
+}
+
+impl fmt::Debug for ConversationTranscriber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ConversationTranscriber")
+            .field("handle", &self.handle)
+            .field("properties", &self.properties)
+            .finish()
+    }
+}
+
+impl SealedRecognizer for ConversationTranscriber {}
+
+impl Recognizer for ConversationTranscriber {
+    fn get_handle(&self) -> SPXRECOHANDLE {
+        self.handle.inner()
+    }
+}
+
+impl ConversationTranscriber {
+    /// # Safety
+    /// `handle` must be a valid handle to a live conversation transcriber.
+    unsafe fn from_handle(handle: SPXRECOHANDLE) -> Result<ConversationTranscriber> {
+        unsafe {
+            let mut prop_bag_handle: MaybeUninit<SPXPROPERTYBAGHANDLE> = MaybeUninit::uninit();
+            let ret = recognizer_get_property_bag(handle, prop_bag_handle.as_mut_ptr());
+            convert_err(ret, "ConversationTranscriber::from_handle error")?;
+
+            let property_bag = PropertyCollection::from_handle(prop_bag_handle.assume_init());
+
+            let result = ConversationTranscriber {
+                handle: SmartHandle::create(
+                    "ConversationTranscriber",
+                    handle,
+                    recognizer_handle_release,
+                ),
+                properties: property_bag,
+                callback_bag: Box::new(CallbackBag {
+                    session_started_cb: None,
+                    session_stopped_cb: None,
+                    speech_start_detected_cb: None,
+                    speech_end_detected_cb: None,
+                    canceled_cb: None,
+                    transcribing_cb: None,
+                    transcribed_cb: None,
+                }),
+            };
+            Ok(result)
+        }
+    }
+
+    pub fn from_config(
+        speech_config: SpeechConfig,
+        audio_config: AudioConfig,
+    ) -> Result<ConversationTranscriber> {
+        unsafe {
+            let mut handle: MaybeUninit<SPXRECOHANDLE> = MaybeUninit::uninit();
+            convert_err(
+                recognizer_create_conversation_transcriber_from_config(
+                    handle.as_mut_ptr(),
+                    speech_config.handle.inner(),
+                    audio_config.handle.inner(),
+                ),
+                "ConversationTranscriber.from_config error",
+            )?;
+            ConversationTranscriber::from_handle(handle.assume_init())
+        }
+    }
+
+    pub fn from_auto_detect_source_lang_config(
+        speech_config: SpeechConfig,
+        audio_config: AudioConfig,
+        lang_config: AutoDetectSourceLanguageConfig,
+    ) -> Result<ConversationTranscriber> {
+        unsafe {
+            let mut handle: MaybeUninit<SPXRECOHANDLE> = MaybeUninit::uninit();
+            convert_err(
+                recognizer_create_conversation_transcriber_from_auto_detect_source_lang_config(
+                    handle.as_mut_ptr(),
+                    speech_config.handle.inner(),
+                    lang_config.handle.inner(),
+                    audio_config.handle.inner(),
+                ),
+                "ConversationTranscriber.from_auto_detect_source_lang_config error",
+            )?;
+            ConversationTranscriber::from_handle(handle.assume_init())
+        }
+    }
+
+    pub fn from_source_lang_config(
+        speech_config: SpeechConfig,
+        audio_config: AudioConfig,
+        source_lang_config: SourceLanguageConfig,
+    ) -> Result<ConversationTranscriber> {
+        unsafe {
+            let mut handle: MaybeUninit<SPXRECOHANDLE> = MaybeUninit::uninit();
+            convert_err(
+                recognizer_create_conversation_transcriber_from_source_lang_config(
+                    handle.as_mut_ptr(),
+                    speech_config.handle.inner(),
+                    source_lang_config.handle.inner(),
+                    audio_config.handle.inner(),
+                ),
+                "ConversationTranscriber.from_source_lang_config error",
+            )?;
+            ConversationTranscriber::from_handle(handle.assume_init())
+        }
+    }
+
+    pub fn from_source_lang(
+        speech_config: SpeechConfig,
+        audio_config: AudioConfig,
+        source_lang: &str,
+    ) -> Result<ConversationTranscriber> {
+        let source_lang_config = SourceLanguageConfig::from_language(source_lang)?;
+        ConversationTranscriber::from_source_lang_config(
+            speech_config,
+            audio_config,
+            source_lang_config,
+        )
+    }
+
+    pub fn set_session_started_cb<F>(&mut self, f: F) -> Result<()>
+    where
+        F: Fn(SessionEvent) + 'static + Send,
+    {
+        self.callback_bag.session_started_cb = Some(Box::new(f));
+        unsafe {
+            let ret = recognizer_session_started_set_callback(
+                self.handle.inner(),
+                Some(Self::cb_session_started),
+                &*self.callback_bag as *const _ as *mut c_void,
+            );
+            convert_err(ret, "ConversationTranscriber.set_session_started_cb error")?;
+            Ok(())
+        }
+    }
+
+    pub fn set_session_stopped_cb<F>(&mut self, f: F) -> Result<()>
+    where
+        F: Fn(SessionEvent) + 'static + Send,
+    {
+        self.callback_bag.session_stopped_cb = Some(Box::new(f));
+        unsafe {
+            let ret = recognizer_session_stopped_set_callback(
+                self.handle.inner(),
+                Some(Self::cb_session_stopped),
+                &*self.callback_bag as *const _ as *mut c_void,
+            );
+            convert_err(ret, "ConversationTranscriber.set_session_stopped_cb error")?;
+            Ok(())
+        }
+    }
+
+    pub fn set_speech_start_detected_cb<F>(&mut self, f: F) -> Result<()>
+    where
+        F: Fn(RecognitionEvent) + 'static + Send,
+    {
+        self.callback_bag.speech_start_detected_cb = Some(Box::new(f));
+        unsafe {
+            let ret = recognizer_speech_start_detected_set_callback(
+                self.handle.inner(),
+                Some(Self::cb_speech_start_detected),
+                &*self.callback_bag as *const _ as *mut c_void,
+            );
+            convert_err(
+                ret,
+                "ConversationTranscriber.set_speech_start_detected_cb error",
+            )?;
+            Ok(())
+        }
+    }
+
+    pub fn set_speech_end_detected_cb<F>(&mut self, f: F) -> Result<()>
+    where
+        F: Fn(RecognitionEvent) + 'static + Send,
+    {
+        self.callback_bag.speech_end_detected_cb = Some(Box::new(f));
+        unsafe {
+            let ret = recognizer_speech_end_detected_set_callback(
+                self.handle.inner(),
+                Some(Self::cb_speech_end_detected),
+                &*self.callback_bag as *const _ as *mut c_void,
+            );
+            convert_err(
+                ret,
+                "ConversationTranscriber.set_speech_end_detected_cb error",
+            )?;
+            Ok(())
+        }
+    }
+
+    /// Canceled signals for events containing canceled transcription results
+    /// (indicating a transcription attempt that was canceled as a result or a direct cancellation request
+    /// or, alternatively, a transport or protocol failure).
+    pub fn set_canceled_cb<F>(&mut self, f: F) -> Result<()>
+    where
+        F: Fn(ConversationTranscriptionCanceledEvent) + 'static + Send,
+    {
+        self.callback_bag.canceled_cb = Some(Box::new(f));
+        unsafe {
+            let ret = recognizer_canceled_set_callback(
+                self.handle.inner(),
+                Some(Self::cb_canceled),
+                &*self.callback_bag as *const _ as *mut c_void,
+            );
+            convert_err(ret, "ConversationTranscriber.set_canceled_cb error")?;
+            Ok(())
+        }
+    }
+
+    /// Transcribing signals for events containing intermediate transcription results.
+    pub fn set_transcribing_cb<F>(&mut self, f: F) -> Result<()>
+    where
+        F: Fn(ConversationTranscriptionEvent) + 'static + Send,
+    {
+        self.callback_bag.transcribing_cb = Some(Box::new(f));
+        unsafe {
+            let ret = recognizer_recognizing_set_callback(
+                self.handle.inner(),
+                Some(Self::cb_transcribing),
+                &*self.callback_bag as *const _ as *mut c_void,
+            );
+            convert_err(ret, "ConversationTranscriber.set_transcribing_cb error")?;
+            Ok(())
+        }
+    }
+
+    /// Transcribed signals for events containing final transcription results
+    /// (indicating a successful transcription attempt) including identifier of the speaker.
+    pub fn set_transcribed_cb<F>(&mut self, f: F) -> Result<()>
+    where
+        F: Fn(ConversationTranscriptionEvent) + 'static + Send,
+    {
+        self.callback_bag.transcribed_cb = Some(Box::new(f));
+        unsafe {
+            let ret = recognizer_recognized_set_callback(
+                self.handle.inner(),
+                Some(Self::cb_transcribed),
+                &*self.callback_bag as *const _ as *mut c_void,
+            );
+            convert_err(ret, "ConversationTranscriber.set_transcribed_cb error")?;
+            Ok(())
+        }
+    }
+
+    #[allow(non_snake_case)]
+    #[allow(unused_variables)]
+    unsafe extern "C" fn cb_session_started(
+        hreco: SPXRECOHANDLE,
+        hevent: SPXEVENTHANDLE,
+        pvContext: *mut c_void,
+    ) {
+        trace!("ConversationTranscriber::cb_session_started called");
+        let callback_bag = &mut *(pvContext as *mut CallbackBag);
+        if let Some(cb) = &callback_bag.session_started_cb {
+            trace!("session_started_cb defined");
+            match SessionEvent::from_handle(hevent) {
+                Ok(event) => {
+                    trace!("calling cb with event {:?}", event);
+                    cb(event);
+                }
+                Err(err) => {
+                    error!(
+                        "ConversationTranscriber::cb_session_started error {:?}",
+                        err
+                    );
+                }
+            }
+        }
+    }
+
+    #[allow(non_snake_case)]
+    #[allow(unused_variables)]
+    unsafe extern "C" fn cb_session_stopped(
+        hreco: SPXRECOHANDLE,
+        hevent: SPXEVENTHANDLE,
+        pvContext: *mut c_void,
+    ) {
+        trace!("ConversationTranscriber::cb_session_stopped called");
+        let callback_bag = &mut *(pvContext as *mut CallbackBag);
+        if let Some(cb) = &callback_bag.session_stopped_cb {
+            trace!("session_stopped_cb defined");
+            match SessionEvent::from_handle(hevent) {
+                Ok(event) => {
+                    trace!("calling cb with event {:?}", event);
+                    cb(event);
+                }
+                Err(err) => {
+                    error!(
+                        "ConversationTranscriber::cb_session_stopped error {:?}",
+                        err
+                    );
+                }
+            }
+        }
+    }
+
+    #[allow(non_snake_case)]
+    #[allow(unused_variables)]
+    unsafe extern "C" fn cb_speech_start_detected(
+        hreco: SPXRECOHANDLE,
+        hevent: SPXEVENTHANDLE,
+        pvContext: *mut c_void,
+    ) {
+        trace!("ConversationTranscriber::cb_speech_start_detected called");
+        let callback_bag = &mut *(pvContext as *mut CallbackBag);
+        if let Some(cb) = &callback_bag.speech_start_detected_cb {
+            trace!("speech_start_detected_cb defined");
+            match RecognitionEvent::from_handle(hevent) {
+                Ok(event) => {
+                    trace!("calling cb with event {:?}", event);
+                    cb(event);
+                }
+                Err(err) => {
+                    error!(
+                        "ConversationTranscriber::cb_speech_start_detected error {:?}",
+                        err
+                    );
+                }
+            }
+        }
+    }
+
+    #[allow(non_snake_case)]
+    #[allow(unused_variables)]
+    unsafe extern "C" fn cb_speech_end_detected(
+        hreco: SPXRECOHANDLE,
+        hevent: SPXEVENTHANDLE,
+        pvContext: *mut c_void,
+    ) {
+        trace!("ConversationTranscriber::cb_speech_end_detected called");
+        let callback_bag = &mut *(pvContext as *mut CallbackBag);
+        if let Some(cb) = &callback_bag.speech_end_detected_cb {
+            trace!("speech_end_detected_cb defined");
+            match RecognitionEvent::from_handle(hevent) {
+                Ok(event) => {
+                    trace!("calling cb with event {:?}", event);
+                    cb(event);
+                }
+                Err(err) => {
+                    error!(
+                        "ConversationTranscriber::cb_speech_end_detected error {:?}",
+                        err
+                    );
+                }
+            }
+        }
+    }
+
+    #[allow(non_snake_case)]
+    #[allow(unused_variables)]
+    unsafe extern "C" fn cb_canceled(
+        hreco: SPXRECOHANDLE,
+        hevent: SPXEVENTHANDLE,
+        pvContext: *mut c_void,
+    ) {
+        trace!("ConversationTranscriber::cb_canceled called");
+        let callback_bag = &mut *(pvContext as *mut CallbackBag);
+        if let Some(cb) = &callback_bag.canceled_cb {
+            trace!("canceled_cb defined");
+            match ConversationTranscriptionCanceledEvent::from_handle(hevent) {
+                Ok(event) => {
+                    trace!("calling cb with event {:?}", event);
+                    cb(event);
+                }
+                Err(err) => {
+                    error!("ConversationTranscriber::cb_canceled error {:?}", err);
+                }
+            }
+        }
+    }
+
+    #[allow(non_snake_case)]
+    #[allow(unused_variables)]
+    unsafe extern "C" fn cb_transcribing(
+        hreco: SPXRECOHANDLE,
+        hevent: SPXEVENTHANDLE,
+        pvContext: *mut c_void,
+    ) {
+        trace!("ConversationTranscriber::cb_transcribing called");
+        let callback_bag = &mut *(pvContext as *mut CallbackBag);
+        if let Some(cb) = &callback_bag.transcribing_cb {
+            trace!("transcribing_cb defined");
+            match ConversationTranscriptionEvent::from_handle(hevent) {
+                Ok(event) => {
+                    trace!("calling cb with event {:?}", event);
+                    cb(event);
+                }
+                Err(err) => {
+                    error!("ConversationTranscriber::cb_transcribing error {:?}", err);
+                }
+            }
+        }
+    }
+
+    #[allow(non_snake_case)]
+    #[allow(unused_variables)]
+    unsafe extern "C" fn cb_transcribed(
+        hreco: SPXRECOHANDLE,
+        hevent: SPXEVENTHANDLE,
+        pvContext: *mut c_void,
+    ) {
+        trace!("ConversationTranscriber::cb_transcribed called");
+        let callback_bag = &mut *(pvContext as *mut CallbackBag);
+        if let Some(cb) = &callback_bag.transcribed_cb {
+            trace!("transcribed_cb defined");
+            match ConversationTranscriptionEvent::from_handle(hevent) {
+                Ok(event) => {
+                    trace!("calling cb with event {:?}", event);
+                    cb(event);
+                }
+                Err(err) => {
+                    error!("ConversationTranscriber::cb_transcribed error {:?}", err);
+                }
+            }
+        }
+    }
+
+    /// Asynchronously starts conversation transcription.
+    /// Results are delivered via *set_transcribing_cb* and *set_transcribed_cb* callbacks.
+    pub async fn start_transcribing_async(&mut self) -> Result<()> {
+        let handle_async_start = unsafe {
+            let mut handle_async_start: MaybeUninit<SPXASYNCHANDLE> = MaybeUninit::uninit();
+            let ret = recognizer_start_continuous_recognition_async(
+                self.handle.inner(),
+                handle_async_start.as_mut_ptr(),
+            );
+            convert_err(
+                ret,
+                "ConversationTranscriber.recognizer_start_continuous_recognition_async error",
+            )?;
+            SmartHandle::create(
+                "handle_async_start_transcribing",
+                handle_async_start.assume_init(),
+                recognizer_async_handle_release,
+            )
+        };
+        wait_for_async(handle_async_start, |handle_async| unsafe {
+            let ret =
+                recognizer_start_continuous_recognition_async_wait_for(handle_async, u32::MAX);
+            convert_err(
+                ret,
+                "ConversationTranscriber.recognizer_start_continuous_recognition_async_wait_for error",
+            )
+        })
+        .await
+    }
+
+    /// Asynchronously stops ongoing conversation transcription.
+    pub async fn stop_transcribing_async(&mut self) -> Result<()> {
+        let handle_async_stop = unsafe {
+            let mut handle_async_stop: MaybeUninit<SPXASYNCHANDLE> = MaybeUninit::uninit();
+            let ret = recognizer_stop_continuous_recognition_async(
+                self.handle.inner(),
+                handle_async_stop.as_mut_ptr(),
+            );
+            convert_err(
+                ret,
+                "ConversationTranscriber.recognizer_stop_continuous_recognition_async error",
+            )?;
+            SmartHandle::create(
+                "handle_async_stop_transcribing",
+                handle_async_stop.assume_init(),
+                recognizer_async_handle_release,
+            )
+        };
+        wait_for_async(handle_async_stop, |handle_async| unsafe {
+            let ret = recognizer_stop_continuous_recognition_async_wait_for(handle_async, u32::MAX);
+            convert_err(
+                ret,
+                "ConversationTranscriber.recognizer_stop_continuous_recognition_async_wait_for error",
+            )
+        })
+        .await
+    }
+
+    pub fn get_endpoint_id(&self) -> Result<String> {
+        self.properties
+            .get_property(PropertyId::SpeechServiceConnectionEndpointId, "")
+    }
+
+    pub fn get_auth_token(&self) -> Result<String> {
+        self.properties
+            .get_property(PropertyId::SpeechServiceAuthorizationToken, "")
+    }
+
+    /// Sets the authorization token that will be used for connecting to the service.
+    /// Note: The caller needs to ensure that the authorization token is valid. Before the authorization token
+    /// expires, the caller needs to refresh it by calling this setter with a new valid token.
+    /// Otherwise, the transcriber will encounter errors during transcription.
+    pub fn set_auth_token(&mut self, token: &str) -> Result<()> {
+        self.properties
+            .set_property(PropertyId::SpeechServiceAuthorizationToken, token)
+    }
+}
+
+/// Disconnects registered callbacks before callback bag is freed, as native transcriber
+/// can outlive this struct when future of pending transcription is dropped.
+impl Drop for ConversationTranscriber {
+    fn drop(&mut self) {
+        unsafe {
+            if self.callback_bag.session_started_cb.is_some() {
+                recognizer_session_started_set_callback(
+                    self.handle.inner(),
+                    None,
+                    std::ptr::null_mut(),
+                );
+            }
+            if self.callback_bag.session_stopped_cb.is_some() {
+                recognizer_session_stopped_set_callback(
+                    self.handle.inner(),
+                    None,
+                    std::ptr::null_mut(),
+                );
+            }
+            if self.callback_bag.speech_start_detected_cb.is_some() {
+                recognizer_speech_start_detected_set_callback(
+                    self.handle.inner(),
+                    None,
+                    std::ptr::null_mut(),
+                );
+            }
+            if self.callback_bag.speech_end_detected_cb.is_some() {
+                recognizer_speech_end_detected_set_callback(
+                    self.handle.inner(),
+                    None,
+                    std::ptr::null_mut(),
+                );
+            }
+            if self.callback_bag.canceled_cb.is_some() {
+                recognizer_canceled_set_callback(self.handle.inner(), None, std::ptr::null_mut());
+            }
+            if self.callback_bag.transcribing_cb.is_some() {
+                recognizer_recognizing_set_callback(
+                    self.handle.inner(),
+                    None,
+                    std::ptr::null_mut(),
+                );
+            }
+            if self.callback_bag.transcribed_cb.is_some() {
+                recognizer_recognized_set_callback(self.handle.inner(), None, std::ptr::null_mut());
+            }
+        }
+    }
+}
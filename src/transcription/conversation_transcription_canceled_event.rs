@@ -0,0 +1,60 @@
+use crate::common::{CancellationErrorCode, CancellationReason, PropertyId};
+use crate::error::{convert_err, Result};
+use crate::ffi::{result_get_canceled_error_code, result_get_reason_canceled, SPXEVENTHANDLE};
+use crate::transcription::ConversationTranscriptionEvent;
+use log::*;
+
+/// Recognition event extending *ConversationTranscriptionEvent* passed into callback *set_canceled_cb*.
+#[derive(Debug)]
+pub struct ConversationTranscriptionCanceledEvent {
+    pub base: ConversationTranscriptionEvent,
+    pub reason: CancellationReason,
+    pub error_code: CancellationErrorCode,
+    pub error_details: String,
+}
+
+impl ConversationTranscriptionCanceledEvent {
+    /// # Safety
+    /// `handle` must be a valid handle to a live conversation transcription canceled event.
+    pub unsafe fn from_handle(
+        handle: SPXEVENTHANDLE,
+    ) -> Result<ConversationTranscriptionCanceledEvent> {
+        unsafe {
+            let base = ConversationTranscriptionEvent::from_handle(handle)?;
+            let result_handle = base.result.base.handle.inner();
+            let mut reason = 0;
+            let ret = result_get_reason_canceled(result_handle, &mut reason);
+            convert_err(
+                ret,
+                "ConversationTranscriptionCanceledEvent::from_handle(result_get_reason_canceled) error",
+            )?;
+
+            let mut error_code = 0;
+            let ret = result_get_canceled_error_code(result_handle, &mut error_code);
+            convert_err(
+                ret,
+                "ConversationTranscriptionCanceledEvent::from_handle(result_get_canceled_error_code) error",
+            )?;
+
+            let error_details = base
+                .result
+                .base
+                .properties
+                .get_property(PropertyId::SpeechServiceResponseJsonErrorDetails, "")
+                .unwrap_or_else(|err| {
+                    warn!(
+                        "Error when getting SpeechServiceResponseJsonErrorDetails {:?}",
+                        err
+                    );
+                    "".to_owned()
+                });
+
+            Ok(ConversationTranscriptionCanceledEvent {
+                base,
+                reason: reason.into(),
+                error_code: error_code.into(),
+                error_details,
+            })
+        }
+    }
+}
@@ -0,0 +1,30 @@
+use crate::error::{convert_err, Result};
+use crate::ffi::{recognizer_recognition_event_get_result, SPXEVENTHANDLE, SPXRESULTHANDLE};
+use crate::speech::RecognitionEvent;
+use crate::transcription::ConversationTranscriptionResult;
+use log::*;
+use std::mem::MaybeUninit;
+
+/// Recognition event extending *RecognitionEvent* passed into callbacks *set_transcribing_cb* and *set_transcribed_cb*.
+#[derive(Debug)]
+pub struct ConversationTranscriptionEvent {
+    pub base: RecognitionEvent,
+    pub result: ConversationTranscriptionResult,
+}
+
+impl ConversationTranscriptionEvent {
+    /// # Safety
+    /// `handle` must be a valid handle to a live conversation transcription event.
+    pub unsafe fn from_handle(handle: SPXEVENTHANDLE) -> Result<ConversationTranscriptionEvent> {
+        unsafe {
+            let base = RecognitionEvent::from_handle(handle)?;
+            let mut result_handle: MaybeUninit<SPXRESULTHANDLE> = MaybeUninit::uninit();
+            trace!("calling recognizer_recognition_event_get_result");
+            let ret = recognizer_recognition_event_get_result(handle, result_handle.as_mut_ptr());
+            convert_err(ret, "ConversationTranscriptionEvent::from_handle error")?;
+            trace!("called recognizer_recognition_event_get_result");
+            let result = ConversationTranscriptionResult::from_handle(result_handle.assume_init())?;
+            Ok(ConversationTranscriptionEvent { base, result })
+        }
+    }
+}
@@ -0,0 +1,47 @@
+use crate::error::{convert_err, Result};
+use crate::ffi::{conversation_transcription_result_get_speaker_id, SPXRESULTHANDLE};
+use crate::speech::SpeechRecognitionResult;
+use std::ffi::CStr;
+use std::fmt;
+
+/// Represents conversation transcription result contained within callback event *ConversationTranscriptionEvent*.
+/// Extends *SpeechRecognitionResult* with identifier of the speaker.
+pub struct ConversationTranscriptionResult {
+    pub base: SpeechRecognitionResult,
+    /// Speaker identifier assigned by diarization, e.g. *Guest-1*.
+    /// Empty or *Unknown* when speaker could not be identified (yet).
+    pub speaker_id: String,
+}
+
+impl fmt::Debug for ConversationTranscriptionResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ConversationTranscriptionResult")
+            .field("base", &self.base)
+            .field("speaker_id", &self.speaker_id)
+            .finish()
+    }
+}
+
+impl ConversationTranscriptionResult {
+    /// # Safety
+    /// `handle` must be a valid handle to a live conversation transcription result.
+    pub unsafe fn from_handle(handle: SPXRESULTHANDLE) -> Result<ConversationTranscriptionResult> {
+        unsafe {
+            let base = SpeechRecognitionResult::from_handle(handle)?;
+
+            let mut c_buf = [0; 1024];
+            let ret = conversation_transcription_result_get_speaker_id(
+                base.handle.inner(),
+                c_buf.as_mut_ptr(),
+                c_buf.len() as u32,
+            );
+            convert_err(
+                ret,
+                "ConversationTranscriptionResult::from_handle(conversation_transcription_result_get_speaker_id) error",
+            )?;
+            let speaker_id = CStr::from_ptr(c_buf.as_ptr()).to_str()?.to_owned();
+
+            Ok(ConversationTranscriptionResult { base, speaker_id })
+        }
+    }
+}
@@ -0,0 +1,225 @@
+use crate::common::PropertyCollection;
+use crate::error::{convert_err, Result};
+use crate::ffi::{
+    meeting_create_from_config, meeting_delete_meeting, meeting_end_meeting,
+    meeting_get_meeting_id, meeting_get_property_bag, meeting_lock_meeting,
+    meeting_mute_all_participants, meeting_mute_participant, meeting_release_handle,
+    meeting_start_meeting, meeting_unlock_meeting, meeting_unmute_all_participants,
+    meeting_unmute_participant, meeting_update_participant, meeting_update_participant_by_user,
+    meeting_update_participant_by_user_id, run_blocking, SharedHandle, SmartHandle,
+    SPXMEETINGHANDLE, SPXPROPERTYBAGHANDLE,
+};
+use crate::speech::SpeechConfig;
+use crate::transcription::{Participant, User};
+use std::ffi::{CStr, CString};
+use std::mem::MaybeUninit;
+
+/// Meeting represents meeting transcribed by *MeetingTranscriber*.
+/// Participants with voice signatures should be added to the meeting
+/// before transcription is started so that transcribed phrases can be attributed to them.
+#[derive(Debug)]
+pub struct Meeting {
+    pub handle: SharedHandle<SPXMEETINGHANDLE>,
+    pub properties: PropertyCollection,
+}
+
+impl Meeting {
+    /// Creates meeting with given meeting id.
+    pub fn from_config(speech_config: SpeechConfig, meeting_id: &str) -> Result<Meeting> {
+        unsafe {
+            let c_id = CString::new(meeting_id)?;
+            let mut handle: MaybeUninit<SPXMEETINGHANDLE> = MaybeUninit::uninit();
+            let mut ret = meeting_create_from_config(
+                handle.as_mut_ptr(),
+                speech_config.handle.inner(),
+                c_id.as_ptr(),
+            );
+            convert_err(ret, "Meeting::from_config error")?;
+            // SAFETY: native meeting serializes service requests and is driven
+            // by native worker threads anyway, hence can be used from blocking tasks
+            let handle = SharedHandle::new(SmartHandle::create(
+                "Meeting",
+                handle.assume_init(),
+                meeting_release_handle,
+            ));
+
+            let mut prop_bag_handle: MaybeUninit<SPXPROPERTYBAGHANDLE> = MaybeUninit::uninit();
+            ret = meeting_get_property_bag(handle.inner(), prop_bag_handle.as_mut_ptr());
+            convert_err(ret, "Meeting::from_config(get_property_bag) error")?;
+            let properties = PropertyCollection::from_handle(prop_bag_handle.assume_init());
+
+            Ok(Meeting { handle, properties })
+        }
+    }
+
+    /// Returns id of the meeting.
+    pub fn meeting_id(&self) -> Result<String> {
+        unsafe {
+            let mut c_buf = [0; 1024];
+            let ret = meeting_get_meeting_id(self.handle.inner(), c_buf.as_mut_ptr(), c_buf.len());
+            convert_err(ret, "Meeting.meeting_id error")?;
+            Ok(CStr::from_ptr(c_buf.as_ptr()).to_str()?.to_owned())
+        }
+    }
+
+    /// Starts meeting.
+    pub async fn start_meeting_async(&self) -> Result<()> {
+        let handle = self.handle.clone();
+        run_blocking(move || unsafe {
+            let ret = meeting_start_meeting(handle.inner());
+            convert_err(ret, "Meeting.start_meeting_async error")
+        })
+        .await
+    }
+
+    /// Ends meeting.
+    pub async fn end_meeting_async(&self) -> Result<()> {
+        let handle = self.handle.clone();
+        run_blocking(move || unsafe {
+            let ret = meeting_end_meeting(handle.inner());
+            convert_err(ret, "Meeting.end_meeting_async error")
+        })
+        .await
+    }
+
+    /// Deletes meeting. Any participants that are still part of the meeting will be ejected.
+    pub async fn delete_meeting_async(&self) -> Result<()> {
+        let handle = self.handle.clone();
+        run_blocking(move || unsafe {
+            let ret = meeting_delete_meeting(handle.inner());
+            convert_err(ret, "Meeting.delete_meeting_async error")
+        })
+        .await
+    }
+
+    /// Locks meeting. This will prevent new participants from joining.
+    pub async fn lock_meeting_async(&self) -> Result<()> {
+        let handle = self.handle.clone();
+        run_blocking(move || unsafe {
+            let ret = meeting_lock_meeting(handle.inner());
+            convert_err(ret, "Meeting.lock_meeting_async error")
+        })
+        .await
+    }
+
+    /// Unlocks meeting.
+    pub async fn unlock_meeting_async(&self) -> Result<()> {
+        let handle = self.handle.clone();
+        run_blocking(move || unsafe {
+            let ret = meeting_unlock_meeting(handle.inner());
+            convert_err(ret, "Meeting.unlock_meeting_async error")
+        })
+        .await
+    }
+
+    /// Mutes all participants except for the host.
+    pub async fn mute_all_participants_async(&self) -> Result<()> {
+        let handle = self.handle.clone();
+        run_blocking(move || unsafe {
+            let ret = meeting_mute_all_participants(handle.inner());
+            convert_err(ret, "Meeting.mute_all_participants_async error")
+        })
+        .await
+    }
+
+    /// Unmutes all participants.
+    pub async fn unmute_all_participants_async(&self) -> Result<()> {
+        let handle = self.handle.clone();
+        run_blocking(move || unsafe {
+            let ret = meeting_unmute_all_participants(handle.inner());
+            convert_err(ret, "Meeting.unmute_all_participants_async error")
+        })
+        .await
+    }
+
+    /// Mutes participant with given user id.
+    pub async fn mute_participant_async(&self, user_id: &str) -> Result<()> {
+        let handle = self.handle.clone();
+        let c_user_id = CString::new(user_id)?;
+        run_blocking(move || unsafe {
+            let ret = meeting_mute_participant(handle.inner(), c_user_id.as_ptr());
+            convert_err(ret, "Meeting.mute_participant_async error")
+        })
+        .await
+    }
+
+    /// Unmutes participant with given user id.
+    pub async fn unmute_participant_async(&self, user_id: &str) -> Result<()> {
+        let handle = self.handle.clone();
+        let c_user_id = CString::new(user_id)?;
+        run_blocking(move || unsafe {
+            let ret = meeting_unmute_participant(handle.inner(), c_user_id.as_ptr());
+            convert_err(ret, "Meeting.unmute_participant_async error")
+        })
+        .await
+    }
+
+    /// Adds participant (including its voice signature and preferred language) to the meeting.
+    pub async fn add_participant_async(&self, participant: &Participant) -> Result<()> {
+        let handle = self.handle.clone();
+        let participant_handle = participant.handle.clone();
+        run_blocking(move || unsafe {
+            let ret = meeting_update_participant(handle.inner(), true, participant_handle.inner());
+            convert_err(ret, "Meeting.add_participant_async error")
+        })
+        .await
+    }
+
+    /// Removes participant from the meeting.
+    pub async fn remove_participant_async(&self, participant: &Participant) -> Result<()> {
+        let handle = self.handle.clone();
+        let participant_handle = participant.handle.clone();
+        run_blocking(move || unsafe {
+            let ret = meeting_update_participant(handle.inner(), false, participant_handle.inner());
+            convert_err(ret, "Meeting.remove_participant_async error")
+        })
+        .await
+    }
+
+    /// Adds participant identified by user id only to the meeting.
+    pub async fn add_participant_by_user_id_async(&self, user_id: &str) -> Result<()> {
+        let handle = self.handle.clone();
+        let c_user_id = CString::new(user_id)?;
+        run_blocking(move || unsafe {
+            let ret =
+                meeting_update_participant_by_user_id(handle.inner(), true, c_user_id.as_ptr());
+            convert_err(ret, "Meeting.add_participant_by_user_id_async error")
+        })
+        .await
+    }
+
+    /// Removes participant identified by user id from the meeting.
+    pub async fn remove_participant_by_user_id_async(&self, user_id: &str) -> Result<()> {
+        let handle = self.handle.clone();
+        let c_user_id = CString::new(user_id)?;
+        run_blocking(move || unsafe {
+            let ret =
+                meeting_update_participant_by_user_id(handle.inner(), false, c_user_id.as_ptr());
+            convert_err(ret, "Meeting.remove_participant_by_user_id_async error")
+        })
+        .await
+    }
+
+    /// Adds user to the meeting.
+    pub async fn add_user_async(&self, user: &User) -> Result<()> {
+        let handle = self.handle.clone();
+        let user_handle = user.handle.clone();
+        run_blocking(move || unsafe {
+            let ret = meeting_update_participant_by_user(handle.inner(), true, user_handle.inner());
+            convert_err(ret, "Meeting.add_user_async error")
+        })
+        .await
+    }
+
+    /// Removes user from the meeting.
+    pub async fn remove_user_async(&self, user: &User) -> Result<()> {
+        let handle = self.handle.clone();
+        let user_handle = user.handle.clone();
+        run_blocking(move || unsafe {
+            let ret =
+                meeting_update_participant_by_user(handle.inner(), false, user_handle.inner());
+            convert_err(ret, "Meeting.remove_user_async error")
+        })
+        .await
+    }
+}
@@ -0,0 +1,68 @@
+use crate::error::{convert_err, Result};
+use crate::ffi::{
+    meeting_transcription_result_get_user_id, meeting_transcription_result_get_utterance_id,
+    SPXRESULTHANDLE,
+};
+use crate::speech::SpeechRecognitionResult;
+use std::ffi::CStr;
+use std::fmt;
+
+/// Represents meeting transcription result contained within callback event *MeetingTranscriptionEvent*.
+/// Extends *SpeechRecognitionResult* with identifiers of the user and the utterance.
+pub struct MeetingTranscriptionResult {
+    pub base: SpeechRecognitionResult,
+    /// Identifier of the meeting participant who spoke, e.g. user id of *Participant*.
+    /// *Guest_N* or *Unidentified* when speaker could not be matched to a participant.
+    pub user_id: String,
+    /// Identifier of the utterance.
+    pub utterance_id: String,
+}
+
+impl fmt::Debug for MeetingTranscriptionResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MeetingTranscriptionResult")
+            .field("base", &self.base)
+            .field("user_id", &self.user_id)
+            .field("utterance_id", &self.utterance_id)
+            .finish()
+    }
+}
+
+impl MeetingTranscriptionResult {
+    /// # Safety
+    /// `handle` must be a valid handle to a live meeting transcription result.
+    pub unsafe fn from_handle(handle: SPXRESULTHANDLE) -> Result<MeetingTranscriptionResult> {
+        unsafe {
+            let base = SpeechRecognitionResult::from_handle(handle)?;
+
+            let mut c_buf = [0; 1024];
+            let mut ret = meeting_transcription_result_get_user_id(
+                base.handle.inner(),
+                c_buf.as_mut_ptr(),
+                c_buf.len() as u32,
+            );
+            convert_err(
+                ret,
+                "MeetingTranscriptionResult::from_handle(meeting_transcription_result_get_user_id) error",
+            )?;
+            let user_id = CStr::from_ptr(c_buf.as_ptr()).to_str()?.to_owned();
+
+            ret = meeting_transcription_result_get_utterance_id(
+                base.handle.inner(),
+                c_buf.as_mut_ptr(),
+                c_buf.len() as u32,
+            );
+            convert_err(
+                ret,
+                "MeetingTranscriptionResult::from_handle(meeting_transcription_result_get_utterance_id) error",
+            )?;
+            let utterance_id = CStr::from_ptr(c_buf.as_ptr()).to_str()?.to_owned();
+
+            Ok(MeetingTranscriptionResult {
+                base,
+                user_id,
+                utterance_id,
+            })
+        }
+    }
+}
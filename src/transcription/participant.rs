@@ -0,0 +1,82 @@
+use crate::common::PropertyCollection;
+use crate::error::{convert_err, Result};
+use crate::ffi::{
+    participant_create_handle, participant_get_property_bag, participant_release_handle,
+    participant_set_preferred_langugage, participant_set_voice_signature, SharedHandle,
+    SmartHandle, NULL_C_STR_PTR, SPXPARTICIPANTHANDLE, SPXPROPERTYBAGHANDLE,
+};
+use std::ffi::CString;
+use std::mem::MaybeUninit;
+
+/// Participant represents a participant in a meeting. Voice signature
+/// allows meeting transcription to attribute transcribed phrases to the participant.
+#[derive(Debug)]
+pub struct Participant {
+    pub handle: SharedHandle<SPXPARTICIPANTHANDLE>,
+    pub user_id: String,
+    pub properties: PropertyCollection,
+}
+
+impl Participant {
+    /// Creates participant from user id, preferred language (e.g. en-US)
+    /// and optional voice signature. Voice signature is JSON
+    /// returned by the voice signature service for the participant's enrollment audio.
+    pub fn from_user_id(
+        user_id: &str,
+        preferred_language: &str,
+        voice_signature: Option<&str>,
+    ) -> Result<Participant> {
+        unsafe {
+            let c_user_id = CString::new(user_id)?;
+            let c_language = CString::new(preferred_language)?;
+            let c_voice_signature = voice_signature.map(CString::new).transpose()?;
+            let mut handle: MaybeUninit<SPXPARTICIPANTHANDLE> = MaybeUninit::uninit();
+            let mut ret = participant_create_handle(
+                handle.as_mut_ptr(),
+                c_user_id.as_ptr(),
+                c_language.as_ptr(),
+                c_voice_signature
+                    .as_ref()
+                    .map_or(NULL_C_STR_PTR, |signature| signature.as_ptr()),
+            );
+            convert_err(ret, "Participant::from_user_id error")?;
+            // SAFETY: native participant is plain data holder, its setters take &mut self
+            // and meeting only reads it when participant is being added
+            let handle = SharedHandle::new(SmartHandle::create(
+                "Participant",
+                handle.assume_init(),
+                participant_release_handle,
+            ));
+
+            let mut prop_bag_handle: MaybeUninit<SPXPROPERTYBAGHANDLE> = MaybeUninit::uninit();
+            ret = participant_get_property_bag(handle.inner(), prop_bag_handle.as_mut_ptr());
+            convert_err(ret, "Participant::from_user_id(get_property_bag) error")?;
+            let properties = PropertyCollection::from_handle(prop_bag_handle.assume_init());
+
+            Ok(Participant {
+                handle,
+                user_id: user_id.to_owned(),
+                properties,
+            })
+        }
+    }
+
+    /// Sets preferred language of the participant, e.g. en-US.
+    pub fn set_preferred_language(&mut self, preferred_language: &str) -> Result<()> {
+        unsafe {
+            let c_language = CString::new(preferred_language)?;
+            let ret = participant_set_preferred_langugage(self.handle.inner(), c_language.as_ptr());
+            convert_err(ret, "Participant.set_preferred_language error")
+        }
+    }
+
+    /// Sets voice signature of the participant.
+    pub fn set_voice_signature(&mut self, voice_signature: &str) -> Result<()> {
+        unsafe {
+            let c_voice_signature = CString::new(voice_signature)?;
+            let ret =
+                participant_set_voice_signature(self.handle.inner(), c_voice_signature.as_ptr());
+            convert_err(ret, "Participant.set_voice_signature error")
+        }
+    }
+}
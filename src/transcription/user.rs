@@ -0,0 +1,42 @@
+use crate::error::{convert_err, Result};
+use crate::ffi::{
+    user_create_from_id, user_get_id, user_release_handle, SharedHandle, SmartHandle, SPXUSERHANDLE,
+};
+use std::ffi::{CStr, CString};
+use std::mem::MaybeUninit;
+
+/// User represents a user in a meeting, identified by user id only.
+/// Use *Participant* to provide also voice signature and preferred language.
+#[derive(Debug)]
+pub struct User {
+    pub handle: SharedHandle<SPXUSERHANDLE>,
+}
+
+impl User {
+    pub fn from_id(user_id: &str) -> Result<User> {
+        unsafe {
+            let c_user_id = CString::new(user_id)?;
+            let mut handle: MaybeUninit<SPXUSERHANDLE> = MaybeUninit::uninit();
+            let ret = user_create_from_id(c_user_id.as_ptr(), handle.as_mut_ptr());
+            convert_err(ret, "User::from_id error")?;
+            Ok(User {
+                // SAFETY: user is immutable once created, native calls only read it
+                handle: SharedHandle::new(SmartHandle::create(
+                    "User",
+                    handle.assume_init(),
+                    user_release_handle,
+                )),
+            })
+        }
+    }
+
+    /// Returns id of the user.
+    pub fn id(&self) -> Result<String> {
+        unsafe {
+            let mut c_buf = [0; 1024];
+            let ret = user_get_id(self.handle.inner(), c_buf.as_mut_ptr(), c_buf.len());
+            convert_err(ret, "User.id error")?;
+            Ok(CStr::from_ptr(c_buf.as_ptr()).to_str()?.to_owned())
+        }
+    }
+}
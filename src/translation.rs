@@ -1,15 +1,35 @@
+mod conversation;
+mod conversation_expiration_event;
+mod conversation_participants_changed_event;
+mod conversation_translation_canceled_event;
+mod conversation_translation_event;
+mod conversation_translation_result;
+mod conversation_translator;
+mod participant;
+mod participant_changed_reason;
 mod speech_translation_config;
 mod translation_recognition_canceled_event;
 mod translation_recognition_event;
 mod translation_recognition_result;
 mod translation_recognizer;
+mod translation_recognizer_event;
 mod translation_synthesis_event;
 mod translation_synthesis_result;
 
+pub use conversation::Conversation;
+pub use conversation_expiration_event::ConversationExpirationEvent;
+pub use conversation_participants_changed_event::ConversationParticipantsChangedEvent;
+pub use conversation_translation_canceled_event::ConversationTranslationCanceledEvent;
+pub use conversation_translation_event::ConversationTranslationEvent;
+pub use conversation_translation_result::ConversationTranslationResult;
+pub use conversation_translator::ConversationTranslator;
+pub use participant::Participant;
+pub use participant_changed_reason::ParticipantChangedReason;
 pub use speech_translation_config::SpeechTranslationConfig;
 pub use translation_recognition_canceled_event::TranslationRecognitionCanceledEvent;
 pub use translation_recognition_event::TranslationRecognitionEvent;
 pub use translation_recognition_result::TranslationRecognitionResult;
 pub use translation_recognizer::TranslationRecognizer;
+pub use translation_recognizer_event::TranslationRecognizerEvent;
 pub use translation_synthesis_event::TranslationSynthesisEvent;
 pub use translation_synthesis_result::TranslationSynthesisResult;
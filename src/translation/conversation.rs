@@ -0,0 +1,188 @@
+use super::SpeechTranslationConfig;
+use crate::common::PropertyCollection;
+use crate::error::{Result, convert_err};
+use crate::ffi::{
+    NULL_C_STR_PTR, SPXCONVERSATIONHANDLE, SPXPROPERTYBAGHANDLE, SharedHandle, SmartHandle,
+    conversation_create_from_config, conversation_delete_conversation,
+    conversation_end_conversation, conversation_get_conversation_id, conversation_get_property_bag,
+    conversation_lock_conversation, conversation_mute_all_participants,
+    conversation_mute_participant, conversation_release_handle, conversation_start_conversation,
+    conversation_unlock_conversation, conversation_unmute_all_participants,
+    conversation_unmute_participant, conversation_update_participant_by_user_id, run_blocking,
+};
+use std::ffi::{CStr, CString};
+use std::mem::MaybeUninit;
+
+/// Conversation represents multi-user conversation hosted by the speech service.
+/// Host creates and starts conversation, other participants join it
+/// using *ConversationTranslator* and conversation id.
+#[derive(Debug)]
+pub struct Conversation {
+    pub handle: SharedHandle<SPXCONVERSATIONHANDLE>,
+    pub properties: PropertyCollection,
+}
+
+impl Conversation {
+    /// Creates conversation. When *conversation_id* is not provided
+    /// it is generated by the service once conversation is started.
+    pub fn from_config(
+        speech_translation_config: SpeechTranslationConfig,
+        conversation_id: Option<&str>,
+    ) -> Result<Conversation> {
+        unsafe {
+            let c_id = conversation_id.map(CString::new).transpose()?;
+            let mut handle: MaybeUninit<SPXCONVERSATIONHANDLE> = MaybeUninit::uninit();
+            let mut ret = conversation_create_from_config(
+                handle.as_mut_ptr(),
+                speech_translation_config.handle.inner(),
+                c_id.as_ref().map_or(NULL_C_STR_PTR, |id| id.as_ptr()),
+            );
+            convert_err(ret, "Conversation::from_config error")?;
+            // SAFETY: native conversation serializes service requests and is driven
+            // by native worker threads anyway, hence can be used from blocking tasks
+            let handle = SharedHandle::new(SmartHandle::create(
+                "Conversation",
+                handle.assume_init(),
+                conversation_release_handle,
+            ));
+
+            let mut prop_bag_handle: MaybeUninit<SPXPROPERTYBAGHANDLE> = MaybeUninit::uninit();
+            ret = conversation_get_property_bag(handle.inner(), prop_bag_handle.as_mut_ptr());
+            convert_err(ret, "Conversation::from_config(get_property_bag) error")?;
+            let properties = PropertyCollection::from_handle(prop_bag_handle.assume_init());
+
+            Ok(Conversation { handle, properties })
+        }
+    }
+
+    /// Returns id of the conversation.
+    pub fn conversation_id(&self) -> Result<String> {
+        unsafe {
+            let mut c_buf = [0; 1024];
+            let ret = conversation_get_conversation_id(
+                self.handle.inner(),
+                c_buf.as_mut_ptr(),
+                c_buf.len(),
+            );
+            convert_err(ret, "Conversation.conversation_id error")?;
+            Ok(CStr::from_ptr(c_buf.as_ptr()).to_str()?.to_owned())
+        }
+    }
+
+    /// Starts conversation so that participants can join it.
+    pub async fn start_conversation_async(&self) -> Result<()> {
+        let handle = self.handle.clone();
+        run_blocking(move || unsafe {
+            let ret = conversation_start_conversation(handle.inner());
+            convert_err(ret, "Conversation.start_conversation_async error")
+        })
+        .await
+    }
+
+    /// Ends conversation.
+    pub async fn end_conversation_async(&self) -> Result<()> {
+        let handle = self.handle.clone();
+        run_blocking(move || unsafe {
+            let ret = conversation_end_conversation(handle.inner());
+            convert_err(ret, "Conversation.end_conversation_async error")
+        })
+        .await
+    }
+
+    /// Deletes conversation. Any participants that are still part of the conversation will be ejected.
+    pub async fn delete_conversation_async(&self) -> Result<()> {
+        let handle = self.handle.clone();
+        run_blocking(move || unsafe {
+            let ret = conversation_delete_conversation(handle.inner());
+            convert_err(ret, "Conversation.delete_conversation_async error")
+        })
+        .await
+    }
+
+    /// Locks conversation. This will prevent new participants from joining.
+    pub async fn lock_conversation_async(&self) -> Result<()> {
+        let handle = self.handle.clone();
+        run_blocking(move || unsafe {
+            let ret = conversation_lock_conversation(handle.inner());
+            convert_err(ret, "Conversation.lock_conversation_async error")
+        })
+        .await
+    }
+
+    /// Unlocks conversation.
+    pub async fn unlock_conversation_async(&self) -> Result<()> {
+        let handle = self.handle.clone();
+        run_blocking(move || unsafe {
+            let ret = conversation_unlock_conversation(handle.inner());
+            convert_err(ret, "Conversation.unlock_conversation_async error")
+        })
+        .await
+    }
+
+    /// Mutes all participants except for the host. This prevents others from generating
+    /// transcriptions, or sending text messages.
+    pub async fn mute_all_participants_async(&self) -> Result<()> {
+        let handle = self.handle.clone();
+        run_blocking(move || unsafe {
+            let ret = conversation_mute_all_participants(handle.inner());
+            convert_err(ret, "Conversation.mute_all_participants_async error")
+        })
+        .await
+    }
+
+    /// Allows other participants to generate transcriptions, or send text messages.
+    pub async fn unmute_all_participants_async(&self) -> Result<()> {
+        let handle = self.handle.clone();
+        run_blocking(move || unsafe {
+            let ret = conversation_unmute_all_participants(handle.inner());
+            convert_err(ret, "Conversation.unmute_all_participants_async error")
+        })
+        .await
+    }
+
+    /// Mutes particular participant.
+    pub async fn mute_participant_async(&self, participant_id: &str) -> Result<()> {
+        let handle = self.handle.clone();
+        let c_id = CString::new(participant_id)?;
+        run_blocking(move || unsafe {
+            let ret = conversation_mute_participant(handle.inner(), c_id.as_ptr());
+            convert_err(ret, "Conversation.mute_participant_async error")
+        })
+        .await
+    }
+
+    /// Unmutes particular participant.
+    pub async fn unmute_participant_async(&self, participant_id: &str) -> Result<()> {
+        let handle = self.handle.clone();
+        let c_id = CString::new(participant_id)?;
+        run_blocking(move || unsafe {
+            let ret = conversation_unmute_participant(handle.inner(), c_id.as_ptr());
+            convert_err(ret, "Conversation.unmute_participant_async error")
+        })
+        .await
+    }
+
+    /// Adds participant to conversation.
+    pub async fn add_participant_async(&self, user_id: &str) -> Result<()> {
+        let handle = self.handle.clone();
+        let c_id = CString::new(user_id)?;
+        run_blocking(move || unsafe {
+            let ret =
+                conversation_update_participant_by_user_id(handle.inner(), true, c_id.as_ptr());
+            convert_err(ret, "Conversation.add_participant_async error")
+        })
+        .await
+    }
+
+    /// Removes participant from conversation.
+    pub async fn remove_participant_async(&self, user_id: &str) -> Result<()> {
+        let handle = self.handle.clone();
+        let c_id = CString::new(user_id)?;
+        run_blocking(move || unsafe {
+            let ret =
+                conversation_update_participant_by_user_id(handle.inner(), false, c_id.as_ptr());
+            convert_err(ret, "Conversation.remove_participant_async error")
+        })
+        .await
+    }
+}
@@ -0,0 +1,31 @@
+use crate::error::{Result, convert_err};
+use crate::ffi::{SPXEVENTHANDLE, conversation_translator_event_get_expiration_time};
+use crate::speech::SessionEvent;
+
+/// Event extending *SessionEvent* passed into callback *set_conversation_expiration_cb*.
+#[derive(Debug)]
+pub struct ConversationExpirationEvent {
+    pub base: SessionEvent,
+    /// How many minutes are left until the conversation expires.
+    pub expiration_time: i32,
+}
+
+impl ConversationExpirationEvent {
+    /// # Safety
+    /// `handle` must be a valid handle to a live conversation expiration event.
+    pub unsafe fn from_handle(handle: SPXEVENTHANDLE) -> Result<ConversationExpirationEvent> {
+        unsafe {
+            let base = SessionEvent::from_handle(handle)?;
+            let mut expiration_time = 0;
+            let ret = conversation_translator_event_get_expiration_time(
+                base.handle.inner(),
+                &mut expiration_time,
+            );
+            convert_err(ret, "ConversationExpirationEvent::from_handle error")?;
+            Ok(ConversationExpirationEvent {
+                base,
+                expiration_time,
+            })
+        }
+    }
+}
@@ -0,0 +1,62 @@
+use super::{Participant, ParticipantChangedReason};
+use crate::error::{Result, convert_err};
+use crate::ffi::{
+    SPX_NOERROR, SPXEVENTHANDLE, SPXPARTICIPANTHANDLE,
+    conversation_translator_event_get_participant_changed_at_index,
+    conversation_translator_event_get_participant_changed_reason,
+};
+use crate::speech::SessionEvent;
+use std::ptr;
+
+/// Event extending *SessionEvent* passed into callback *set_participants_changed_cb*.
+#[derive(Debug)]
+pub struct ConversationParticipantsChangedEvent {
+    pub base: SessionEvent,
+    pub reason: ParticipantChangedReason,
+    /// Participants that joined, left or were updated.
+    pub participants: Vec<Participant>,
+}
+
+impl ConversationParticipantsChangedEvent {
+    /// # Safety
+    /// `handle` must be a valid handle to a live conversation participants changed event.
+    pub unsafe fn from_handle(
+        handle: SPXEVENTHANDLE,
+    ) -> Result<ConversationParticipantsChangedEvent> {
+        unsafe {
+            let base = SessionEvent::from_handle(handle)?;
+
+            let mut reason = 0;
+            let ret = conversation_translator_event_get_participant_changed_reason(
+                base.handle.inner(),
+                &mut reason,
+            );
+            convert_err(
+                ret,
+                "ConversationParticipantsChangedEvent::from_handle(get_participant_changed_reason) error",
+            )?;
+
+            // native API does not provide participant count,
+            // participants are read until index is out of range
+            let mut participants = vec![];
+            loop {
+                let mut participant_handle: SPXPARTICIPANTHANDLE = ptr::null_mut();
+                let ret = conversation_translator_event_get_participant_changed_at_index(
+                    base.handle.inner(),
+                    participants.len() as i32,
+                    &mut participant_handle,
+                );
+                if ret != SPX_NOERROR as usize || participant_handle.is_null() {
+                    break;
+                }
+                participants.push(Participant::from_handle(participant_handle)?);
+            }
+
+            Ok(ConversationParticipantsChangedEvent {
+                base,
+                reason: reason.into(),
+                participants,
+            })
+        }
+    }
+}
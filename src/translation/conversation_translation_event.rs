@@ -0,0 +1,28 @@
+use super::ConversationTranslationResult;
+use crate::error::{Result, convert_err};
+use crate::ffi::{SPXEVENTHANDLE, SPXRESULTHANDLE, recognizer_recognition_event_get_result};
+use crate::speech::RecognitionEvent;
+use std::mem::MaybeUninit;
+
+/// Event extending *RecognitionEvent* passed into callbacks *set_transcribing_cb*, *set_transcribed_cb*
+/// and *set_text_message_received_cb* of *ConversationTranslator*.
+#[derive(Debug)]
+pub struct ConversationTranslationEvent {
+    pub base: RecognitionEvent,
+    pub result: ConversationTranslationResult,
+}
+
+impl ConversationTranslationEvent {
+    /// # Safety
+    /// `handle` must be a valid handle to a live conversation translation event.
+    pub unsafe fn from_handle(handle: SPXEVENTHANDLE) -> Result<ConversationTranslationEvent> {
+        unsafe {
+            let base = RecognitionEvent::from_handle(handle)?;
+            let mut result_handle: MaybeUninit<SPXRESULTHANDLE> = MaybeUninit::uninit();
+            let ret = recognizer_recognition_event_get_result(handle, result_handle.as_mut_ptr());
+            convert_err(ret, "ConversationTranslationEvent::from_handle error")?;
+            let result = ConversationTranslationResult::from_handle(result_handle.assume_init())?;
+            Ok(ConversationTranslationEvent { base, result })
+        }
+    }
+}
@@ -0,0 +1,57 @@
+use super::TranslationRecognitionResult;
+use crate::error::{Result, convert_err};
+use crate::ffi::{
+    SPXRESULTHANDLE, conversation_translator_result_get_original_lang,
+    conversation_translator_result_get_user_id,
+};
+use std::ffi::CStr;
+
+/// Represents result of conversation translation contained within callback event *ConversationTranslationEvent*.
+#[derive(Debug)]
+pub struct ConversationTranslationResult {
+    pub base: TranslationRecognitionResult,
+    /// Id of the participant who spoke or sent the text message.
+    pub participant_id: String,
+    /// Language of the original speech or text message.
+    pub original_lang: String,
+}
+
+impl ConversationTranslationResult {
+    /// # Safety
+    /// `handle` must be a valid handle to a live conversation translation result.
+    pub unsafe fn from_handle(handle: SPXRESULTHANDLE) -> Result<ConversationTranslationResult> {
+        unsafe {
+            let base = TranslationRecognitionResult::from_handle(handle)?;
+
+            let mut c_buf = [0; 1024];
+            let mut ret = conversation_translator_result_get_user_id(
+                handle,
+                c_buf.as_mut_ptr(),
+                c_buf.len() as u32,
+            );
+            convert_err(
+                ret,
+                "ConversationTranslationResult::from_handle(conversation_translator_result_get_user_id) error",
+            )?;
+            let participant_id = CStr::from_ptr(c_buf.as_ptr()).to_str()?.to_owned();
+
+            let mut len = c_buf.len() as u32;
+            ret = conversation_translator_result_get_original_lang(
+                handle,
+                c_buf.as_mut_ptr(),
+                &mut len,
+            );
+            convert_err(
+                ret,
+                "ConversationTranslationResult::from_handle(conversation_translator_result_get_original_lang) error",
+            )?;
+            let original_lang = CStr::from_ptr(c_buf.as_ptr()).to_str()?.to_owned();
+
+            Ok(ConversationTranslationResult {
+                base,
+                participant_id,
+                original_lang,
+            })
+        }
+    }
+}
@@ -0,0 +1,614 @@
+use super::{
+    Conversation, ConversationExpirationEvent, ConversationParticipantsChangedEvent,
+    ConversationTranslationCanceledEvent, ConversationTranslationEvent,
+};
+use crate::audio::AudioConfig;
+use crate::common::PropertyCollection;
+use crate::error::{Result, convert_err};
+use crate::ffi::{
+    SPXCONVERSATIONTRANSLATORHANDLE, SPXEVENTHANDLE, SPXPROPERTYBAGHANDLE, SharedHandle,
+    SmartHandle, conversation_translator_canceled_set_callback,
+    conversation_translator_conversation_expiration_set_callback,
+    conversation_translator_create_from_config, conversation_translator_get_property_bag,
+    conversation_translator_handle_release, conversation_translator_join,
+    conversation_translator_join_with_id, conversation_translator_leave,
+    conversation_translator_participants_changed_set_callback,
+    conversation_translator_send_text_message,
+    conversation_translator_session_started_set_callback,
+    conversation_translator_session_stopped_set_callback,
+    conversation_translator_set_authorization_token, conversation_translator_start_transcribing,
+    conversation_translator_stop_transcribing,
+    conversation_translator_text_message_recevied_set_callback,
+    conversation_translator_transcribed_set_callback,
+    conversation_translator_transcribing_set_callback, run_blocking,
+};
+use crate::speech::SessionEvent;
+use log::*;
+use std::boxed::Box;
+use std::ffi::CString;
+use std::fmt;
+use std::mem::MaybeUninit;
+use std::os::raw::c_void;
+
+/// A separate internal struct to hold all the callback closures for the conversation translator events.
+/// By creating a separate struct, and then boxing this struct inside our ConversationTranslator,
+/// we can ensure the ConversationTranslator itself can be moved freely by end users,
+/// and the callbacks will remain at a fixed memory address on the heap.
+struct CallbackBag {
+    session_started_cb: Option<Box<dyn Fn(SessionEvent) + Send>>,
+    session_stopped_cb: Option<Box<dyn Fn(SessionEvent) + Send>>,
+    canceled_cb: Option<Box<dyn Fn(ConversationTranslationCanceledEvent) + Send>>,
+    participants_changed_cb: Option<Box<dyn Fn(ConversationParticipantsChangedEvent) + Send>>,
+    conversation_expiration_cb: Option<Box<dyn Fn(ConversationExpirationEvent) + Send>>,
+    transcribing_cb: Option<Box<dyn Fn(ConversationTranslationEvent) + Send>>,
+    transcribed_cb: Option<Box<dyn Fn(ConversationTranslationEvent) + Send>>,
+    text_message_received_cb: Option<Box<dyn Fn(ConversationTranslationEvent) + Send>>,
+}
+
+/// ConversationTranslator struct holds functionality for joining multi-user conversation
+/// in which speech and text messages of all participants are transcribed and translated.
+pub struct ConversationTranslator {
+    pub(crate) handle: SharedHandle<SPXCONVERSATIONTRANSLATORHANDLE>,
+    pub properties: PropertyCollection,
+    callback_bag: Box<CallbackBag>,
+}
+
+impl fmt::Debug for ConversationTranslator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ConversationTranslator")
+            .field("handle", &self.handle)
+            .field("properties", &self.properties)
+            .finish()
+    }
+}
+
+impl ConversationTranslator {
+    pub fn from_config(audio_config: AudioConfig) -> Result<ConversationTranslator> {
+        unsafe {
+            let mut handle: MaybeUninit<SPXCONVERSATIONTRANSLATORHANDLE> = MaybeUninit::uninit();
+            let mut ret = conversation_translator_create_from_config(
+                handle.as_mut_ptr(),
+                audio_config.handle.inner(),
+            );
+            convert_err(ret, "ConversationTranslator::from_config error")?;
+            // SAFETY: native conversation translator runs on its own worker threads
+            // and synchronizes calls coming from application threads
+            let handle = SharedHandle::new(SmartHandle::create(
+                "ConversationTranslator",
+                handle.assume_init(),
+                conversation_translator_handle_release,
+            ));
+
+            let mut prop_bag_handle: MaybeUninit<SPXPROPERTYBAGHANDLE> = MaybeUninit::uninit();
+            ret = conversation_translator_get_property_bag(
+                handle.inner(),
+                prop_bag_handle.as_mut_ptr(),
+            );
+            convert_err(
+                ret,
+                "ConversationTranslator::from_config(get_property_bag) error",
+            )?;
+            let properties = PropertyCollection::from_handle(prop_bag_handle.assume_init());
+
+            Ok(ConversationTranslator {
+                handle,
+                properties,
+                callback_bag: Box::new(CallbackBag {
+                    session_started_cb: None,
+                    session_stopped_cb: None,
+                    canceled_cb: None,
+                    participants_changed_cb: None,
+                    conversation_expiration_cb: None,
+                    transcribing_cb: None,
+                    transcribed_cb: None,
+                    text_message_received_cb: None,
+                }),
+            })
+        }
+    }
+
+    /// Joins conversation created (and started) by the host using *Conversation*.
+    pub async fn join_conversation_async(
+        &self,
+        conversation: &Conversation,
+        nickname: &str,
+    ) -> Result<()> {
+        let handle = self.handle.clone();
+        let conversation_handle = conversation.handle.clone();
+        let c_nickname = CString::new(nickname)?;
+        run_blocking(move || unsafe {
+            let ret = conversation_translator_join(
+                handle.inner(),
+                conversation_handle.inner(),
+                c_nickname.as_ptr(),
+            );
+            convert_err(ret, "ConversationTranslator.join_conversation_async error")
+        })
+        .await
+    }
+
+    /// Joins existing conversation identified by *conversation_id*.
+    /// *lang* is the language participant speaks (e.g. en-US).
+    pub async fn join_conversation_with_id_async(
+        &self,
+        conversation_id: &str,
+        nickname: &str,
+        lang: &str,
+    ) -> Result<()> {
+        let handle = self.handle.clone();
+        let c_id = CString::new(conversation_id)?;
+        let c_nickname = CString::new(nickname)?;
+        let c_lang = CString::new(lang)?;
+        run_blocking(move || unsafe {
+            let ret = conversation_translator_join_with_id(
+                handle.inner(),
+                c_id.as_ptr(),
+                c_nickname.as_ptr(),
+                c_lang.as_ptr(),
+            );
+            convert_err(
+                ret,
+                "ConversationTranslator.join_conversation_with_id_async error",
+            )
+        })
+        .await
+    }
+
+    /// Starts sending audio to the conversation service for speech recognition and translation.
+    pub async fn start_transcribing_async(&self) -> Result<()> {
+        let handle = self.handle.clone();
+        run_blocking(move || unsafe {
+            let ret = conversation_translator_start_transcribing(handle.inner());
+            convert_err(ret, "ConversationTranslator.start_transcribing_async error")
+        })
+        .await
+    }
+
+    /// Stops sending audio to the conversation service.
+    pub async fn stop_transcribing_async(&self) -> Result<()> {
+        let handle = self.handle.clone();
+        run_blocking(move || unsafe {
+            let ret = conversation_translator_stop_transcribing(handle.inner());
+            convert_err(ret, "ConversationTranslator.stop_transcribing_async error")
+        })
+        .await
+    }
+
+    /// Sends instant message to all participants of the conversation.
+    /// Message will be translated into each participant's text language.
+    pub async fn send_text_message_async(&self, message: &str) -> Result<()> {
+        let handle = self.handle.clone();
+        let c_message = CString::new(message)?;
+        run_blocking(move || unsafe {
+            let ret = conversation_translator_send_text_message(handle.inner(), c_message.as_ptr());
+            convert_err(ret, "ConversationTranslator.send_text_message_async error")
+        })
+        .await
+    }
+
+    /// Leaves the current conversation. After this is called, you will no longer receive any events.
+    pub async fn leave_conversation_async(&self) -> Result<()> {
+        let handle = self.handle.clone();
+        run_blocking(move || unsafe {
+            let ret = conversation_translator_leave(handle.inner());
+            convert_err(ret, "ConversationTranslator.leave_conversation_async error")
+        })
+        .await
+    }
+
+    /// Sets the authorization token (and region) that will be used for connecting to the service.
+    pub fn set_authorization_token(&self, token: &str, region: &str) -> Result<()> {
+        unsafe {
+            let c_token = CString::new(token)?;
+            let c_region = CString::new(region)?;
+            let ret = conversation_translator_set_authorization_token(
+                self.handle.inner(),
+                c_token.as_ptr(),
+                c_region.as_ptr(),
+            );
+            convert_err(ret, "ConversationTranslator.set_authorization_token error")
+        }
+    }
+
+    pub fn set_session_started_cb<F>(&mut self, f: F) -> Result<()>
+    where
+        F: Fn(SessionEvent) + 'static + Send,
+    {
+        self.callback_bag.session_started_cb = Some(Box::new(f));
+        unsafe {
+            let ret = conversation_translator_session_started_set_callback(
+                self.handle.inner(),
+                Some(Self::cb_session_started),
+                &*self.callback_bag as *const _ as *mut c_void,
+            );
+            convert_err(ret, "ConversationTranslator.set_session_started_cb error")?;
+            Ok(())
+        }
+    }
+
+    pub fn set_session_stopped_cb<F>(&mut self, f: F) -> Result<()>
+    where
+        F: Fn(SessionEvent) + 'static + Send,
+    {
+        self.callback_bag.session_stopped_cb = Some(Box::new(f));
+        unsafe {
+            let ret = conversation_translator_session_stopped_set_callback(
+                self.handle.inner(),
+                Some(Self::cb_session_stopped),
+                &*self.callback_bag as *const _ as *mut c_void,
+            );
+            convert_err(ret, "ConversationTranslator.set_session_stopped_cb error")?;
+            Ok(())
+        }
+    }
+
+    /// Canceled signals that conversation translation was canceled
+    /// (e.g. as a result of transport or protocol failure).
+    pub fn set_canceled_cb<F>(&mut self, f: F) -> Result<()>
+    where
+        F: Fn(ConversationTranslationCanceledEvent) + 'static + Send,
+    {
+        self.callback_bag.canceled_cb = Some(Box::new(f));
+        unsafe {
+            let ret = conversation_translator_canceled_set_callback(
+                self.handle.inner(),
+                Some(Self::cb_canceled),
+                &*self.callback_bag as *const _ as *mut c_void,
+            );
+            convert_err(ret, "ConversationTranslator.set_canceled_cb error")?;
+            Ok(())
+        }
+    }
+
+    /// Signals that participants joined, left or were updated in the conversation.
+    pub fn set_participants_changed_cb<F>(&mut self, f: F) -> Result<()>
+    where
+        F: Fn(ConversationParticipantsChangedEvent) + 'static + Send,
+    {
+        self.callback_bag.participants_changed_cb = Some(Box::new(f));
+        unsafe {
+            let ret = conversation_translator_participants_changed_set_callback(
+                self.handle.inner(),
+                Some(Self::cb_participants_changed),
+                &*self.callback_bag as *const _ as *mut c_void,
+            );
+            convert_err(
+                ret,
+                "ConversationTranslator.set_participants_changed_cb error",
+            )?;
+            Ok(())
+        }
+    }
+
+    /// Signals how many minutes are left until the conversation expires.
+    pub fn set_conversation_expiration_cb<F>(&mut self, f: F) -> Result<()>
+    where
+        F: Fn(ConversationExpirationEvent) + 'static + Send,
+    {
+        self.callback_bag.conversation_expiration_cb = Some(Box::new(f));
+        unsafe {
+            let ret = conversation_translator_conversation_expiration_set_callback(
+                self.handle.inner(),
+                Some(Self::cb_conversation_expiration),
+                &*self.callback_bag as *const _ as *mut c_void,
+            );
+            convert_err(
+                ret,
+                "ConversationTranslator.set_conversation_expiration_cb error",
+            )?;
+            Ok(())
+        }
+    }
+
+    /// Signals intermediate transcription (and translation) results of participants.
+    pub fn set_transcribing_cb<F>(&mut self, f: F) -> Result<()>
+    where
+        F: Fn(ConversationTranslationEvent) + 'static + Send,
+    {
+        self.callback_bag.transcribing_cb = Some(Box::new(f));
+        unsafe {
+            let ret = conversation_translator_transcribing_set_callback(
+                self.handle.inner(),
+                Some(Self::cb_transcribing),
+                &*self.callback_bag as *const _ as *mut c_void,
+            );
+            convert_err(ret, "ConversationTranslator.set_transcribing_cb error")?;
+            Ok(())
+        }
+    }
+
+    /// Signals final transcription (and translation) results of participants.
+    pub fn set_transcribed_cb<F>(&mut self, f: F) -> Result<()>
+    where
+        F: Fn(ConversationTranslationEvent) + 'static + Send,
+    {
+        self.callback_bag.transcribed_cb = Some(Box::new(f));
+        unsafe {
+            let ret = conversation_translator_transcribed_set_callback(
+                self.handle.inner(),
+                Some(Self::cb_transcribed),
+                &*self.callback_bag as *const _ as *mut c_void,
+            );
+            convert_err(ret, "ConversationTranslator.set_transcribed_cb error")?;
+            Ok(())
+        }
+    }
+
+    /// Signals text message (and its translations) sent by participant.
+    pub fn set_text_message_received_cb<F>(&mut self, f: F) -> Result<()>
+    where
+        F: Fn(ConversationTranslationEvent) + 'static + Send,
+    {
+        self.callback_bag.text_message_received_cb = Some(Box::new(f));
+        unsafe {
+            let ret = conversation_translator_text_message_recevied_set_callback(
+                self.handle.inner(),
+                Some(Self::cb_text_message_received),
+                &*self.callback_bag as *const _ as *mut c_void,
+            );
+            convert_err(
+                ret,
+                "ConversationTranslator.set_text_message_received_cb error",
+            )?;
+            Ok(())
+        }
+    }
+
+    #[allow(non_snake_case)]
+    #[allow(unused_variables)]
+    unsafe extern "C" fn cb_session_started(
+        hConvTrans: SPXCONVERSATIONTRANSLATORHANDLE,
+        hevent: SPXEVENTHANDLE,
+        pvContext: *mut c_void,
+    ) {
+        trace!("ConversationTranslator::cb_session_started called");
+        let callback_bag = &mut *(pvContext as *mut CallbackBag);
+        if let Some(cb) = &callback_bag.session_started_cb {
+            trace!("session_started_cb defined");
+            match SessionEvent::from_handle(hevent) {
+                Ok(event) => {
+                    trace!("calling cb with event {:?}", event);
+                    cb(event);
+                }
+                Err(err) => {
+                    error!("ConversationTranslator::cb_session_started error {:?}", err);
+                }
+            }
+        }
+    }
+
+    #[allow(non_snake_case)]
+    #[allow(unused_variables)]
+    unsafe extern "C" fn cb_session_stopped(
+        hConvTrans: SPXCONVERSATIONTRANSLATORHANDLE,
+        hevent: SPXEVENTHANDLE,
+        pvContext: *mut c_void,
+    ) {
+        trace!("ConversationTranslator::cb_session_stopped called");
+        let callback_bag = &mut *(pvContext as *mut CallbackBag);
+        if let Some(cb) = &callback_bag.session_stopped_cb {
+            trace!("session_stopped_cb defined");
+            match SessionEvent::from_handle(hevent) {
+                Ok(event) => {
+                    trace!("calling cb with event {:?}", event);
+                    cb(event);
+                }
+                Err(err) => {
+                    error!("ConversationTranslator::cb_session_stopped error {:?}", err);
+                }
+            }
+        }
+    }
+
+    #[allow(non_snake_case)]
+    #[allow(unused_variables)]
+    unsafe extern "C" fn cb_canceled(
+        hConvTrans: SPXCONVERSATIONTRANSLATORHANDLE,
+        hevent: SPXEVENTHANDLE,
+        pvContext: *mut c_void,
+    ) {
+        trace!("ConversationTranslator::cb_canceled called");
+        let callback_bag = &mut *(pvContext as *mut CallbackBag);
+        if let Some(cb) = &callback_bag.canceled_cb {
+            trace!("canceled_cb defined");
+            match ConversationTranslationCanceledEvent::from_handle(hevent) {
+                Ok(event) => {
+                    trace!("calling cb with event {:?}", event);
+                    cb(event);
+                }
+                Err(err) => {
+                    error!("ConversationTranslator::cb_canceled error {:?}", err);
+                }
+            }
+        }
+    }
+
+    #[allow(non_snake_case)]
+    #[allow(unused_variables)]
+    unsafe extern "C" fn cb_participants_changed(
+        hConvTrans: SPXCONVERSATIONTRANSLATORHANDLE,
+        hevent: SPXEVENTHANDLE,
+        pvContext: *mut c_void,
+    ) {
+        trace!("ConversationTranslator::cb_participants_changed called");
+        let callback_bag = &mut *(pvContext as *mut CallbackBag);
+        if let Some(cb) = &callback_bag.participants_changed_cb {
+            trace!("participants_changed_cb defined");
+            match ConversationParticipantsChangedEvent::from_handle(hevent) {
+                Ok(event) => {
+                    trace!("calling cb with event {:?}", event);
+                    cb(event);
+                }
+                Err(err) => {
+                    error!(
+                        "ConversationTranslator::cb_participants_changed error {:?}",
+                        err
+                    );
+                }
+            }
+        }
+    }
+
+    #[allow(non_snake_case)]
+    #[allow(unused_variables)]
+    unsafe extern "C" fn cb_conversation_expiration(
+        hConvTrans: SPXCONVERSATIONTRANSLATORHANDLE,
+        hevent: SPXEVENTHANDLE,
+        pvContext: *mut c_void,
+    ) {
+        trace!("ConversationTranslator::cb_conversation_expiration called");
+        let callback_bag = &mut *(pvContext as *mut CallbackBag);
+        if let Some(cb) = &callback_bag.conversation_expiration_cb {
+            trace!("conversation_expiration_cb defined");
+            match ConversationExpirationEvent::from_handle(hevent) {
+                Ok(event) => {
+                    trace!("calling cb with event {:?}", event);
+                    cb(event);
+                }
+                Err(err) => {
+                    error!(
+                        "ConversationTranslator::cb_conversation_expiration error {:?}",
+                        err
+                    );
+                }
+            }
+        }
+    }
+
+    #[allow(non_snake_case)]
+    #[allow(unused_variables)]
+    unsafe extern "C" fn cb_transcribing(
+        hConvTrans: SPXCONVERSATIONTRANSLATORHANDLE,
+        hevent: SPXEVENTHANDLE,
+        pvContext: *mut c_void,
+    ) {
+        trace!("ConversationTranslator::cb_transcribing called");
+        let callback_bag = &mut *(pvContext as *mut CallbackBag);
+        if let Some(cb) = &callback_bag.transcribing_cb {
+            trace!("transcribing_cb defined");
+            match ConversationTranslationEvent::from_handle(hevent) {
+                Ok(event) => {
+                    trace!("calling cb with event {:?}", event);
+                    cb(event);
+                }
+                Err(err) => {
+                    error!("ConversationTranslator::cb_transcribing error {:?}", err);
+                }
+            }
+        }
+    }
+
+    #[allow(non_snake_case)]
+    #[allow(unused_variables)]
+    unsafe extern "C" fn cb_transcribed(
+        hConvTrans: SPXCONVERSATIONTRANSLATORHANDLE,
+        hevent: SPXEVENTHANDLE,
+        pvContext: *mut c_void,
+    ) {
+        trace!("ConversationTranslator::cb_transcribed called");
+        let callback_bag = &mut *(pvContext as *mut CallbackBag);
+        if let Some(cb) = &callback_bag.transcribed_cb {
+            trace!("transcribed_cb defined");
+            match ConversationTranslationEvent::from_handle(hevent) {
+                Ok(event) => {
+                    trace!("calling cb with event {:?}", event);
+                    cb(event);
+                }
+                Err(err) => {
+                    error!("ConversationTranslator::cb_transcribed error {:?}", err);
+                }
+            }
+        }
+    }
+
+    #[allow(non_snake_case)]
+    #[allow(unused_variables)]
+    unsafe extern "C" fn cb_text_message_received(
+        hConvTrans: SPXCONVERSATIONTRANSLATORHANDLE,
+        hevent: SPXEVENTHANDLE,
+        pvContext: *mut c_void,
+    ) {
+        trace!("ConversationTranslator::cb_text_message_received called");
+        let callback_bag = &mut *(pvContext as *mut CallbackBag);
+        if let Some(cb) = &callback_bag.text_message_received_cb {
+            trace!("text_message_received_cb defined");
+            match ConversationTranslationEvent::from_handle(hevent) {
+                Ok(event) => {
+                    trace!("calling cb with event {:?}", event);
+                    cb(event);
+                }
+                Err(err) => {
+                    error!(
+                        "ConversationTranslator::cb_text_message_received error {:?}",
+                        err
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// Service calls keep native conversation translator alive until they return, even when
+/// their futures are dropped, so registered callbacks are disconnected before callback bag is freed.
+impl Drop for ConversationTranslator {
+    fn drop(&mut self) {
+        unsafe {
+            if self.callback_bag.session_started_cb.is_some() {
+                conversation_translator_session_started_set_callback(
+                    self.handle.inner(),
+                    None,
+                    std::ptr::null_mut(),
+                );
+            }
+            if self.callback_bag.session_stopped_cb.is_some() {
+                conversation_translator_session_stopped_set_callback(
+                    self.handle.inner(),
+                    None,
+                    std::ptr::null_mut(),
+                );
+            }
+            if self.callback_bag.canceled_cb.is_some() {
+                conversation_translator_canceled_set_callback(
+                    self.handle.inner(),
+                    None,
+                    std::ptr::null_mut(),
+                );
+            }
+            if self.callback_bag.participants_changed_cb.is_some() {
+                conversation_translator_participants_changed_set_callback(
+                    self.handle.inner(),
+                    None,
+                    std::ptr::null_mut(),
+                );
+            }
+            if self.callback_bag.conversation_expiration_cb.is_some() {
+                conversation_translator_conversation_expiration_set_callback(
+                    self.handle.inner(),
+                    None,
+                    std::ptr::null_mut(),
+                );
+            }
+            if self.callback_bag.transcribing_cb.is_some() {
+                conversation_translator_transcribing_set_callback(
+                    self.handle.inner(),
+                    None,
+                    std::ptr::null_mut(),
+                );
+            }
+            if self.callback_bag.transcribed_cb.is_some() {
+                conversation_translator_transcribed_set_callback(
+                    self.handle.inner(),
+                    None,
+                    std::ptr::null_mut(),
+                );
+            }
+            if self.callback_bag.text_message_received_cb.is_some() {
+                conversation_translator_text_message_recevied_set_callback(
+                    self.handle.inner(),
+                    None,
+                    std::ptr::null_mut(),
+                );
+            }
+        }
+    }
+}
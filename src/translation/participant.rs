@@ -0,0 +1,116 @@
+use crate::common::PropertyCollection;
+use crate::error::{Result, convert_err};
+use crate::ffi::{
+    AZACHR, SPXPARTICIPANTHANDLE, SPXPROPERTYBAGHANDLE, SmartHandle,
+    conversation_translator_participant_get_avatar,
+    conversation_translator_participant_get_displayname,
+    conversation_translator_participant_get_id, conversation_translator_participant_get_is_host,
+    conversation_translator_participant_get_is_muted,
+    conversation_translator_participant_get_is_using_tts, participant_get_property_bag,
+    participant_release_handle,
+};
+use std::ffi::CStr;
+use std::fmt;
+use std::mem::MaybeUninit;
+use std::os::raw::c_char;
+
+/// Participant represents a participant in a conversation joined by *ConversationTranslator*.
+pub struct Participant {
+    pub handle: SmartHandle<SPXPARTICIPANTHANDLE>,
+    pub id: String,
+    /// Colour of the participant's avatar as HTML hex string (e.g. #FF0000).
+    pub avatar: String,
+    pub display_name: String,
+    pub is_host: bool,
+    pub is_muted: bool,
+    /// Whether participant is using text to speech.
+    pub is_using_tts: bool,
+    pub properties: PropertyCollection,
+}
+
+impl fmt::Debug for Participant {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Participant")
+            .field("id", &self.id)
+            .field("avatar", &self.avatar)
+            .field("display_name", &self.display_name)
+            .field("is_host", &self.is_host)
+            .field("is_muted", &self.is_muted)
+            .field("is_using_tts", &self.is_using_tts)
+            .finish()
+    }
+}
+
+impl Participant {
+    /// # Safety
+    /// `handle` must be a valid handle to a live conversation translator participant.
+    pub unsafe fn from_handle(handle: SPXPARTICIPANTHANDLE) -> Result<Participant> {
+        unsafe {
+            let handle = SmartHandle::create("Participant", handle, participant_release_handle);
+
+            let id = participant_get_string(
+                handle.inner(),
+                conversation_translator_participant_get_id,
+                "Participant::from_handle(get_id) error",
+            )?;
+            let avatar = participant_get_string(
+                handle.inner(),
+                conversation_translator_participant_get_avatar,
+                "Participant::from_handle(get_avatar) error",
+            )?;
+            let display_name = participant_get_string(
+                handle.inner(),
+                conversation_translator_participant_get_displayname,
+                "Participant::from_handle(get_displayname) error",
+            )?;
+
+            let mut is_host = false;
+            let mut ret =
+                conversation_translator_participant_get_is_host(handle.inner(), &mut is_host);
+            convert_err(ret, "Participant::from_handle(get_is_host) error")?;
+
+            let mut is_muted = false;
+            ret = conversation_translator_participant_get_is_muted(handle.inner(), &mut is_muted);
+            convert_err(ret, "Participant::from_handle(get_is_muted) error")?;
+
+            let mut is_using_tts = false;
+            ret = conversation_translator_participant_get_is_using_tts(
+                handle.inner(),
+                &mut is_using_tts,
+            );
+            convert_err(ret, "Participant::from_handle(get_is_using_tts) error")?;
+
+            let mut prop_bag_handle: MaybeUninit<SPXPROPERTYBAGHANDLE> = MaybeUninit::uninit();
+            ret = participant_get_property_bag(handle.inner(), prop_bag_handle.as_mut_ptr());
+            convert_err(ret, "Participant::from_handle(get_property_bag) error")?;
+            let properties = PropertyCollection::from_handle(prop_bag_handle.assume_init());
+
+            Ok(Participant {
+                handle,
+                id,
+                avatar,
+                display_name,
+                is_host,
+                is_muted,
+                is_using_tts,
+                properties,
+            })
+        }
+    }
+}
+
+/// Reads string attribute of participant using one of the native
+/// conversation_translator_participant_get_* getters.
+unsafe fn participant_get_string(
+    handle: SPXPARTICIPANTHANDLE,
+    getter: unsafe extern "C" fn(SPXPARTICIPANTHANDLE, *mut c_char, *mut u32) -> AZACHR,
+    err_msg: &str,
+) -> Result<String> {
+    unsafe {
+        let mut c_buf = [0; 1024];
+        let mut len = c_buf.len() as u32;
+        let ret = getter(handle, c_buf.as_mut_ptr(), &mut len);
+        convert_err(ret, err_msg)?;
+        Ok(CStr::from_ptr(c_buf.as_ptr()).to_str()?.to_owned())
+    }
+}
@@ -0,0 +1,35 @@
+/// ParticipantChangedReason defines why a participant changed in conversation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParticipantChangedReason {
+    /// JoinedConversation indicates participant joined the conversation.
+    JoinedConversation = 0,
+
+    /// LeftConversation indicates participant left the conversation.
+    /// This could be voluntary, or involuntary (e.g. they are experiencing networking issues).
+    LeftConversation = 1,
+
+    /// Updated indicates the participants' state has changed (e.g. they became muted, changed their nickname).
+    Updated = 2,
+}
+
+impl ParticipantChangedReason {
+    pub fn from_u32(reason: u32) -> Self {
+        match reason {
+            0 => ParticipantChangedReason::JoinedConversation,
+            1 => ParticipantChangedReason::LeftConversation,
+            _ => ParticipantChangedReason::Updated,
+        }
+    }
+}
+
+impl From<u32> for ParticipantChangedReason {
+    fn from(value: u32) -> Self {
+        ParticipantChangedReason::from_u32(value)
+    }
+}
+
+impl From<i32> for ParticipantChangedReason {
+    fn from(value: i32) -> Self {
+        ParticipantChangedReason::from_u32(value as u32)
+    }
+}
@@ -8,8 +8,10 @@ use crate::ffi::{
     speech_config_set_profanity, speech_config_set_service_property,
     speech_translation_config_add_target_language,
     speech_translation_config_from_authorization_token, speech_translation_config_from_endpoint,
-    speech_translation_config_from_host, speech_translation_config_from_subscription, SmartHandle,
-    SPXHANDLE, SPXPROPERTYBAGHANDLE, SPXSPEECHCONFIGHANDLE,
+    speech_translation_config_from_host, speech_translation_config_from_subscription,
+    speech_translation_config_remove_target_language,
+    speech_translation_config_set_custom_model_category_id, SmartHandle, SPXHANDLE,
+    SPXPROPERTYBAGHANDLE, SPXSPEECHCONFIGHANDLE,
 };
 // use crate::speech::EmbeddedSpeechConfig;
 use std::ffi::CString;
@@ -490,4 +492,56 @@ impl SpeechTranslationConfig {
             Ok(())
         }
     }
+
+    /// Removes target language for translation.
+    pub fn remove_target_language<S>(&mut self, target_lang: S) -> Result<()>
+    where
+        S: Into<Vec<u8>>,
+    {
+        unsafe {
+            let c_target_lang = CString::new(target_lang)?;
+            let ret = speech_translation_config_remove_target_language(
+                self.handle.inner(),
+                c_target_lang.as_ptr(),
+            );
+            convert_err(ret, "SpeechTranslationConfig.remove_target_language error")?;
+            Ok(())
+        }
+    }
+
+    /// Gets target languages for translation.
+    pub fn get_target_languages(&self) -> Result<Vec<String>> {
+        let languages =
+            self.get_property(PropertyId::SpeechServiceConnectionTranslationToLanguages)?;
+        Ok(split_languages(&languages))
+    }
+
+    /// Sets category id of custom translator model (Custom Translator project category).
+    pub fn set_custom_model_category_id<S>(&mut self, category_id: S) -> Result<()>
+    where
+        S: Into<Vec<u8>>,
+    {
+        unsafe {
+            let c_category_id = CString::new(category_id)?;
+            let ret = speech_translation_config_set_custom_model_category_id(
+                self.handle.inner(),
+                c_category_id.as_ptr(),
+            );
+            convert_err(
+                ret,
+                "SpeechTranslationConfig.set_custom_model_category_id error",
+            )?;
+            Ok(())
+        }
+    }
+}
+
+/// Splits comma separated list of target languages.
+pub(crate) fn split_languages(languages: &str) -> Vec<String> {
+    languages
+        .split(',')
+        .map(str::trim)
+        .filter(|lang| !lang.is_empty())
+        .map(ToString::to_string)
+        .collect()
 }
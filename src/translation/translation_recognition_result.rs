@@ -1,4 +1,4 @@
-use crate::common::{PropertyCollection, ResultReason};
+use crate::common::{PropertyCollection, PropertyId, ResultReason, SpeechBackend, Ticks};
 use crate::error::{convert_err, Result};
 use crate::ffi::{
     recognizer_result_handle_release, result_get_duration, result_get_offset,
@@ -6,10 +6,12 @@ use crate::ffi::{
     translation_text_result_get_translation, translation_text_result_get_translation_count,
     SmartHandle, SPXPROPERTYBAGHANDLE, SPXRESULTHANDLE,
 };
+use crate::speech::DetailedRecognitionResult;
 use std::collections::HashMap;
 use std::ffi::CStr;
 use std::fmt;
 use std::mem::MaybeUninit;
+use std::time::Duration;
 
 /// Represents translation recognition result contained within callback event *TranslationRecognitionEvent*.
 pub struct TranslationRecognitionResult {
@@ -17,8 +19,8 @@ pub struct TranslationRecognitionResult {
     pub result_id: String,
     pub reason: ResultReason,
     pub text: String,
-    pub duration: String, //TBD: change to duration
-    pub offset: String,   // TBD: change to duration
+    pub duration: Duration,
+    pub offset: Duration,
     pub translations: HashMap<String, String>,
     pub properties: PropertyCollection,
 }
@@ -144,11 +146,25 @@ impl TranslationRecognitionResult {
                 result_id,
                 reason: reason.into(),
                 text: result_text,
-                duration: (duration).to_string(),
-                offset: (offset).to_string(),
+                duration: Duration::from_ticks(duration),
+                offset: Duration::from_ticks(offset),
                 translations,
                 properties,
             })
         }
     }
+
+    /// Returns backend (cloud or embedded) which produced the result.
+    pub fn backend(&self) -> Result<SpeechBackend> {
+        let value = self
+            .properties
+            .get_property(PropertyId::SpeechServiceResponseRecognitionBackend, "")?;
+        Ok(SpeechBackend::from_property(&value))
+    }
+
+    /// Returns detailed result (alternatives, word timings, detected language)
+    /// parsed from JSON result. Returns None if JSON result is not available.
+    pub fn detailed_result(&self) -> Result<Option<DetailedRecognitionResult>> {
+        DetailedRecognitionResult::from_properties(&self.properties)
+    }
 }
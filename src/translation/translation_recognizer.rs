@@ -1,16 +1,21 @@
+use super::speech_translation_config::split_languages;
 use super::{
     SpeechTranslationConfig, TranslationRecognitionCanceledEvent, TranslationRecognitionEvent,
-    TranslationRecognitionResult, TranslationSynthesisEvent,
+    TranslationRecognitionResult, TranslationRecognizerEvent, TranslationSynthesisEvent,
 };
 use crate::audio::AudioConfig;
-use crate::common::{PropertyCollection, PropertyId};
+use crate::common::{
+    EventBuffering, EventStream, PropertyCollection, PropertyId, TokenProvider, apply_token,
+    event_channel,
+};
 use crate::error::{Result, convert_err};
 use crate::ffi::{
     SPXASYNCHANDLE, SPXEVENTHANDLE, SPXPROPERTYBAGHANDLE, SPXRECOHANDLE, SPXRESULTHANDLE,
     SmartHandle, recognizer_async_handle_release, recognizer_canceled_set_callback,
     recognizer_create_translation_recognizer_from_auto_detect_source_lang_config,
-    recognizer_create_translation_recognizer_from_config, recognizer_get_property_bag,
-    recognizer_handle_release, recognizer_recognize_once, recognizer_recognized_set_callback,
+    recognizer_create_translation_recognizer_from_config, recognizer_event_handle_release,
+    recognizer_get_property_bag, recognizer_handle_release, recognizer_recognize_once_async,
+    recognizer_recognize_once_async_wait_for, recognizer_recognized_set_callback,
     recognizer_recognizing_set_callback, recognizer_session_started_set_callback,
     recognizer_session_stopped_set_callback, recognizer_speech_end_detected_set_callback,
     recognizer_speech_start_detected_set_callback, recognizer_start_continuous_recognition_async,
@@ -20,14 +25,16 @@ use crate::ffi::{
     recognizer_stop_continuous_recognition_async,
     recognizer_stop_continuous_recognition_async_wait_for,
     recognizer_stop_keyword_recognition_async, recognizer_stop_keyword_recognition_async_wait_for,
-    translator_synthesizing_audio_set_callback,
+    recognizer_token_requested_set_callback, translator_add_target_language,
+    translator_remove_target_language, translator_synthesizing_audio_set_callback, wait_for_async,
 };
 use crate::speech::{
-    AutoDetectSourceLanguageConfig, EmbeddedSpeechConfig, KeywordRecognitionModel,
-    RecognitionEvent, SessionEvent,
+    AutoDetectSourceLanguageConfig, EmbeddedSpeechConfig, HybridSpeechConfig,
+    KeywordRecognitionModel, RecognitionEvent, Recognizer, SealedRecognizer, SessionEvent,
 };
 use log::*;
 use std::boxed::Box;
+use std::ffi::CString;
 use std::fmt;
 use std::mem::MaybeUninit;
 use std::os::raw::c_void;
@@ -45,16 +52,13 @@ struct CallbackBag {
     recognizing_cb: Option<Box<dyn Fn(TranslationRecognitionEvent) + Send>>,
     recognized_cb: Option<Box<dyn Fn(TranslationRecognitionEvent) + Send>>,
     synthesizing_cb: Option<Box<dyn Fn(TranslationSynthesisEvent) + Send>>,
+    token_provider: Option<Box<dyn TokenProvider>>,
 }
 
 /// TranslationRecognizer struct holds functionality for speech-to-speech translation.
 pub struct TranslationRecognizer {
     pub(crate) handle: SmartHandle<SPXRECOHANDLE>,
     properties: PropertyCollection,
-    handle_async_start_continuous: Option<SmartHandle<SPXASYNCHANDLE>>,
-    handle_async_stop_continuous: Option<SmartHandle<SPXASYNCHANDLE>>,
-    handle_async_start_keyword: Option<SmartHandle<SPXASYNCHANDLE>>,
-    handle_async_stop_keyword: Option<SmartHandle<SPXASYNCHANDLE>>,
     callback_bag: Box<CallbackBag>,
 }
 
@@ -67,6 +71,14 @@ impl fmt::Debug for TranslationRecognizer {
     }
 }
 
+impl SealedRecognizer for TranslationRecognizer {}
+
+impl Recognizer for TranslationRecognizer {
+    fn get_handle(&self) -> SPXRECOHANDLE {
+        self.handle.inner()
+    }
+}
+
 impl TranslationRecognizer {
     /// # Safety
     /// `handle` must be a valid handle to a live translation recognizer.
@@ -85,10 +97,6 @@ impl TranslationRecognizer {
                     recognizer_handle_release,
                 ),
                 properties: property_bag,
-                handle_async_start_continuous: None,
-                handle_async_stop_continuous: None,
-                handle_async_start_keyword: None,
-                handle_async_stop_keyword: None,
                 // Here we return a boxed instance of the CallbackBag,
                 // ensure that the pointer we provide to the C library
                 // points to a stable, heap-allocated location that holds the callbacks.
@@ -101,6 +109,7 @@ impl TranslationRecognizer {
                     recognizing_cb: None,
                     recognized_cb: None,
                     synthesizing_cb: None,
+                    token_provider: None,
                 }),
             };
             Ok(result)
@@ -143,6 +152,26 @@ impl TranslationRecognizer {
         }
     }
 
+    /// Creates translation recognizer which uses the speech service when it is reachable
+    /// and falls back to embedded models otherwise.
+    pub fn from_hybrid_config(
+        speech_config: HybridSpeechConfig,
+        audio_config: AudioConfig,
+    ) -> Result<TranslationRecognizer> {
+        unsafe {
+            let mut handle: MaybeUninit<SPXRECOHANDLE> = MaybeUninit::uninit();
+            convert_err(
+                recognizer_create_translation_recognizer_from_config(
+                    handle.as_mut_ptr(),
+                    speech_config.config.handle.inner(),
+                    audio_config.handle.inner(),
+                ),
+                "TranslationRecognizer.from_hybrid_config error",
+            )?;
+            TranslationRecognizer::from_handle(handle.assume_init())
+        }
+    }
+
     pub fn from_auto_detect_source_lang_config(
         speech_translation_config: SpeechTranslationConfig,
         audio_config: AudioConfig,
@@ -194,6 +223,48 @@ impl TranslationRecognizer {
     }
     */
 
+    /// Returns stream of all recognizer events so that they can be consumed
+    /// from async code instead of registering individual callbacks.
+    /// Replaces callbacks previously set via *set_*_cb* methods.
+    /// Stream ends when the recognizer is dropped.
+    pub fn events(
+        &mut self,
+        buffering: EventBuffering,
+    ) -> Result<EventStream<TranslationRecognizerEvent>> {
+        let (sender, stream) = event_channel(buffering);
+        let tx = sender.clone();
+        self.set_session_started_cb(move |event| {
+            tx.send(TranslationRecognizerEvent::SessionStarted(event))
+        })?;
+        let tx = sender.clone();
+        self.set_session_stopped_cb(move |event| {
+            tx.send(TranslationRecognizerEvent::SessionStopped(event))
+        })?;
+        let tx = sender.clone();
+        self.set_speech_start_detected_cb(move |event| {
+            tx.send(TranslationRecognizerEvent::SpeechStartDetected(event))
+        })?;
+        let tx = sender.clone();
+        self.set_speech_end_detected_cb(move |event| {
+            tx.send(TranslationRecognizerEvent::SpeechEndDetected(event))
+        })?;
+        let tx = sender.clone();
+        self.set_recognizing_cb(move |event| {
+            tx.send(TranslationRecognizerEvent::Recognizing(event))
+        })?;
+        let tx = sender.clone();
+        self.set_recognized_cb(move |event| {
+            tx.send(TranslationRecognizerEvent::Recognized(event))
+        })?;
+        let tx = sender.clone();
+        self.set_canceled_cb(move |event| tx.send(TranslationRecognizerEvent::Canceled(event)))?;
+        let tx = sender;
+        self.set_synthesizing_cb(move |event| {
+            tx.send(TranslationRecognizerEvent::Synthesizing(event))
+        })?;
+        Ok(stream)
+    }
+
     pub fn set_session_started_cb<F>(&mut self, f: F) -> Result<()>
     where
         F: Fn(SessionEvent) + 'static + Send,
@@ -399,7 +470,10 @@ impl TranslationRecognizer {
                     cb(event);
                 }
                 Err(err) => {
-                    error!("TranslationRecognizer::cb_speech_start_detected error {:?}", err);
+                    error!(
+                        "TranslationRecognizer::cb_speech_start_detected error {:?}",
+                        err
+                    );
                 }
             }
         }
@@ -422,7 +496,10 @@ impl TranslationRecognizer {
                     cb(event);
                 }
                 Err(err) => {
-                    error!("TranslationRecognizer::cb_speech_end_detected error {:?}", err);
+                    error!(
+                        "TranslationRecognizer::cb_speech_end_detected error {:?}",
+                        err
+                    );
                 }
             }
         }
@@ -527,20 +604,39 @@ impl TranslationRecognizer {
     /// shot recognition like command or query.
     /// For long-running multi-utterance recognition, use StartContinuousRecognitionAsync() instead.
     pub async fn recognize_once_async(&mut self) -> Result<TranslationRecognitionResult> {
-        unsafe {
-            let mut handle_result: MaybeUninit<SPXRESULTHANDLE> = MaybeUninit::uninit();
-            let ret = recognizer_recognize_once(self.handle.inner(), handle_result.as_mut_ptr());
+        let handle_async = unsafe {
+            let mut handle_async: MaybeUninit<SPXASYNCHANDLE> = MaybeUninit::uninit();
+            let ret =
+                recognizer_recognize_once_async(self.handle.inner(), handle_async.as_mut_ptr());
             convert_err(ret, "TranslationRecognizer.recognize_once_async error")?;
+            SmartHandle::create(
+                "handle_async_recognize_once",
+                handle_async.assume_init(),
+                recognizer_async_handle_release,
+            )
+        };
+        wait_for_async(handle_async, |handle_async| unsafe {
+            let mut handle_result: MaybeUninit<SPXRESULTHANDLE> = MaybeUninit::uninit();
+            let ret = recognizer_recognize_once_async_wait_for(
+                handle_async,
+                u32::MAX,
+                handle_result.as_mut_ptr(),
+            );
+            convert_err(
+                ret,
+                "TranslationRecognizer.recognizer_recognize_once_async_wait_for error",
+            )?;
             TranslationRecognitionResult::from_handle(handle_result.assume_init())
-        }
+        })
+        .await
     }
 
     /// Asynchronously initiates continuous translation recognition operation.
     pub async fn start_continuous_recognition_async(&mut self) -> Result<()> {
-        unsafe {
+        let handle_async_start_continuous = unsafe {
             let mut handle_async_start_continuous: MaybeUninit<SPXASYNCHANDLE> =
                 MaybeUninit::uninit();
-            let mut ret = recognizer_start_continuous_recognition_async(
+            let ret = recognizer_start_continuous_recognition_async(
                 self.handle.inner(),
                 handle_async_start_continuous.as_mut_ptr(),
             );
@@ -548,30 +644,28 @@ impl TranslationRecognizer {
                 ret,
                 "TranslationRecognizer.recognizer_start_continuous_recognition_async error",
             )?;
-            self.handle_async_start_continuous = Some(SmartHandle::create(
+            SmartHandle::create(
                 "handle_async_start_continuous",
                 handle_async_start_continuous.assume_init(),
                 recognizer_async_handle_release,
-            ));
-
-            ret = recognizer_start_continuous_recognition_async_wait_for(
-                handle_async_start_continuous.assume_init(),
-                u32::MAX,
-            );
+            )
+        };
+        wait_for_async(handle_async_start_continuous, |handle_async| unsafe {
+            let ret = recognizer_start_continuous_recognition_async_wait_for(handle_async, u32::MAX);
             convert_err(
                 ret,
                 "TranslationRecognizer.recognizer_start_continuous_recognition_async_wait_for error",
-            )?;
-        }
-        Ok(())
+            )
+        })
+        .await
     }
 
     /// Asynchronously terminates ongoing continuous translation recognition operation.
     pub async fn stop_continuous_recognition_async(&mut self) -> Result<()> {
-        unsafe {
+        let handle_async_stop_continuous = unsafe {
             let mut handle_async_stop_continuous: MaybeUninit<SPXASYNCHANDLE> =
                 MaybeUninit::uninit();
-            let mut ret = recognizer_stop_continuous_recognition_async(
+            let ret = recognizer_stop_continuous_recognition_async(
                 self.handle.inner(),
                 handle_async_stop_continuous.as_mut_ptr(),
             );
@@ -579,22 +673,20 @@ impl TranslationRecognizer {
                 ret,
                 "TranslationRecognizer.recognizer_stop_continuous_recognition_async error",
             )?;
-            self.handle_async_stop_continuous = Some(SmartHandle::create(
+            SmartHandle::create(
                 "handle_async_stop_continuous",
                 handle_async_stop_continuous.assume_init(),
                 recognizer_async_handle_release,
-            ));
-
-            ret = recognizer_stop_continuous_recognition_async_wait_for(
-                handle_async_stop_continuous.assume_init(),
-                u32::MAX,
-            );
+            )
+        };
+        wait_for_async(handle_async_stop_continuous, |handle_async| unsafe {
+            let ret = recognizer_stop_continuous_recognition_async_wait_for(handle_async, u32::MAX);
             convert_err(
                 ret,
                 "TranslationRecognizer.recognizer_stop_continuous_recognition_async_wait_for error",
-            )?;
-        }
-        Ok(())
+            )
+        })
+        .await
     }
 
     /// Asynchronously initiates keyword recognition operation.
@@ -602,9 +694,9 @@ impl TranslationRecognizer {
         &mut self,
         model: KeywordRecognitionModel,
     ) -> Result<()> {
-        unsafe {
+        let handle_async_start_keyword = unsafe {
             let mut handle_async_start_keyword: MaybeUninit<SPXASYNCHANDLE> = MaybeUninit::uninit();
-            let mut ret = recognizer_start_keyword_recognition_async(
+            let ret = recognizer_start_keyword_recognition_async(
                 self.handle.inner(),
                 model.handle.inner(),
                 handle_async_start_keyword.as_mut_ptr(),
@@ -613,30 +705,27 @@ impl TranslationRecognizer {
                 ret,
                 "TranslationRecognizer.recognizer_start_keyword_recognition_async error",
             )?;
-
-            self.handle_async_start_keyword = Some(SmartHandle::create(
+            SmartHandle::create(
                 "handle_async_start_keyword",
                 handle_async_start_keyword.assume_init(),
                 recognizer_async_handle_release,
-            ));
-
-            ret = recognizer_start_keyword_recognition_async_wait_for(
-                handle_async_start_keyword.assume_init(),
-                u32::MAX,
-            );
+            )
+        };
+        wait_for_async(handle_async_start_keyword, |handle_async| unsafe {
+            let ret = recognizer_start_keyword_recognition_async_wait_for(handle_async, u32::MAX);
             convert_err(
                 ret,
                 "TranslationRecognizer.recognizer_start_keyword_recognition_async_wait_for error",
-            )?;
-        }
-        Ok(())
+            )
+        })
+        .await
     }
 
     /// Asynchronously terminates keyword recognition operation.
     pub async fn stop_keyword_recognition_async(&mut self) -> Result<()> {
-        unsafe {
+        let handle_async_stop_keyword = unsafe {
             let mut handle_async_stop_keyword: MaybeUninit<SPXASYNCHANDLE> = MaybeUninit::uninit();
-            let mut ret = recognizer_stop_keyword_recognition_async(
+            let ret = recognizer_stop_keyword_recognition_async(
                 self.handle.inner(),
                 handle_async_stop_keyword.as_mut_ptr(),
             );
@@ -644,23 +733,20 @@ impl TranslationRecognizer {
                 ret,
                 "TranslationRecognizer.recognizer_stop_keyword_recognition_async error",
             )?;
-
-            self.handle_async_stop_keyword = Some(SmartHandle::create(
+            SmartHandle::create(
                 "handle_async_stop_keyword",
                 handle_async_stop_keyword.assume_init(),
                 recognizer_async_handle_release,
-            ));
-
-            ret = recognizer_stop_keyword_recognition_async_wait_for(
-                handle_async_stop_keyword.assume_init(),
-                u32::MAX,
-            );
+            )
+        };
+        wait_for_async(handle_async_stop_keyword, |handle_async| unsafe {
+            let ret = recognizer_stop_keyword_recognition_async_wait_for(handle_async, u32::MAX);
             convert_err(
                 ret,
                 "TranslationRecognizer.recognizer_stop_keyword_recognition_async_wait_for error",
-            )?;
-        }
-        Ok(())
+            )
+        })
+        .await
     }
 
     pub fn get_endpoint_id(&self) -> Result<String> {
@@ -681,4 +767,147 @@ impl TranslationRecognizer {
         self.properties
             .set_property(PropertyId::SpeechServiceAuthorizationToken, token)
     }
+
+    /// Sets authorization token provider. Token is fetched from the provider right away
+    /// and then whenever the native layer requests a new one, so that long running
+    /// continuous recognition survives token rotation without calling *set_auth_token* periodically.
+    pub fn set_token_provider<P>(&mut self, provider: P) -> Result<()>
+    where
+        P: TokenProvider + 'static,
+    {
+        apply_token(&provider, &mut self.properties)?;
+        self.callback_bag.token_provider = Some(Box::new(provider));
+        unsafe {
+            let ret = recognizer_token_requested_set_callback(
+                self.handle.inner(),
+                Some(Self::cb_token_requested),
+                &*self.callback_bag as *const _ as *mut c_void,
+            );
+            convert_err(ret, "TranslationRecognizer.set_token_provider error")?;
+            Ok(())
+        }
+    }
+
+    #[allow(non_snake_case)]
+    #[allow(unused_variables)]
+    unsafe extern "C" fn cb_token_requested(
+        hreco: SPXRECOHANDLE,
+        hevent: SPXEVENTHANDLE,
+        pvContext: *mut c_void,
+    ) {
+        trace!("TranslationRecognizer::cb_token_requested called");
+        // event carries no data, just make sure it gets released
+        let _event = SmartHandle::create(
+            "TokenRequestedEvent",
+            hevent,
+            recognizer_event_handle_release,
+        );
+        let callback_bag = &mut *(pvContext as *mut CallbackBag);
+        if let Some(provider) = &callback_bag.token_provider {
+            let mut prop_bag_handle: MaybeUninit<SPXPROPERTYBAGHANDLE> = MaybeUninit::uninit();
+            let ret = recognizer_get_property_bag(hreco, prop_bag_handle.as_mut_ptr());
+            let result = convert_err(ret, "TranslationRecognizer::cb_token_requested error")
+                .and_then(|_| {
+                    let mut properties =
+                        PropertyCollection::from_handle(prop_bag_handle.assume_init());
+                    apply_token(provider.as_ref(), &mut properties)
+                });
+            if let Err(err) = result {
+                error!("TranslationRecognizer::cb_token_requested error {:?}", err);
+            }
+        }
+    }
+
+    /// Adds target language for translation while recognizer is running,
+    /// e.g. when attendee with new language joins live event.
+    pub fn add_target_language(&mut self, target_lang: &str) -> Result<()> {
+        unsafe {
+            let c_target_lang = CString::new(target_lang)?;
+            let ret = translator_add_target_language(self.handle.inner(), c_target_lang.as_ptr());
+            convert_err(ret, "TranslationRecognizer.add_target_language error")
+        }
+    }
+
+    /// Removes target language for translation while recognizer is running.
+    pub fn remove_target_language(&mut self, target_lang: &str) -> Result<()> {
+        unsafe {
+            let c_target_lang = CString::new(target_lang)?;
+            let ret =
+                translator_remove_target_language(self.handle.inner(), c_target_lang.as_ptr());
+            convert_err(ret, "TranslationRecognizer.remove_target_language error")
+        }
+    }
+
+    /// Gets current target languages for translation.
+    pub fn target_languages(&self) -> Result<Vec<String>> {
+        let languages = self.properties.get_property(
+            PropertyId::SpeechServiceConnectionTranslationToLanguages,
+            "",
+        )?;
+        Ok(split_languages(&languages))
+    }
+}
+
+/// Disconnects registered callbacks before callback bag is freed, as native recognizer
+/// can outlive this struct when future of pending recognition is dropped.
+impl Drop for TranslationRecognizer {
+    fn drop(&mut self) {
+        unsafe {
+            if self.callback_bag.session_started_cb.is_some() {
+                recognizer_session_started_set_callback(
+                    self.handle.inner(),
+                    None,
+                    std::ptr::null_mut(),
+                );
+            }
+            if self.callback_bag.session_stopped_cb.is_some() {
+                recognizer_session_stopped_set_callback(
+                    self.handle.inner(),
+                    None,
+                    std::ptr::null_mut(),
+                );
+            }
+            if self.callback_bag.speech_start_detected_cb.is_some() {
+                recognizer_speech_start_detected_set_callback(
+                    self.handle.inner(),
+                    None,
+                    std::ptr::null_mut(),
+                );
+            }
+            if self.callback_bag.speech_end_detected_cb.is_some() {
+                recognizer_speech_end_detected_set_callback(
+                    self.handle.inner(),
+                    None,
+                    std::ptr::null_mut(),
+                );
+            }
+            if self.callback_bag.canceled_cb.is_some() {
+                recognizer_canceled_set_callback(self.handle.inner(), None, std::ptr::null_mut());
+            }
+            if self.callback_bag.recognizing_cb.is_some() {
+                recognizer_recognizing_set_callback(
+                    self.handle.inner(),
+                    None,
+                    std::ptr::null_mut(),
+                );
+            }
+            if self.callback_bag.recognized_cb.is_some() {
+                recognizer_recognized_set_callback(self.handle.inner(), None, std::ptr::null_mut());
+            }
+            if self.callback_bag.synthesizing_cb.is_some() {
+                translator_synthesizing_audio_set_callback(
+                    self.handle.inner(),
+                    None,
+                    std::ptr::null_mut(),
+                );
+            }
+            if self.callback_bag.token_provider.is_some() {
+                recognizer_token_requested_set_callback(
+                    self.handle.inner(),
+                    None,
+                    std::ptr::null_mut(),
+                );
+            }
+        }
+    }
 }
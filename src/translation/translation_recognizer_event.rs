@@ -0,0 +1,18 @@
+use super::{
+    TranslationRecognitionCanceledEvent, TranslationRecognitionEvent, TranslationSynthesisEvent,
+};
+use crate::speech::{RecognitionEvent, SessionEvent};
+
+/// Event yielded by stream returned from *TranslationRecognizer::events*.
+/// Each variant corresponds to one of the *TranslationRecognizer::set_*_cb* callbacks.
+#[derive(Debug)]
+pub enum TranslationRecognizerEvent {
+    SessionStarted(SessionEvent),
+    SessionStopped(SessionEvent),
+    SpeechStartDetected(RecognitionEvent),
+    SpeechEndDetected(RecognitionEvent),
+    Recognizing(TranslationRecognitionEvent),
+    Recognized(TranslationRecognitionEvent),
+    Canceled(TranslationRecognitionCanceledEvent),
+    Synthesizing(TranslationSynthesisEvent),
+}
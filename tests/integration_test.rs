@@ -1,8 +1,40 @@
 use cognitive_services_speech_sdk_rs::{
-    audio::AudioConfig,
-    speech::{PhraseListGrammar, SpeechConfig, SpeechRecognizer},
+    audio::{
+        AudioConfig, AudioProcessingFlags, AudioProcessingOptions, AudioStreamFormat,
+        ChannelPullSource, ChannelPushSink, MicrophoneArrayGeometry, MicrophoneArrayType,
+        MicrophoneCoordinates, PullAudioInputStream, PullAudioInputStreamCallbacks,
+        PushAudioInputStream, PushAudioOutputStream, PushAudioOutputStreamCallbacks,
+        ReaderPullSource, SpeakerReferenceChannel, VecPushSink, WavFileInput, WavFilePushSink,
+        WavSampleFormat, WAV_INPUT_SAMPLES_PER_SECOND,
+    },
+    common::{
+        CachingTokenProvider, EventBuffering, OutputFormat, PronunciationAssessmentGradingSystem,
+        PronunciationAssessmentGranularity, RecognitionFactorScope, ResultReason, SpeechBackend,
+        SpeechSynthesisOutputFormat, Ticks,
+    },
+    diagnostics::MemoryLogger,
+    dialog::{Activity, ActivityType, InputHint},
+    error::{Error, ErrorRootCause},
+    intent::{
+        IntentRecognizer, PatternMatchingEntity, PatternMatchingIntent, PatternMatchingModel,
+    },
+    speaker::{VoiceProfileClient, VoiceProfileType},
+    speech::{
+        AudioDataStream, AutoDetectSourceLanguageConfig, Connection, EmbeddedSpeechConfig,
+        GrammarList, HybridSpeechConfig, KeywordRecognitionModel, KeywordRecognizer,
+        PhraseListGrammar, PronunciationAssessmentConfig, PronunciationAssessmentResult,
+        SourceLanguageRecognizer, SpeechConfig, SpeechRecognizer, SpeechRecognizerEvent,
+        SpeechSynthesizer,
+    },
+    transcription::{ConversationTranscriber, Meeting, MeetingTranscriber, Participant, User},
+    translation::{
+        Conversation, ConversationTranslator, SpeechTranslationConfig, TranslationRecognizer,
+    },
 };
 use log::{error, *};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
 use std::{env, path::PathBuf};
 mod common;
 use common::*;
@@ -75,3 +107,930 @@ async fn phrase_list_test() {
     );
     assert!(result.text.to_lowercase().contains("peloozoid"));
 }
+
+#[tokio::test]
+async fn translation_phrase_list_test() {
+    let audio_config = AudioConfig::from_wav_file_input(&get_sample_file("peloozoid.wav")).unwrap();
+    let mut translation_config = SpeechTranslationConfig::from_subscription(
+        env::var("MSSubscriptionKey").unwrap(),
+        env::var("MSServiceRegion").unwrap(),
+    )
+    .unwrap();
+    translation_config
+        .set_speech_recognition_language("en-US")
+        .unwrap();
+    translation_config.add_target_language("de").unwrap();
+
+    let mut translation_recognizer =
+        TranslationRecognizer::from_config(translation_config, audio_config).unwrap();
+
+    let phrase_list = PhraseListGrammar::from_recognizer(&translation_recognizer).unwrap();
+    phrase_list.add_phrase("peloozoid").unwrap();
+    phrase_list.set_weight(1.5).unwrap();
+    let grammar_list = GrammarList::from_recognizer(&translation_recognizer).unwrap();
+    grammar_list
+        .set_recognition_factor(1.0, RecognitionFactorScope::PartialPhrase)
+        .unwrap();
+
+    let result = translation_recognizer.recognize_once_async().await.unwrap();
+    info!("got translation result {result:?}");
+    assert!(result.text.to_lowercase().contains("peloozoid"));
+}
+
+#[tokio::test]
+async fn pronunciation_assessment_test() {
+    let file_path_str = &get_sample_file("myVoiceIsMyPassportVerifyMe01.wav");
+    let mut speech_recognizer = speech_recognizer_from_wav_file(file_path_str);
+
+    let mut pa_config = PronunciationAssessmentConfig::new(
+        "My voice is my passport, verify me.",
+        PronunciationAssessmentGradingSystem::HundredMark,
+        PronunciationAssessmentGranularity::Phoneme,
+        true,
+    )
+    .unwrap();
+    pa_config.enable_prosody_assessment().unwrap();
+    pa_config.apply_to_recognizer(&speech_recognizer).unwrap();
+
+    let result = speech_recognizer.recognize_once_async().await.unwrap();
+    let assessment = PronunciationAssessmentResult::from_result(&result)
+        .unwrap()
+        .unwrap();
+    info!("got pronunciation assessment {assessment:?}");
+    assert!(assessment.pronunciation_score > 0.0);
+    assert_eq!(assessment.words.len(), 6);
+    assert!(!assessment.words[0].phonemes.is_empty());
+    assert!(assessment.prosody_score.is_some());
+    let prosody = assessment.words[0].prosody_feedback.as_ref().unwrap();
+    assert!(!prosody.break_feedback.error_types.is_empty());
+}
+
+#[tokio::test]
+async fn pattern_matching_intent_test() {
+    let audio_config =
+        AudioConfig::from_wav_file_input(&get_sample_file("turn_on_the_lamp.wav")).unwrap();
+    let speech_config = SpeechConfig::from_subscription(
+        env::var("MSSubscriptionKey").unwrap(),
+        env::var("MSServiceRegion").unwrap(),
+    )
+    .unwrap();
+    let mut intent_recognizer = IntentRecognizer::from_config(speech_config, audio_config).unwrap();
+
+    let mut model = PatternMatchingModel::new("LampModel");
+    model.add_intent(PatternMatchingIntent::new(
+        "TurnOn",
+        vec!["turn on the {device}".to_string()],
+    ));
+    model.add_entity(PatternMatchingEntity::StrictList {
+        id: "device".to_string(),
+        phrases: vec!["lamp".to_string(), "radio".to_string()],
+    });
+    intent_recognizer
+        .apply_language_models(&[model.into()])
+        .unwrap();
+
+    let result = intent_recognizer.recognize_once_async().await.unwrap();
+    info!("got intent recognition {result:?}");
+    assert_eq!(result.intent_id, "TurnOn");
+    assert_eq!(
+        result.entities.get("device").map(|s| s.as_str()),
+        Some("lamp")
+    );
+}
+
+#[tokio::test]
+async fn speaker_verification_test() {
+    let speech_config = SpeechConfig::from_subscription(
+        env::var("MSSubscriptionKey").unwrap(),
+        env::var("MSServiceRegion").unwrap(),
+    )
+    .unwrap();
+    let client = VoiceProfileClient::from_config(speech_config).unwrap();
+    let profile = client
+        .create_profile_async(VoiceProfileType::TextIndependentVerification, "en-us")
+        .await
+        .unwrap();
+    info!("created voice profile {}", profile.id().unwrap());
+
+    let audio_config =
+        AudioConfig::from_wav_file_input(&get_sample_file("TalkForAFewSeconds16.wav")).unwrap();
+    let enrollment = client
+        .enroll_profile_async(&profile, audio_config)
+        .await
+        .unwrap();
+    info!("got enrollment {enrollment:?}");
+    assert!(matches!(
+        enrollment.reason,
+        ResultReason::EnrollingVoiceProfile | ResultReason::EnrolledVoiceProfile
+    ));
+    assert_eq!(enrollment.profile_id, profile.id().unwrap());
+    assert!(enrollment.audio_length > Duration::ZERO);
+
+    let result = client.delete_profile_async(&profile).await.unwrap();
+    assert!(matches!(result.reason, ResultReason::DeletedVoiceProfile));
+}
+
+#[tokio::test]
+async fn conversation_translator_test() {
+    let mut translation_config = SpeechTranslationConfig::from_subscription(
+        env::var("MSSubscriptionKey").unwrap(),
+        env::var("MSServiceRegion").unwrap(),
+    )
+    .unwrap();
+    translation_config
+        .set_speech_recognition_language("en-US")
+        .unwrap();
+    translation_config.add_target_language("de").unwrap();
+
+    let conversation = Conversation::from_config(translation_config, None).unwrap();
+    conversation.start_conversation_async().await.unwrap();
+    let conversation_id = conversation.conversation_id().unwrap();
+    info!("started conversation {conversation_id}");
+    assert!(!conversation_id.is_empty());
+
+    let audio_config =
+        AudioConfig::from_wav_file_input(&get_sample_file("hello_rust.wav")).unwrap();
+    let mut translator = ConversationTranslator::from_config(audio_config).unwrap();
+    let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+    translator
+        .set_text_message_received_cb(move |event| {
+            info!("text message received {event:?}");
+            let _ = tx.send(event.result.base.text);
+        })
+        .unwrap();
+    translator
+        .join_conversation_async(&conversation, "Host")
+        .await
+        .unwrap();
+    translator
+        .send_text_message_async("Hello Rust!")
+        .await
+        .unwrap();
+
+    let message = tokio::time::timeout(std::time::Duration::from_secs(30), rx.recv())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(message, "Hello Rust!");
+
+    translator.leave_conversation_async().await.unwrap();
+    conversation.end_conversation_async().await.unwrap();
+    conversation.delete_conversation_async().await.unwrap();
+}
+
+#[tokio::test]
+async fn diagnostics_memory_logger_test() {
+    MemoryLogger::start();
+    let file_path_str = &get_sample_file("hello_rust.wav");
+    let mut speech_recognizer = speech_recognizer_from_wav_file(file_path_str);
+    speech_recognizer
+        .set_canceled_cb(MemoryLogger::dump_on_canceled(|event| {
+            error!("recognition canceled {event:?}");
+        }))
+        .unwrap();
+    let result = speech_recognizer.recognize_once_async().await.unwrap();
+    info!("got recognition {result:?}");
+    MemoryLogger::stop();
+
+    let lines = MemoryLogger::lines();
+    assert!(!lines.is_empty());
+}
+
+#[tokio::test]
+async fn audio_processing_options_test() {
+    let geometry = MicrophoneArrayGeometry::new(
+        MicrophoneArrayType::Linear,
+        vec![
+            MicrophoneCoordinates::new(-20, 0, 0),
+            MicrophoneCoordinates::new(20, 0, 0),
+        ],
+    );
+    let options = AudioProcessingOptions::from_microphone_array_geometry(
+        AudioProcessingFlags::ENABLE_DEFAULT | AudioProcessingFlags::DISABLE_GAIN_CONTROL,
+        &geometry,
+        SpeakerReferenceChannel::LastChannel,
+    )
+    .unwrap();
+
+    let wave_format = AudioStreamFormat::get_wave_format_pcm(16000, None, Some(3)).unwrap();
+    let pull_stream = PullAudioInputStream::from_format(&wave_format).unwrap();
+    let audio_config =
+        AudioConfig::from_stream_input_with_processing(&pull_stream, &options).unwrap();
+
+    let options = audio_config.get_audio_processing_options().unwrap();
+    let flags = options.get_audio_processing_flags().unwrap();
+    assert!(flags.contains(AudioProcessingFlags::DISABLE_GAIN_CONTROL));
+    assert_eq!(
+        options.get_microphone_array_type().unwrap(),
+        MicrophoneArrayType::Linear
+    );
+    assert_eq!(options.get_beamforming_end_angle().unwrap(), 180);
+    assert_eq!(
+        options.get_microphone_coordinates().unwrap(),
+        geometry.microphone_coordinates
+    );
+    assert_eq!(
+        options.get_speaker_reference_channel().unwrap(),
+        SpeakerReferenceChannel::LastChannel
+    );
+}
+
+#[tokio::test]
+async fn meeting_transcriber_test() {
+    let speech_config = SpeechConfig::from_subscription(
+        env::var("MSSubscriptionKey").unwrap(),
+        env::var("MSServiceRegion").unwrap(),
+    )
+    .unwrap();
+    let meeting = Meeting::from_config(speech_config, "rust-meeting-test").unwrap();
+    assert_eq!(meeting.meeting_id().unwrap(), "rust-meeting-test");
+
+    let participant = Participant::from_user_id("alice@example.com", "en-US", None).unwrap();
+    meeting.add_participant_async(&participant).await.unwrap();
+    let user = User::from_id("bob@example.com").unwrap();
+    assert_eq!(user.id().unwrap(), "bob@example.com");
+    meeting.add_user_async(&user).await.unwrap();
+
+    let audio_config =
+        AudioConfig::from_wav_file_input(&get_sample_file("hello_rust.wav")).unwrap();
+    let mut transcriber = MeetingTranscriber::from_config(audio_config).unwrap();
+    let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+    transcriber
+        .set_transcribed_cb(move |event| {
+            info!("transcribed {event:?}");
+            let _ = tx.send(event.result);
+        })
+        .unwrap();
+    transcriber.join_meeting(&meeting).unwrap();
+    transcriber.start_transcribing_async().await.unwrap();
+
+    let result = tokio::time::timeout(std::time::Duration::from_secs(30), rx.recv())
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(!result.utterance_id.is_empty());
+
+    transcriber.stop_transcribing_async().await.unwrap();
+    transcriber.leave_meeting().unwrap();
+}
+
+#[tokio::test]
+async fn caching_token_provider_test() {
+    let fetch_count = Arc::new(AtomicUsize::new(0));
+    let counter = fetch_count.clone();
+    let provider = Arc::new(CachingTokenProvider::new(move || {
+        let n = counter.fetch_add(1, Ordering::SeqCst);
+        Ok((format!("token-{n}"), Duration::from_secs(600)))
+    }));
+
+    let (mut speech_synthesizer, _) = speech_synthesizer_pull();
+    speech_synthesizer
+        .set_token_provider(provider.clone())
+        .unwrap();
+    let mut speech_recognizer = speech_recognizer_from_wav_file(&get_sample_file("hello_rust.wav"));
+    speech_recognizer
+        .set_token_provider(provider.clone())
+        .unwrap();
+
+    assert_eq!(speech_synthesizer.get_auth_token().unwrap(), "token-0");
+    assert_eq!(speech_recognizer.get_auth_token().unwrap(), "token-0");
+    assert_eq!(fetch_count.load(Ordering::SeqCst), 1);
+
+    provider.invalidate();
+    speech_recognizer.set_token_provider(provider).unwrap();
+    assert_eq!(speech_recognizer.get_auth_token().unwrap(), "token-1");
+
+    let err = speech_recognizer
+        .set_token_provider(
+            || -> cognitive_services_speech_sdk_rs::error::Result<String> {
+                Err(Error::token_provider("sts endpoint unavailable"))
+            },
+        )
+        .unwrap_err();
+    assert!(matches!(
+        err.caused_by,
+        ErrorRootCause::TokenProviderError(_)
+    ));
+}
+
+#[tokio::test]
+async fn source_language_recognizer_test() {
+    let speech_config = SpeechConfig::from_subscription(
+        env::var("MSSubscriptionKey").unwrap(),
+        env::var("MSServiceRegion").unwrap(),
+    )
+    .unwrap();
+    let audio_config =
+        AudioConfig::from_wav_file_input(&get_sample_file("hello_rust.wav")).unwrap();
+    let lang_config =
+        AutoDetectSourceLanguageConfig::from_languages(vec!["en-US".into(), "de-DE".into()])
+            .unwrap();
+    let mut recognizer = SourceLanguageRecognizer::from_auto_detect_source_lang_config(
+        speech_config,
+        audio_config,
+        lang_config,
+    )
+    .unwrap();
+
+    let result = recognizer.recognize_once_async().await.unwrap();
+    info!("got language {result:?}");
+    assert_eq!(result.language, "en-US");
+}
+
+#[tokio::test]
+async fn translation_target_languages_test() {
+    let mut translation_config = SpeechTranslationConfig::from_subscription(
+        env::var("MSSubscriptionKey").unwrap(),
+        env::var("MSServiceRegion").unwrap(),
+    )
+    .unwrap();
+    translation_config
+        .set_speech_recognition_language("en-US")
+        .unwrap();
+    translation_config.add_target_language("de").unwrap();
+    translation_config.add_target_language("it").unwrap();
+    translation_config.remove_target_language("it").unwrap();
+    assert_eq!(translation_config.get_target_languages().unwrap(), ["de"]);
+
+    let audio_config =
+        AudioConfig::from_wav_file_input(&get_sample_file("hello_rust.wav")).unwrap();
+    let mut translation_recognizer =
+        TranslationRecognizer::from_config(translation_config, audio_config).unwrap();
+    translation_recognizer.add_target_language("fr").unwrap();
+    translation_recognizer.remove_target_language("de").unwrap();
+    assert_eq!(translation_recognizer.target_languages().unwrap(), ["fr"]);
+
+    let result = translation_recognizer.recognize_once_async().await.unwrap();
+    info!("got translation result {result:?}");
+    assert!(result.translations.contains_key("fr"));
+    assert!(!result.translations.contains_key("de"));
+}
+
+#[tokio::test]
+async fn audio_data_stream_read_test() {
+    use std::io::{Seek, SeekFrom};
+    use tokio::io::AsyncReadExt;
+
+    let (speech_synthesizer, _) = speech_synthesizer_pull();
+    let result = speech_synthesizer
+        .speak_text_async("Hello Rust!")
+        .await
+        .unwrap();
+    let mut audio_data_stream = AudioDataStream::from_speech_synthesis_result(result).unwrap();
+
+    let mut async_audio = vec![];
+    audio_data_stream
+        .read_to_end(&mut async_audio)
+        .await
+        .unwrap();
+    assert!(!async_audio.is_empty());
+    assert_eq!(audio_data_stream.get_available_size(), 0);
+
+    audio_data_stream.seek(SeekFrom::Start(0)).unwrap();
+    let mut sync_audio = vec![];
+    std::io::Read::read_to_end(&mut audio_data_stream, &mut sync_audio).unwrap();
+    assert_eq!(async_audio, sync_audio);
+
+    // sync and async reads share position of the stream
+    audio_data_stream.seek(SeekFrom::Start(0)).unwrap();
+    let mut head = [0u8; 100];
+    audio_data_stream.read_exact(&mut head).await.unwrap();
+    assert_eq!(audio_data_stream.stream_position().unwrap(), 100);
+    assert_eq!(&head[..], &sync_audio[..100]);
+}
+
+#[tokio::test]
+async fn bot_framework_activity_test() {
+    let json = r#"{
+        "type": "message",
+        "id": "activity-1",
+        "text": "Hello Rust!",
+        "speak": "Hello Rust!",
+        "inputHint": "expectingInput",
+        "attachments": [
+            {"contentType": "application/vnd.microsoft.card.adaptive", "content": {"type": "AdaptiveCard"}}
+        ],
+        "replyToId": "activity-0"
+    }"#;
+    let activity = Activity::from_json(json).unwrap();
+    assert_eq!(activity.activity_type, ActivityType::Message);
+    assert_eq!(activity.text.as_deref(), Some("Hello Rust!"));
+    assert_eq!(activity.input_hint, Some(InputHint::ExpectingInput));
+    assert_eq!(activity.attachments.len(), 1);
+    assert_eq!(activity.extra["replyToId"], "activity-0");
+    assert_eq!(
+        Activity::from_json(&activity.to_json().unwrap()).unwrap(),
+        activity
+    );
+
+    let event = Activity::event("setLocale", Some(serde_json::json!("en-US")));
+    let event_json: serde_json::Value = serde_json::from_str(&event.to_json().unwrap()).unwrap();
+    assert_eq!(
+        event_json,
+        serde_json::json!({"type": "event", "name": "setLocale", "value": "en-US"})
+    );
+    // unknown type and input hint values are preserved
+    let invoke = Activity::from_json(r#"{"type": "invoke", "inputHint": "thinking"}"#).unwrap();
+    assert_eq!(
+        invoke.activity_type,
+        ActivityType::Other("invoke".to_owned())
+    );
+    assert_eq!(
+        invoke.input_hint,
+        Some(InputHint::Other("thinking".to_owned()))
+    );
+    let invoke_json: serde_json::Value = serde_json::from_str(&invoke.to_json().unwrap()).unwrap();
+    assert_eq!(
+        invoke_json,
+        serde_json::json!({"type": "invoke", "inputHint": "thinking"})
+    );
+}
+
+#[tokio::test]
+async fn detailed_recognition_result_test() {
+    let mut speech_config = SpeechConfig::from_subscription(
+        env::var("MSSubscriptionKey").unwrap(),
+        env::var("MSServiceRegion").unwrap(),
+    )
+    .unwrap();
+    speech_config
+        .set_get_output_format(OutputFormat::Detailed)
+        .unwrap();
+    speech_config.request_word_level_timestamps().unwrap();
+    let audio_config =
+        AudioConfig::from_wav_file_input(&get_sample_file("hello_rust.wav")).unwrap();
+    let mut speech_recognizer = SpeechRecognizer::from_config(speech_config, audio_config).unwrap();
+
+    let result = speech_recognizer.recognize_once_async().await.unwrap();
+    let detailed = result.detailed_result().unwrap().unwrap();
+    info!("got detailed recognition result {detailed:?}");
+    assert_eq!(detailed.recognition_status, "Success");
+    let best = detailed.n_best.first().unwrap();
+    assert!(best.confidence > 0.0);
+    assert!(!best.lexical.is_empty());
+    assert!(!best.words.is_empty());
+    assert!(best.words.iter().all(|word| !word.duration.is_zero()));
+    assert!(best.words[0].offset >= detailed.offset);
+    assert_eq!(result.offset, detailed.offset);
+}
+
+#[tokio::test]
+async fn ticks_conversion_test() {
+    let duration = Duration::from_ticks(12_345_678);
+    assert_eq!(duration, Duration::from_nanos(1_234_567_800));
+    assert_eq!(duration.as_ticks(), 12_345_678);
+    assert_eq!(Duration::from_millis(1500).as_ticks(), 15_000_000);
+    assert_eq!(Duration::MAX.as_ticks(), u64::MAX);
+    assert_eq!(Duration::from_ticks(u64::MAX).as_ticks(), u64::MAX);
+}
+
+#[tokio::test]
+async fn speech_recognizer_events_test() {
+    use tokio_stream::StreamExt;
+
+    let mut speech_recognizer = speech_recognizer_from_wav_file(&get_sample_file("hello_rust.wav"));
+    let mut events = speech_recognizer
+        .events(EventBuffering::Bounded(16))
+        .unwrap();
+
+    speech_recognizer
+        .start_continuous_recognition_async()
+        .await
+        .unwrap();
+
+    let mut session_started = false;
+    let mut recognized = String::new();
+    while let Some(event) = events.next().await {
+        match event {
+            SpeechRecognizerEvent::SessionStarted(_) => session_started = true,
+            SpeechRecognizerEvent::Recognized(event) => recognized.push_str(&event.result.text),
+            SpeechRecognizerEvent::Canceled(_) | SpeechRecognizerEvent::SessionStopped(_) => break,
+            _ => {}
+        }
+    }
+    speech_recognizer
+        .stop_continuous_recognition_async()
+        .await
+        .unwrap();
+
+    assert!(session_started);
+    assert!(recognized.to_lowercase().contains("hello"));
+}
+
+#[tokio::test]
+async fn async_push_pull_streams_test() {
+    use tokio::io::AsyncReadExt;
+
+    let mut push_stream = PushAudioInputStream::create_push_stream().unwrap();
+    let audio_config = AudioConfig::from_stream_input(&push_stream).unwrap();
+    let mut speech_recognizer = speech_recognizer_from_audio_cfg(audio_config);
+
+    let mut file = tokio::fs::File::open(get_sample_file("hello_rust.wav"))
+        .await
+        .unwrap();
+    tokio::io::copy(&mut file, &mut push_stream).await.unwrap();
+    tokio::io::AsyncWriteExt::shutdown(&mut push_stream)
+        .await
+        .unwrap();
+
+    let result = speech_recognizer.recognize_once_async().await.unwrap();
+    assert!(result.text.to_lowercase().contains("hello"));
+
+    let (speech_synthesizer, mut pull_stream) = speech_synthesizer_pull();
+    speech_synthesizer
+        .speak_text_async("Hello Rust!")
+        .await
+        .unwrap();
+    // pull stream is closed when synthesizer is dropped
+    drop(speech_synthesizer);
+
+    let mut audio = vec![];
+    pull_stream.read_to_end(&mut audio).await.unwrap();
+    assert!(!audio.is_empty());
+
+    // dropping pull stream while read is pending must not release native stream
+    // under the blocking read, it is released once the read returns
+    let (speech_synthesizer, mut pull_stream) = speech_synthesizer_pull();
+    let mut buf = [0u8; 1024];
+    let pending = tokio::time::timeout(
+        Duration::from_millis(100),
+        AsyncReadExt::read(&mut pull_stream, &mut buf),
+    )
+    .await;
+    assert!(pending.is_err());
+    drop(pull_stream);
+    speech_synthesizer
+        .speak_text_async("Hello Rust!")
+        .await
+        .unwrap();
+    drop(speech_synthesizer);
+}
+
+#[tokio::test]
+async fn stock_stream_callbacks_test() {
+    let (mut speech_recognizer, mut pull_stream) = speech_recognizer_from_pull_stream();
+    let source = ReaderPullSource::from_file(&get_sample_file("hello_rust.wav")).unwrap();
+    pull_stream.set_callbacks(Box::new(source), false).unwrap();
+    let result = speech_recognizer.recognize_once_async().await.unwrap();
+    assert!(result.text.to_lowercase().contains("hello"));
+
+    let wav_file = env::temp_dir().join("stock_stream_callbacks_test.wav");
+    let wav_file = wav_file.to_str().unwrap();
+    let mut push_stream = PushAudioOutputStream::create_push_stream().unwrap();
+    let vec_sink = VecPushSink::new();
+    let buffer = vec_sink.get_buffer();
+    push_stream.set_callbacks(Box::new(vec_sink)).unwrap();
+    let mut wav_push_stream = PushAudioOutputStream::create_push_stream().unwrap();
+    wav_push_stream
+        .set_callbacks(Box::new(
+            WavFilePushSink::create(wav_file, 16000, None, None).unwrap(),
+        ))
+        .unwrap();
+
+    let speech_config = SpeechConfig::from_subscription(
+        env::var("MSSubscriptionKey").unwrap(),
+        env::var("MSServiceRegion").unwrap(),
+    )
+    .unwrap();
+    let audio_config = AudioConfig::from_stream_output(&push_stream).unwrap();
+    let speech_synthesizer = SpeechSynthesizer::from_config(speech_config, audio_config).unwrap();
+    speech_synthesizer
+        .speak_text_async("Hello Rust!")
+        .await
+        .unwrap();
+    drop(speech_synthesizer);
+    assert!(!buffer.lock().unwrap().is_empty());
+
+    let mut speech_config = SpeechConfig::from_subscription(
+        env::var("MSSubscriptionKey").unwrap(),
+        env::var("MSServiceRegion").unwrap(),
+    )
+    .unwrap();
+    speech_config
+        .set_speech_synthesis_output_format(SpeechSynthesisOutputFormat::Raw16Khz16BitMonoPcm)
+        .unwrap();
+    let audio_config = AudioConfig::from_stream_output(&wav_push_stream).unwrap();
+    let speech_synthesizer = SpeechSynthesizer::from_config(speech_config, audio_config).unwrap();
+    speech_synthesizer
+        .speak_text_async("Hello Rust!")
+        .await
+        .unwrap();
+    drop(speech_synthesizer);
+    drop(wav_push_stream);
+
+    let wav = std::fs::read(wav_file).unwrap();
+    assert_eq!(&wav[..4], b"RIFF");
+    assert_eq!(
+        u32::from_le_bytes([wav[40], wav[41], wav[42], wav[43]]) as usize,
+        wav.len() - 44
+    );
+}
+
+#[test]
+fn channel_stream_callbacks_test() {
+    // callbacks are normally invoked by native threads, i.e. outside of async context
+    let (sender, receiver) = tokio::sync::mpsc::channel(4);
+    let mut sink = ChannelPushSink::new(sender);
+    let mut source = ChannelPullSource::new(receiver);
+    assert_eq!(sink.write(&[1, 2, 3, 4, 5]), 5);
+    assert_eq!(sink.write(&[6, 7]), 2);
+    sink.close();
+    assert_eq!(sink.write(&[8]), 0);
+
+    let mut buf = [0u8; 3];
+    assert_eq!(source.read(&mut buf), 3);
+    assert_eq!(buf, [1, 2, 3]);
+    assert_eq!(source.read(&mut buf), 2);
+    assert_eq!(&buf[..2], &[4, 5]);
+    assert_eq!(source.read(&mut buf), 2);
+    assert_eq!(&buf[..2], &[6, 7]);
+    assert_eq!(source.read(&mut buf), 0);
+
+    // 3200 bytes at 32000 bytes per second take 100ms
+    let mut source = ReaderPullSource::from_bytes(vec![0u8; 3200]);
+    source.set_real_time_pacing(32000);
+    let started = std::time::Instant::now();
+    let mut buf = [0u8; 1600];
+    assert_eq!(source.read(&mut buf), 1600);
+    assert_eq!(source.read(&mut buf), 1600);
+    assert_eq!(source.read(&mut buf), 0);
+    assert!(started.elapsed() >= Duration::from_millis(90));
+}
+
+#[tokio::test]
+async fn wav_file_input_test() {
+    // 44.1kHz stereo 32bit float WAV with one second of silence
+    let frames: u32 = 44100;
+    let data_len = frames * 2 * 4;
+    let mut wav = Vec::new();
+    wav.extend_from_slice(b"RIFF");
+    wav.extend_from_slice(&(36 + data_len).to_le_bytes());
+    wav.extend_from_slice(b"WAVEfmt ");
+    wav.extend_from_slice(&16u32.to_le_bytes());
+    wav.extend_from_slice(&3u16.to_le_bytes());
+    wav.extend_from_slice(&2u16.to_le_bytes());
+    wav.extend_from_slice(&44100u32.to_le_bytes());
+    wav.extend_from_slice(&(44100u32 * 8).to_le_bytes());
+    wav.extend_from_slice(&8u16.to_le_bytes());
+    wav.extend_from_slice(&32u16.to_le_bytes());
+    wav.extend_from_slice(b"data");
+    wav.extend_from_slice(&data_len.to_le_bytes());
+    wav.resize(wav.len() + data_len as usize, 0);
+
+    let input = WavFileInput::from_bytes(&wav).unwrap();
+    assert_eq!(input.source_format.channels, 2);
+    assert_eq!(input.source_format.sample_format, WavSampleFormat::Float);
+    assert_eq!(input.data.len(), WAV_INPUT_SAMPLES_PER_SECOND as usize * 2);
+
+    assert!(WavFileInput::from_bytes(&wav[..20]).is_err());
+
+    let input = WavFileInput::from_file(&get_sample_file("hello_rust.wav")).unwrap();
+    let mut speech_recognizer = speech_recognizer_from_audio_cfg(input.to_audio_config().unwrap());
+    let result = speech_recognizer.recognize_once_async().await.unwrap();
+    info!("got recognition {:?}", result);
+    assert!(result.text.contains("Hello"));
+}
+
+fn wav_bytes(fmt: &[u8], data: &[u8]) -> Vec<u8> {
+    let mut wav = Vec::new();
+    wav.extend_from_slice(b"RIFF");
+    wav.extend_from_slice(&(20 + fmt.len() as u32 + data.len() as u32).to_le_bytes());
+    wav.extend_from_slice(b"WAVEfmt ");
+    wav.extend_from_slice(&(fmt.len() as u32).to_le_bytes());
+    wav.extend_from_slice(fmt);
+    wav.extend_from_slice(b"data");
+    wav.extend_from_slice(&(data.len() as u32).to_le_bytes());
+    wav.extend_from_slice(data);
+    wav
+}
+
+fn wav_fmt(format_tag: u16, channels: u16, samples_per_second: u32, bits: u16) -> Vec<u8> {
+    let block_align = channels * bits / 8;
+    let mut fmt = Vec::new();
+    fmt.extend_from_slice(&format_tag.to_le_bytes());
+    fmt.extend_from_slice(&channels.to_le_bytes());
+    fmt.extend_from_slice(&samples_per_second.to_le_bytes());
+    fmt.extend_from_slice(&(samples_per_second * u32::from(block_align)).to_le_bytes());
+    fmt.extend_from_slice(&block_align.to_le_bytes());
+    fmt.extend_from_slice(&bits.to_le_bytes());
+    fmt
+}
+
+fn wav_input_samples(input: &WavFileInput) -> Vec<i16> {
+    input
+        .data
+        .chunks_exact(2)
+        .map(|sample| i16::from_le_bytes([sample[0], sample[1]]))
+        .collect()
+}
+
+#[test]
+fn wav_file_input_decode_test() {
+    // 8bit unsigned samples
+    let wav = wav_bytes(&wav_fmt(1, 1, 16000, 8), &[0, 64, 128, 192, 255]);
+    let input = WavFileInput::from_bytes(&wav).unwrap();
+    assert_eq!(input.source_format.bits_per_sample, 8);
+    assert_eq!(
+        wav_input_samples(&input),
+        vec![-32767, -16384, 0, 16384, 32511]
+    );
+
+    // 24bit signed samples
+    let wav = wav_bytes(
+        &wav_fmt(1, 1, 16000, 24),
+        &[
+            0, 0, 0x40, 0, 0, 0xC0, 0xFF, 0xFF, 0x7F, 0, 0, 0x80, 0, 0, 0,
+        ],
+    );
+    let input = WavFileInput::from_bytes(&wav).unwrap();
+    assert_eq!(input.source_format.sample_format, WavSampleFormat::Int);
+    assert_eq!(
+        wav_input_samples(&input),
+        vec![16384, -16384, 32767, -32767, 0]
+    );
+
+    // WAVE_FORMAT_EXTENSIBLE stereo 16bit PCM, channels are averaged
+    let mut fmt = wav_fmt(0xFFFE, 2, 16000, 16);
+    fmt.extend_from_slice(&22u16.to_le_bytes());
+    fmt.extend_from_slice(&16u16.to_le_bytes());
+    fmt.extend_from_slice(&3u32.to_le_bytes());
+    fmt.extend_from_slice(&[
+        0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10, 0x00, 0x80, 0x00, 0x00, 0xAA, 0x00, 0x38, 0x9B,
+        0x71,
+    ]);
+    let mut data = Vec::new();
+    for (left, right) in [(1000i16, 3000i16), (-8192, -8192), (16384, -16384)] {
+        data.extend_from_slice(&left.to_le_bytes());
+        data.extend_from_slice(&right.to_le_bytes());
+    }
+    let input = WavFileInput::from_bytes(&wav_bytes(&fmt, &data)).unwrap();
+    assert_eq!(input.source_format.channels, 2);
+    assert_eq!(input.source_format.sample_format, WavSampleFormat::Int);
+    assert_eq!(wav_input_samples(&input), vec![2000, -8192, 0]);
+
+    // 48kHz float: 1kHz tone keeps its level, 12kHz tone must not alias into speech band
+    let tone = |frequency: f64| {
+        let mut data = Vec::new();
+        for i in 0..48000 {
+            let phase = 2.0 * std::f64::consts::PI * frequency * f64::from(i) / 48000.0;
+            data.extend_from_slice(&(0.5 * phase.sin() as f32).to_le_bytes());
+        }
+        let input = WavFileInput::from_bytes(&wav_bytes(&wav_fmt(3, 1, 48000, 32), &data)).unwrap();
+        let samples = wav_input_samples(&input);
+        assert_eq!(samples.len(), WAV_INPUT_SAMPLES_PER_SECOND as usize);
+        // skip edges where filter kernel is cut
+        let inner = &samples[100..samples.len() - 100];
+        let power = inner.iter().map(|s| f64::from(*s).powi(2)).sum::<f64>() / inner.len() as f64;
+        power.sqrt() / 32767.0
+    };
+    let rms = tone(1000.0);
+    assert!((rms - 0.5 / 2f64.sqrt()).abs() < 0.01, "rms {}", rms);
+    let rms = tone(12000.0);
+    assert!(rms < 0.005, "rms {}", rms);
+}
+
+#[tokio::test]
+async fn connection_test() {
+    let mut speech_recognizer = speech_recognizer_from_wav_file(&get_sample_file("hello_rust.wav"));
+    let mut connection = Connection::from_recognizer(&speech_recognizer).unwrap();
+    let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+    connection
+        .set_connected_cb(move |event| {
+            info!("connected {event:?}");
+            let _ = tx.send(event.session_id);
+        })
+        .unwrap();
+    let (msg_tx, mut msg_rx) = tokio::sync::mpsc::unbounded_channel();
+    connection
+        .set_message_received_cb(move |event| {
+            info!("message received {event:?}");
+            let _ = msg_tx.send(event.message.path);
+        })
+        .unwrap();
+
+    connection.open(false).unwrap();
+    let session_id = tokio::time::timeout(Duration::from_secs(10), rx.recv())
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(!session_id.is_empty());
+
+    let result = speech_recognizer.recognize_once_async().await.unwrap();
+    assert!(result.text.contains("Hello"));
+
+    let mut paths = vec![];
+    while let Ok(path) = msg_rx.try_recv() {
+        paths.push(path);
+    }
+    assert!(paths.iter().any(|path| path == "speech.phrase"));
+    connection.close().unwrap();
+}
+
+#[tokio::test]
+async fn conversation_transcriber_test() {
+    let speech_config = SpeechConfig::from_subscription(
+        env::var("MSSubscriptionKey").unwrap(),
+        env::var("MSServiceRegion").unwrap(),
+    )
+    .unwrap();
+    let audio_config =
+        AudioConfig::from_wav_file_input(&get_sample_file("hello_rust.wav")).unwrap();
+    let mut transcriber =
+        ConversationTranscriber::from_config(speech_config, audio_config).unwrap();
+    let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+    transcriber
+        .set_transcribed_cb(move |event| {
+            info!("transcribed {event:?}");
+            let _ = tx.send(event.result);
+        })
+        .unwrap();
+    transcriber.start_transcribing_async().await.unwrap();
+
+    let result = tokio::time::timeout(Duration::from_secs(30), rx.recv())
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(result.base.text.contains("Hello"));
+    assert!(!result.speaker_id.is_empty());
+
+    transcriber.stop_transcribing_async().await.unwrap();
+}
+
+#[tokio::test]
+async fn keyword_recognizer_stop_test() {
+    // keyword models are trained per keyword and are not part of the repository,
+    // test runs only when path to a model (.table file) is provided
+    let Ok(model_file) = env::var("MSKeywordModelFile") else {
+        info!("MSKeywordModelFile not set, skipping keyword_recognizer_stop_test");
+        return;
+    };
+    let model = KeywordRecognitionModel::from_file(&model_file).unwrap();
+    // push stream is never closed so recognition stays pending until stopped
+    let push_stream = PushAudioInputStream::create_push_stream().unwrap();
+    let audio_config = AudioConfig::from_stream_input(&push_stream).unwrap();
+    let keyword_recognizer = KeywordRecognizer::from_config(audio_config).unwrap();
+
+    let stop = async {
+        tokio::time::sleep(Duration::from_secs(1)).await;
+        keyword_recognizer.stop_recognition_async().await
+    };
+    let (result, stopped) = tokio::join!(keyword_recognizer.recognize_once_async(&model), stop);
+    stopped.unwrap();
+    let result = result.unwrap();
+    info!("got keyword result {:?}", result);
+    assert!(!matches!(
+        result.base.reason,
+        ResultReason::RecognizedKeyword
+    ));
+}
+
+#[tokio::test]
+async fn hybrid_speech_config_test() {
+    assert_eq!(SpeechBackend::from_property("online"), SpeechBackend::Cloud);
+    assert_eq!(
+        SpeechBackend::from_property("offline"),
+        SpeechBackend::Embedded
+    );
+    assert_eq!(SpeechBackend::from_property(""), SpeechBackend::Unknown);
+
+    let cloud_config = SpeechConfig::from_subscription(
+        env::var("MSSubscriptionKey").unwrap(),
+        env::var("MSServiceRegion").unwrap(),
+    )
+    .unwrap();
+    // no embedded models are needed as long as the speech service is reachable
+    let embedded_config = EmbeddedSpeechConfig::from_path(env::temp_dir()).unwrap();
+    let speech_config = HybridSpeechConfig::from_configs(&cloud_config, &embedded_config).unwrap();
+
+    let audio_config =
+        AudioConfig::from_wav_file_input(&get_sample_file("hello_rust.wav")).unwrap();
+    let mut speech_recognizer =
+        SpeechRecognizer::from_hybrid_config(speech_config, audio_config).unwrap();
+    let result = speech_recognizer.recognize_once_async().await.unwrap();
+    info!("got recognition {:?}", result);
+    assert!(result.text.contains("Hello"));
+    assert_eq!(result.backend().unwrap(), SpeechBackend::Cloud);
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn dropped_recognition_future_test() {
+    // push stream is not closed yet so recognition stays pending
+    let push_stream = PushAudioInputStream::create_push_stream().unwrap();
+    let audio_config = AudioConfig::from_stream_input(&push_stream).unwrap();
+    let mut speech_recognizer = speech_recognizer_from_audio_cfg(audio_config);
+    set_recognizer_callbacks(&mut speech_recognizer);
+
+    // native wait runs on blocking pool, so timer fires even on single threaded runtime
+    let pending = tokio::time::timeout(
+        Duration::from_millis(500),
+        speech_recognizer.recognize_once_async(),
+    )
+    .await;
+    assert!(pending.is_err());
+
+    // recognition finishes only after recognizer is dropped,
+    // its events must not reach freed callbacks
+    drop(speech_recognizer);
+    push_stream.close_stream().unwrap();
+    tokio::time::sleep(Duration::from_secs(2)).await;
+}